@@ -107,6 +107,8 @@ extension NumberFormatExtension on NumberFormat {
         return "Riyal";
       case NumberFormat.Ruble:
         return "Ruble";
+      case NumberFormat.Scientific:
+        return "Scientific";
       case NumberFormat.Rupee:
         return "Rupee";
       case NumberFormat.Rupiah:
@@ -158,7 +158,7 @@ abstract class IFieldTypeOptionLoader {
   String get gridId;
   Future<Either<TypeOptionPB, FlowyError>> load();
 
-  Future<Either<Unit, FlowyError>> switchToField(
+  Future<Either<RepeatedFieldTransformWarningPB, FlowyError>> switchToField(
       String fieldId, FieldType fieldType) {
     final payload = EditFieldChangesetPB.create()
       ..gridId = gridId
@@ -157,6 +157,24 @@ pub enum ErrorCode {
 
     #[error("Out of bounds")]
     OutOfBounds = 52,
+
+    #[error("Field name should not be empty")]
+    FieldNameIsEmpty = 53,
+
+    #[error("Row not exists")]
+    RowNotFound = 54,
+
+    #[error("The cell data is invalid")]
+    InvalidCellData = 55,
+
+    #[error("The type of the field doesn't support this operation")]
+    FieldTypeMismatch = 56,
+
+    #[error("Select option not exists")]
+    SelectOptionNotFound = 57,
+
+    #[error("Group not exists")]
+    GroupNotFound = 58,
 }
 
 impl ErrorCode {
@@ -0,0 +1,56 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+/// The result of calculating the cells of a field, for example, the sum of a number field.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CalculationPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    /// The value is formatted with the field's format, e.g. `$15` for the USD number format.
+    #[pb(index = 2)]
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum CalculationTypePB {
+    Sum = 0,
+    Average = 1,
+    /// The earliest date of a date field.
+    Earliest = 2,
+    /// The latest date of a date field.
+    Latest = 3,
+}
+
+impl CalculationTypePB {
+    pub fn is_date_calculation(&self) -> bool {
+        matches!(self, CalculationTypePB::Earliest | CalculationTypePB::Latest)
+    }
+}
+
+/// The options of calculating the cells of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalculationOptions {
+    /// Counts the empty cells, including the rows without a cell, as zero instead of skipping
+    /// them. It doesn't apply to the date calculations.
+    pub empty_as_zero: bool,
+
+    /// Only calculates the rows that are visible in the view, which means the rows that are
+    /// hidden by the filters are skipped. It's true by default.
+    pub respect_filters: bool,
+}
+
+impl std::default::Default for CalculationOptions {
+    fn default() -> Self {
+        Self {
+            empty_as_zero: false,
+            respect_filters: true,
+        }
+    }
+}
+
+impl std::default::Default for CalculationTypePB {
+    fn default() -> Self {
+        CalculationTypePB::Sum
+    }
+}
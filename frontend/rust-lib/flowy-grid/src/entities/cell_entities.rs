@@ -161,7 +161,10 @@ impl std::convert::From<CellChangesetPB> for RowChangeset {
             row_id: changeset.row_id,
             height: None,
             visibility: None,
+            archived: None,
+            last_edited_by: None,
             cell_by_field_id,
+            deleted_field_ids: vec![],
         }
     }
 }
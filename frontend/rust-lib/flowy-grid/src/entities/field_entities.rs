@@ -5,6 +5,7 @@ use serde_repr::*;
 use std::sync::Arc;
 
 use crate::entities::parser::NotEmptyStr;
+use crate::services::field::{FieldTransformWarning, SelectOptionRenamePB};
 use strum_macros::{Display, EnumCount as EnumCountMacro, EnumIter, EnumString};
 
 /// [FieldPB] defines a Field's attributes. Such as the name, field_type, and width. etc.
@@ -33,10 +34,15 @@ pub struct FieldPB {
 
     #[pb(index = 8)]
     pub is_primary: bool,
+
+    /// True if the field is marked as read-only or its cells are computed, e.g. the formula fields.
+    #[pb(index = 9)]
+    pub read_only: bool,
 }
 
 impl std::convert::From<FieldRevision> for FieldPB {
     fn from(field_rev: FieldRevision) -> Self {
+        let field_type: FieldType = field_rev.ty.into();
         Self {
             id: field_rev.id,
             name: field_rev.name,
@@ -46,6 +52,7 @@ impl std::convert::From<FieldRevision> for FieldPB {
             visibility: field_rev.visibility,
             width: field_rev.width,
             is_primary: field_rev.is_primary,
+            read_only: field_rev.read_only || field_type.is_computed(),
         }
     }
 }
@@ -308,6 +315,39 @@ impl std::convert::From<String> for RepeatedFieldIdPB {
     }
 }
 
+/// The content that a cell loses when its field is switched to another field type.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct FieldTransformWarningPB {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub message: String,
+}
+
+impl std::convert::From<FieldTransformWarning> for FieldTransformWarningPB {
+    fn from(warning: FieldTransformWarning) -> Self {
+        Self {
+            row_id: warning.row_id,
+            message: warning.message,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedFieldTransformWarningPB {
+    #[pb(index = 1)]
+    pub items: Vec<FieldTransformWarningPB>,
+}
+
+impl std::convert::From<Vec<FieldTransformWarning>> for RepeatedFieldTransformWarningPB {
+    fn from(warnings: Vec<FieldTransformWarning>) -> Self {
+        Self {
+            items: warnings.into_iter().map(FieldTransformWarningPB::from).collect(),
+        }
+    }
+}
+
 /// [TypeOptionChangesetPB] is used to update the type-option data.
 #[derive(ProtoBuf, Default)]
 pub struct TypeOptionChangesetPB {
@@ -320,6 +360,11 @@ pub struct TypeOptionChangesetPB {
     /// Check out [TypeOptionPB] for more details.
     #[pb(index = 3)]
     pub type_option_data: Vec<u8>,
+
+    /// Renames the options of the select field in one go, after the `type_option_data` is
+    /// applied. The names of the options must stay unique.
+    #[pb(index = 4)]
+    pub rename_options: Vec<SelectOptionRenamePB>,
 }
 
 #[derive(Clone)]
@@ -327,6 +372,7 @@ pub struct TypeOptionChangesetParams {
     pub grid_id: String,
     pub field_id: String,
     pub type_option_data: Vec<u8>,
+    pub rename_options: Vec<SelectOptionRenamePB>,
 }
 
 impl TryInto<TypeOptionChangesetParams> for TypeOptionChangesetPB {
@@ -340,6 +386,7 @@ impl TryInto<TypeOptionChangesetParams> for TypeOptionChangesetPB {
             grid_id: grid_id.0,
             field_id: self.field_id,
             type_option_data: self.type_option_data,
+            rename_options: self.rename_options,
         })
     }
 }
@@ -411,6 +458,8 @@ pub struct FieldChangesetPB {
     pub width: Option<i32>,
     // #[pb(index = 9, one_of)]
     // pub type_option_data: Option<Vec<u8>>,
+    #[pb(index = 10, one_of)]
+    pub read_only: Option<bool>,
 }
 
 impl TryInto<FieldChangesetParams> for FieldChangesetPB {
@@ -435,11 +484,16 @@ impl TryInto<FieldChangesetParams> for FieldChangesetPB {
             frozen: self.frozen,
             visibility: self.visibility,
             width: self.width,
+            read_only: self.read_only,
             // type_option_data: self.type_option_data,
         })
     }
 }
 
+/// The width of a field is clamped to this range when it's updated.
+pub const MIN_FIELD_WIDTH: i32 = 40;
+pub const MAX_FIELD_WIDTH: i32 = 800;
+
 #[derive(Debug, Clone, Default)]
 pub struct FieldChangesetParams {
     pub field_id: String,
@@ -457,6 +511,8 @@ pub struct FieldChangesetParams {
     pub visibility: Option<bool>,
 
     pub width: Option<i32>,
+
+    pub read_only: Option<bool>,
     // pub type_option_data: Option<Vec<u8>>,
 }
 /// Certain field types have user-defined options such as color, date format, number format,
@@ -492,6 +548,9 @@ pub enum FieldType {
     Checkbox = 5,
     URL = 6,
     Checklist = 7,
+    Formula = 8,
+    CreatedBy = 9,
+    LastEditedBy = 10,
 }
 
 pub const RICH_TEXT_FIELD: FieldType = FieldType::RichText;
@@ -502,6 +561,9 @@ pub const MULTI_SELECT_FIELD: FieldType = FieldType::MultiSelect;
 pub const CHECKBOX_FIELD: FieldType = FieldType::Checkbox;
 pub const URL_FIELD: FieldType = FieldType::URL;
 pub const CHECKLIST_FIELD: FieldType = FieldType::Checklist;
+pub const FORMULA_FIELD: FieldType = FieldType::Formula;
+pub const CREATED_BY_FIELD: FieldType = FieldType::CreatedBy;
+pub const LAST_EDITED_BY_FIELD: FieldType = FieldType::LastEditedBy;
 
 impl std::default::Default for FieldType {
     fn default() -> Self {
@@ -569,9 +631,69 @@ impl FieldType {
         self == &CHECKLIST_FIELD
     }
 
+    pub fn is_formula(&self) -> bool {
+        self == &FORMULA_FIELD
+    }
+
+    pub fn is_created_by(&self) -> bool {
+        self == &CREATED_BY_FIELD
+    }
+
+    pub fn is_last_edited_by(&self) -> bool {
+        self == &LAST_EDITED_BY_FIELD
+    }
+
+    /// The user fields show the authors of the rows.
+    pub fn is_user(&self) -> bool {
+        self.is_created_by() || self.is_last_edited_by()
+    }
+
+    /// The cells of the computed fields are derived from the other cells or the row, so they are
+    /// always read-only.
+    pub fn is_computed(&self) -> bool {
+        self.is_formula() || self.is_user()
+    }
+
     pub fn can_be_group(&self) -> bool {
         self.is_select_option()
     }
+
+    /// Returns None if the `ty` is unknown to this build, e.g. the field type was added by a newer
+    /// version of the app.
+    pub fn try_from_type_rev(ty: FieldTypeRevision) -> Option<FieldType> {
+        match ty {
+            0 => Some(FieldType::RichText),
+            1 => Some(FieldType::Number),
+            2 => Some(FieldType::DateTime),
+            3 => Some(FieldType::SingleSelect),
+            4 => Some(FieldType::MultiSelect),
+            5 => Some(FieldType::Checkbox),
+            6 => Some(FieldType::URL),
+            7 => Some(FieldType::Checklist),
+            8 => Some(FieldType::Formula),
+            9 => Some(FieldType::CreatedBy),
+            10 => Some(FieldType::LastEditedBy),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the icon that the client shows for the field type. The names are
+    /// stable, so the client can keep them as the keys of its icon assets.
+    pub fn icon_name(&self) -> &'static str {
+        match self {
+            FieldType::RichText => "grid/field/text",
+            FieldType::Number => "grid/field/number",
+            FieldType::DateTime => "grid/field/date",
+            FieldType::SingleSelect => "grid/field/single_select",
+            FieldType::MultiSelect => "grid/field/multi_select",
+            FieldType::Checkbox => "grid/field/checkbox",
+            FieldType::URL => "grid/field/url",
+            FieldType::Checklist => "grid/field/checklist",
+            FieldType::Formula => "grid/field/formula",
+            FieldType::CreatedBy => "grid/field/created_by",
+            FieldType::LastEditedBy => "grid/field/last_edited_by",
+        }
+    }
 }
 
 impl std::convert::From<&FieldType> for FieldTypeRevision {
@@ -594,20 +716,10 @@ impl std::convert::From<&FieldTypeRevision> for FieldType {
 
 impl std::convert::From<FieldTypeRevision> for FieldType {
     fn from(ty: FieldTypeRevision) -> Self {
-        match ty {
-            0 => FieldType::RichText,
-            1 => FieldType::Number,
-            2 => FieldType::DateTime,
-            3 => FieldType::SingleSelect,
-            4 => FieldType::MultiSelect,
-            5 => FieldType::Checkbox,
-            6 => FieldType::URL,
-            7 => FieldType::Checklist,
-            _ => {
-                tracing::error!("Can't convert FieldTypeRevision: {} to FieldType", ty);
-                FieldType::RichText
-            }
-        }
+        FieldType::try_from_type_rev(ty).unwrap_or_else(|| {
+            tracing::error!("Can't convert FieldTypeRevision: {} to FieldType", ty);
+            FieldType::RichText
+        })
     }
 }
 #[derive(Debug, Clone, Default, ProtoBuf)]
@@ -667,3 +779,24 @@ pub struct FieldIdParams {
     pub field_id: String,
     pub grid_id: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use std::collections::HashSet;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn field_type_icon_name_test() {
+        assert_eq!(FieldType::RichText.icon_name(), "grid/field/text");
+        assert_eq!(FieldType::DateTime.icon_name(), "grid/field/date");
+        assert_eq!(FieldType::Checklist.icon_name(), "grid/field/checklist");
+
+        // Each field type has its own icon
+        let icon_names = FieldType::iter()
+            .map(|field_type| field_type.icon_name())
+            .collect::<HashSet<&str>>();
+        assert!(icon_names.iter().all(|icon_name| !icon_name.is_empty()));
+        assert_eq!(icon_names.len(), FieldType::iter().count());
+    }
+}
@@ -18,6 +18,16 @@ pub struct DateFilterPB {
 
     #[pb(index = 4, one_of)]
     pub timestamp: Option<i64>,
+
+    /// The id of the date field that the cell is compared with. Only used by the
+    /// [DateFilterConditionPB::DateBeforeField] and [DateFilterConditionPB::DateAfterField].
+    #[pb(index = 5, one_of)]
+    pub other_field_id: Option<String>,
+
+    /// Excludes the dates on the `end` from the [DateFilterConditionPB::DateWithIn], e.g. the
+    /// range of "this week" ends on the first day of the next week. The `end` is inclusive by default.
+    #[pb(index = 6)]
+    pub exclusive_end: bool,
 }
 
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
@@ -25,6 +35,10 @@ pub struct DateFilterContentPB {
     pub start: Option<i64>,
     pub end: Option<i64>,
     pub timestamp: Option<i64>,
+    #[serde(default)]
+    pub other_field_id: Option<String>,
+    #[serde(default)]
+    pub exclusive_end: bool,
 }
 
 impl ToString for DateFilterContentPB {
@@ -52,6 +66,8 @@ pub enum DateFilterConditionPB {
     DateWithIn = 5,
     DateIsEmpty = 6,
     DateIsNotEmpty = 7,
+    DateBeforeField = 8,
+    DateAfterField = 9,
 }
 
 impl std::convert::From<DateFilterConditionPB> for u32 {
@@ -77,6 +93,9 @@ impl std::convert::TryFrom<u8> for DateFilterConditionPB {
             4 => Ok(DateFilterConditionPB::DateOnOrAfter),
             5 => Ok(DateFilterConditionPB::DateWithIn),
             6 => Ok(DateFilterConditionPB::DateIsEmpty),
+            7 => Ok(DateFilterConditionPB::DateIsNotEmpty),
+            8 => Ok(DateFilterConditionPB::DateBeforeField),
+            9 => Ok(DateFilterConditionPB::DateAfterField),
             _ => Err(ErrorCode::InvalidData),
         }
     }
@@ -96,6 +115,8 @@ impl FromFilterString for DateFilterPB {
             filter.start = content.start;
             filter.end = content.end;
             filter.timestamp = content.timestamp;
+            filter.other_field_id = content.other_field_id;
+            filter.exclusive_end = content.exclusive_end;
         };
 
         filter
@@ -113,6 +134,8 @@ impl std::convert::From<&FilterRevision> for DateFilterPB {
             filter.start = content.start;
             filter.end = content.end;
             filter.timestamp = content.timestamp;
+            filter.other_field_id = content.other_field_id;
+            filter.exclusive_end = content.exclusive_end;
         };
 
         filter
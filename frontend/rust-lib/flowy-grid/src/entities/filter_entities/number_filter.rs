@@ -2,6 +2,8 @@ use crate::services::filter::FromFilterString;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 use grid_rev_model::FilterRevision;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct NumberFilterPB {
@@ -23,6 +25,29 @@ pub enum NumberFilterConditionPB {
     LessThanOrEqualTo = 5,
     NumberIsEmpty = 6,
     NumberIsNotEmpty = 7,
+    NumberIsBetween = 8,
+}
+
+/// The content of the [NumberFilterConditionPB::NumberIsBetween] filter. Both bounds are
+/// inclusive, and they are swapped if the `min` is greater than the `max`.
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct NumberFilterRange {
+    pub min: String,
+    pub max: String,
+}
+
+impl ToString for NumberFilterRange {
+    fn to_string(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl FromStr for NumberFilterRange {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
 }
 
 impl std::default::Default for NumberFilterConditionPB {
@@ -49,6 +74,7 @@ impl std::convert::TryFrom<u8> for NumberFilterConditionPB {
             5 => Ok(NumberFilterConditionPB::LessThanOrEqualTo),
             6 => Ok(NumberFilterConditionPB::NumberIsEmpty),
             7 => Ok(NumberFilterConditionPB::NumberIsNotEmpty),
+            8 => Ok(NumberFilterConditionPB::NumberIsBetween),
             _ => Err(ErrorCode::InvalidData),
         }
     }
@@ -39,6 +39,9 @@ impl std::convert::From<&FilterRevision> for FilterPB {
             FieldType::Checklist => ChecklistFilterPB::from(rev).try_into().unwrap(),
             FieldType::Checkbox => CheckboxFilterPB::from(rev).try_into().unwrap(),
             FieldType::URL => TextFilterPB::from(rev).try_into().unwrap(),
+            FieldType::Formula => TextFilterPB::from(rev).try_into().unwrap(),
+            FieldType::CreatedBy => TextFilterPB::from(rev).try_into().unwrap(),
+            FieldType::LastEditedBy => TextFilterPB::from(rev).try_into().unwrap(),
         };
         Self {
             id: rev.id.clone(),
@@ -176,7 +179,11 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
         let bytes: &[u8] = self.data.as_ref();
 
         match self.field_type {
-            FieldType::RichText | FieldType::URL => {
+            FieldType::RichText
+            | FieldType::URL
+            | FieldType::Formula
+            | FieldType::CreatedBy
+            | FieldType::LastEditedBy => {
                 let filter = TextFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
                 condition = filter.condition as u8;
                 content = filter.content;
@@ -197,6 +204,8 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
                     start: filter.start,
                     end: filter.end,
                     timestamp: filter.timestamp,
+                    other_field_id: filter.other_field_id,
+                    exclusive_end: filter.exclusive_end,
                 }
                 .to_string();
             }
@@ -1,5 +1,6 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{CreateRowParams, FieldType, GridLayout, RowPB};
+use crate::services::field::SelectOptionColorPB;
 use crate::services::group::Group;
 use flowy_derive::ProtoBuf;
 use flowy_error::ErrorCode;
@@ -94,6 +95,10 @@ pub struct GroupPB {
 
     #[pb(index = 6)]
     pub is_visible: bool,
+
+    /// The color of the select option that the group is generated from.
+    #[pb(index = 7, one_of)]
+    pub color: Option<SelectOptionColorPB>,
 }
 
 impl std::convert::From<Group> for GroupPB {
@@ -105,10 +110,27 @@ impl std::convert::From<Group> for GroupPB {
             rows: group.rows,
             is_default: group.is_default,
             is_visible: group.is_visible,
+            color: group.color,
         }
     }
 }
 
+/// The summary shown in the header of a board column.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct GroupSummaryPB {
+    #[pb(index = 1)]
+    pub group_id: String,
+
+    /// The number of the rows in the group.
+    #[pb(index = 2)]
+    pub count: i32,
+
+    /// The sum of a number field over the rows of the group, formatted with the format of the
+    /// field, e.g. `$3.00`. It's None if no number field is summed.
+    #[pb(index = 3, one_of)]
+    pub sum: Option<String>,
+}
+
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct RepeatedGroupConfigurationPB {
     #[pb(index = 1)]
@@ -126,7 +126,7 @@ impl TryInto<MoveGroupParams> for MoveGroupPayloadPB {
     }
 }
 
-#[derive(Debug, Default, ProtoBuf)]
+#[derive(Debug, Default, Clone, ProtoBuf)]
 pub struct GroupViewChangesetPB {
     #[pb(index = 1)]
     pub view_id: String,
@@ -153,7 +153,7 @@ impl GroupViewChangesetPB {
     }
 }
 
-#[derive(Debug, Default, ProtoBuf)]
+#[derive(Debug, Default, Clone, ProtoBuf)]
 pub struct InsertedGroupPB {
     #[pb(index = 1)]
     pub group: GroupPB,
@@ -1,3 +1,4 @@
+mod calculation_entities;
 mod cell_entities;
 mod field_entities;
 pub mod filter_entities;
@@ -9,6 +10,7 @@ pub mod setting_entities;
 mod sort_entities;
 mod view_entities;
 
+pub use calculation_entities::*;
 pub use cell_entities::*;
 pub use field_entities::*;
 pub use filter_entities::*;
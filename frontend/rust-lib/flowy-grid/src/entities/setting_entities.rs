@@ -6,7 +6,7 @@ use crate::entities::{
 };
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
-use grid_rev_model::LayoutRevision;
+use grid_rev_model::{FilterConjunctionRevision, LayoutRevision, RowHeightRevision};
 use std::convert::TryInto;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
@@ -25,6 +25,12 @@ pub struct GridSettingPB {
 
     #[pb(index = 4)]
     pub group_configurations: RepeatedGroupConfigurationPB,
+
+    #[pb(index = 5)]
+    pub row_height: RowHeightPB,
+
+    #[pb(index = 6)]
+    pub filter_conjunction: FilterConjunctionPB,
 }
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
@@ -75,6 +81,71 @@ impl std::convert::From<GridLayout> for LayoutRevision {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum RowHeightPB {
+    Compact = 0,
+    Medium = 1,
+    Tall = 2,
+}
+
+impl std::default::Default for RowHeightPB {
+    fn default() -> Self {
+        RowHeightPB::Medium
+    }
+}
+
+impl std::convert::From<RowHeightRevision> for RowHeightPB {
+    fn from(rev: RowHeightRevision) -> Self {
+        match rev {
+            RowHeightRevision::Compact => RowHeightPB::Compact,
+            RowHeightRevision::Medium => RowHeightPB::Medium,
+            RowHeightRevision::Tall => RowHeightPB::Tall,
+        }
+    }
+}
+
+impl std::convert::From<RowHeightPB> for RowHeightRevision {
+    fn from(row_height: RowHeightPB) -> Self {
+        match row_height {
+            RowHeightPB::Compact => RowHeightRevision::Compact,
+            RowHeightPB::Medium => RowHeightRevision::Medium,
+            RowHeightPB::Tall => RowHeightRevision::Tall,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum FilterConjunctionPB {
+    And = 0,
+    Or = 1,
+}
+
+impl std::default::Default for FilterConjunctionPB {
+    fn default() -> Self {
+        FilterConjunctionPB::And
+    }
+}
+
+impl std::convert::From<FilterConjunctionRevision> for FilterConjunctionPB {
+    fn from(rev: FilterConjunctionRevision) -> Self {
+        match rev {
+            FilterConjunctionRevision::And => FilterConjunctionPB::And,
+            FilterConjunctionRevision::Or => FilterConjunctionPB::Or,
+        }
+    }
+}
+
+impl std::convert::From<FilterConjunctionPB> for FilterConjunctionRevision {
+    fn from(filter_conjunction: FilterConjunctionPB) -> Self {
+        match filter_conjunction {
+            FilterConjunctionPB::And => FilterConjunctionRevision::And,
+            FilterConjunctionPB::Or => FilterConjunctionRevision::Or,
+        }
+    }
+}
+
 #[derive(Default, ProtoBuf)]
 pub struct GridSettingChangesetPB {
     #[pb(index = 1)]
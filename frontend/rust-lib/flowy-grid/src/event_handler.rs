@@ -120,6 +120,11 @@ pub(crate) async fn update_field_type_option_handler(
             old_field_rev,
         )
         .await?;
+    if !params.rename_options.is_empty() {
+        let _ = editor
+            .rename_select_options(&params.field_id, params.rename_options)
+            .await?;
+    }
     Ok(())
 }
 
@@ -138,11 +143,11 @@ pub(crate) async fn delete_field_handler(
 pub(crate) async fn switch_to_field_handler(
     data: AFPluginData<EditFieldChangesetPB>,
     manager: AFPluginState<Arc<GridManager>>,
-) -> Result<(), FlowyError> {
+) -> DataResult<RepeatedFieldTransformWarningPB, FlowyError> {
     let params: EditFieldParams = data.into_inner().try_into()?;
     let editor = manager.get_grid_editor(&params.grid_id).await?;
     let old_field_rev = editor.get_field_rev(&params.field_id).await;
-    editor
+    let warnings = editor
         .switch_to_field_type(&params.field_id, &params.field_type)
         .await?;
 
@@ -158,7 +163,7 @@ pub(crate) async fn switch_to_field_handler(
         .update_field_type_option(&params.grid_id, &new_field_rev.id, type_option_data, old_field_rev)
         .await?;
 
-    Ok(())
+    data_result(RepeatedFieldTransformWarningPB::from(warnings))
 }
 
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
@@ -420,10 +425,7 @@ pub(crate) async fn get_select_option_handler(
             let cell_rev = editor.get_cell_rev(&params.row_id, &params.field_id).await?;
             let type_option = select_type_option_from_field_rev(&field_rev)?;
             let type_cell_data: TypeCellData = match cell_rev {
-                None => TypeCellData {
-                    cell_str: "".to_string(),
-                    field_type: field_rev.ty.into(),
-                },
+                None => TypeCellData::from_field_type(&field_rev.ty.into()),
                 Some(cell_rev) => cell_rev.try_into()?,
             };
             let ids = SelectOptionIds::from_cell_str(&type_cell_data.cell_str)?;
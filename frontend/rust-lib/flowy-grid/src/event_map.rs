@@ -110,7 +110,8 @@ pub enum GridEvent {
     /// [SwitchToField] event is used to update the current Field's type.
     /// It will insert a new FieldTypeOptionData if the new FieldType doesn't exist before, otherwise
     /// reuse the existing FieldTypeOptionData. You could check the [GridRevisionPad] for more details.
-    #[event(input = "EditFieldChangesetPB")]
+    /// It returns the content that the cells lose with the switch.
+    #[event(input = "EditFieldChangesetPB", output = "RepeatedFieldTransformWarningPB")]
     SwitchToField = 20,
 
     /// [DuplicateField] event is used to duplicate a Field. The duplicated field data is kind of
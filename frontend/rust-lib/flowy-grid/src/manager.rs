@@ -1,4 +1,4 @@
-use crate::entities::GridLayout;
+use crate::entities::{GridLayout, RowPB};
 use crate::services::grid_editor::{GridRevisionEditor, GridRevisionMergeable};
 use crate::services::persistence::block_index::BlockIndexCache;
 use crate::services::persistence::kv::GridKVPersistence;
@@ -25,13 +25,18 @@ pub trait GridUser: Send + Sync {
     fn user_id(&self) -> Result<String, FlowyError>;
     fn token(&self) -> Result<String, FlowyError>;
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
+
+    /// Returns the name of the user, it's used to display the authors of the rows. Returns None
+    /// if the user is unknown, then the user id is displayed instead.
+    fn user_name(&self, _user_id: &str) -> Option<String> {
+        None
+    }
 }
 
 pub struct GridManager {
     grid_editors: RwLock<RefCountHashMap<Arc<GridRevisionEditor>>>,
     grid_user: Arc<dyn GridUser>,
     block_index_cache: Arc<BlockIndexCache>,
-    #[allow(dead_code)]
     kv_persistence: Arc<GridKVPersistence>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     migration: GridMigration,
@@ -117,6 +122,14 @@ impl GridManager {
         }
     }
 
+    /// Clones the row of the grid into the grid of the `target_view_id`, which is opened if it's
+    /// not opened yet. See [GridRevisionEditor::clone_row_from] for how the cells are mapped.
+    pub async fn clone_row_to_grid(&self, grid_id: &str, row_id: &str, target_view_id: &str) -> FlowyResult<RowPB> {
+        let editor = self.get_grid_editor(grid_id).await?;
+        let target_editor = self.open_grid(target_view_id).await?;
+        target_editor.clone_row_from(&editor, row_id).await
+    }
+
     async fn get_or_create_grid_editor(&self, grid_id: &str) -> FlowyResult<Arc<GridRevisionEditor>> {
         if let Some(editor) = self.grid_editors.read().await.get(grid_id) {
             return Ok(editor);
@@ -142,6 +155,7 @@ impl GridManager {
             user,
             rev_manager,
             self.block_index_cache.clone(),
+            self.kv_persistence.clone(),
             self.task_scheduler.clone(),
         )
         .await?;
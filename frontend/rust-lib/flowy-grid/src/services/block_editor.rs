@@ -102,6 +102,11 @@ impl GridBlockRevisionEditor {
         Ok(())
     }
 
+    pub async fn update_rows(&self, changesets: Vec<RowChangeset>) -> FlowyResult<()> {
+        let _ = self.modify(|block_pad| Ok(block_pad.update_rows(changesets)?)).await?;
+        Ok(())
+    }
+
     pub async fn move_row(&self, row_id: &str, from: usize, to: usize) -> FlowyResult<()> {
         let _ = self
             .modify(|block_pad| Ok(block_pad.move_row(row_id, from, to)?))
@@ -150,20 +150,24 @@ impl GridBlockManager {
     }
 
     #[tracing::instrument(level = "trace", skip_all, err)]
-    pub async fn delete_row(&self, row_id: &str) -> FlowyResult<Option<Arc<RowRevision>>> {
+    pub async fn delete_row(
+        &self,
+        row_id: &str,
+    ) -> FlowyResult<Option<(Arc<RowRevision>, GridBlockMetaRevisionChangeset)>> {
         let row_id = row_id.to_owned();
         let block_id = self.persistence.get_block_id(&row_id)?;
         let editor = self.get_block_editor(&block_id).await?;
         match editor.get_row_rev(&row_id).await? {
             None => Ok(None),
             Some((_, row_rev)) => {
-                let _ = editor.delete_rows(vec![Cow::Borrowed(&row_id)]).await?;
+                let row_count = editor.delete_rows(vec![Cow::Borrowed(&row_id)]).await?;
                 let _ = self.event_notifier.send(GridBlockEvent::DeleteRow {
                     block_id: editor.block_id.clone(),
                     row_id: row_rev.id.clone(),
                 });
 
-                Ok(Some(row_rev))
+                let changeset = GridBlockMetaRevisionChangeset::from_row_count(block_id, row_count);
+                Ok(Some((row_rev, changeset)))
             }
         }
     }
@@ -219,7 +223,48 @@ impl GridBlockManager {
     pub async fn update_cell(&self, changeset: CellChangesetPB) -> FlowyResult<()> {
         let row_changeset: RowChangeset = changeset.clone().into();
         let _ = self.update_row(row_changeset).await?;
-        self.notify_did_update_cell(changeset).await?;
+        self.notify_did_update_cell(&changeset.row_id, &changeset.field_id)
+            .await?;
+        Ok(())
+    }
+
+    /// Updates the cells of many rows. The cells of the rows in the same block are updated in one
+    /// revision.
+    pub async fn update_cells(&self, changesets: Vec<CellChangesetPB>) -> FlowyResult<()> {
+        let row_changesets = changesets.into_iter().map(RowChangeset::from).collect();
+        self.update_rows(row_changesets).await
+    }
+
+    /// Updates many rows, including their cells and the other properties, e.g. the last editor.
+    /// The rows in the same block are updated in one revision.
+    pub async fn update_rows(&self, changesets: Vec<RowChangeset>) -> FlowyResult<()> {
+        let mut changesets_by_block_id: HashMap<String, Vec<RowChangeset>> = HashMap::new();
+        for changeset in changesets {
+            let block_id = self.persistence.get_block_id(&changeset.row_id)?;
+            changesets_by_block_id.entry(block_id).or_default().push(changeset);
+        }
+
+        for (block_id, changesets) in changesets_by_block_id {
+            let editor = self.get_block_editor(&block_id).await?;
+            let _ = editor.update_rows(changesets.clone()).await?;
+
+            for changeset in changesets {
+                let field_ids = changeset.cell_by_field_id.keys().cloned().collect::<Vec<String>>();
+                if let Some((_, row_rev)) = editor.get_row_rev(&changeset.row_id).await? {
+                    let row = UpdatedRowPB {
+                        row: make_row_from_row_rev(row_rev),
+                        field_ids: field_ids.clone(),
+                    };
+                    let _ = self.event_notifier.send(GridBlockEvent::UpdateRow {
+                        block_id: block_id.clone(),
+                        row,
+                    });
+                }
+                for field_id in field_ids.iter() {
+                    self.notify_did_update_cell(&changeset.row_id, field_id).await?;
+                }
+            }
+        }
         Ok(())
     }
 
@@ -256,8 +301,8 @@ impl GridBlockManager {
         Ok(blocks)
     }
 
-    async fn notify_did_update_cell(&self, changeset: CellChangesetPB) -> FlowyResult<()> {
-        let id = format!("{}:{}", changeset.row_id, changeset.field_id);
+    async fn notify_did_update_cell(&self, row_id: &str, field_id: &str) -> FlowyResult<()> {
+        let id = format!("{}:{}", row_id, field_id);
         send_dart_notification(&id, GridDartNotification::DidUpdateCell).send();
         Ok(())
     }
@@ -0,0 +1,79 @@
+use crate::entities::FieldType;
+use crate::services::cell::stringify_cell_data;
+use grid_rev_model::FieldRevision;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+use strum::IntoEnumIterator;
+
+pub type AtomicCellFormatterRegistry = Arc<RwLock<CellFormatterRegistry>>;
+
+/// [CellFormatter] renders the cell string of a field to the string displayed to the user.
+/// Implement it and register it to the [CellFormatterRegistry] to override the rendering
+/// of a field type, for example, formatting the dates with a custom locale.
+pub trait CellFormatter: Send + Sync {
+    /// * `cell_str`: the cell string stored in the [TypeCellData](crate::services::cell::TypeCellData)
+    /// * `decoded_field_type`: the field type that the cell string was created by
+    /// * `field_rev`: the field that the cell belongs to
+    fn format(&self, cell_str: String, decoded_field_type: &FieldType, field_rev: &FieldRevision) -> String;
+}
+
+/// Renders the cell string with the type option of the field type.
+pub struct DefaultCellFormatter {
+    field_type: FieldType,
+}
+
+impl DefaultCellFormatter {
+    pub fn new(field_type: FieldType) -> Self {
+        Self { field_type }
+    }
+}
+
+impl CellFormatter for DefaultCellFormatter {
+    fn format(&self, cell_str: String, decoded_field_type: &FieldType, field_rev: &FieldRevision) -> String {
+        stringify_cell_data(cell_str, decoded_field_type, &self.field_type, field_rev)
+    }
+}
+
+/// Holds the [CellFormatter] of each field type. Each field type is rendered by its
+/// [DefaultCellFormatter] until another formatter is registered for it.
+pub struct CellFormatterRegistry {
+    formatters: HashMap<FieldType, Arc<dyn CellFormatter>>,
+}
+
+impl CellFormatterRegistry {
+    pub fn new() -> AtomicCellFormatterRegistry {
+        Arc::new(RwLock::new(Self::default()))
+    }
+
+    /// Replaces the formatter of the `field_type` with the `formatter`.
+    pub fn register(&mut self, field_type: FieldType, formatter: Arc<dyn CellFormatter>) {
+        self.formatters.insert(field_type, formatter);
+    }
+
+    /// Returns the display string of the cell by using the formatter of the `field_type`.
+    pub fn format(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> String {
+        match self.formatters.get(field_type) {
+            None => stringify_cell_data(cell_str, decoded_field_type, field_type, field_rev),
+            Some(formatter) => formatter.format(cell_str, decoded_field_type, field_rev),
+        }
+    }
+}
+
+impl std::default::Default for CellFormatterRegistry {
+    fn default() -> Self {
+        let formatters = FieldType::iter()
+            .map(|field_type| {
+                let formatter: Arc<dyn CellFormatter> = Arc::new(DefaultCellFormatter::new(field_type.clone()));
+                (field_type, formatter)
+            })
+            .collect();
+        Self { formatters }
+    }
+}
@@ -1,9 +1,14 @@
 use crate::entities::FieldType;
 use crate::services::cell::{AtomicCellDataCache, CellProtobufBlob, TypeCellData};
+use crate::services::errors::GridError;
 use crate::services::field::*;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use grid_rev_model::{CellRevision, FieldRevision};
 use std::fmt::Debug;
+use std::str::FromStr;
+
+/// The display string of the cells whose field type is unknown to this build.
+pub const UNKNOWN_FIELD_TYPE_PLACEHOLDER: &str = "Unsupported field type";
 
 /// Decode the opaque cell data into readable format content
 pub trait CellDataDecoder: TypeOption {
@@ -57,6 +62,10 @@ pub fn apply_cell_data_changeset<C: ToCellChangesetString, T: AsRef<FieldRevisio
     cell_data_cache: Option<AtomicCellDataCache>,
 ) -> Result<String, FlowyError> {
     let field_rev = field_rev.as_ref();
+    // The cells of the unknown field type are read-only, so their data round-trips unchanged.
+    if FieldType::try_from_type_rev(field_rev.ty).is_none() {
+        return Err(GridError::unknown_field_type(&field_rev.id, field_rev.ty).into());
+    }
     let changeset = changeset.to_cell_changeset_str();
     let field_type: FieldType = field_rev.ty.into();
 
@@ -64,6 +73,11 @@ pub fn apply_cell_data_changeset<C: ToCellChangesetString, T: AsRef<FieldRevisio
         Ok(type_cell_data) => Some(type_cell_data),
         Err(_) => None,
     });
+    // Editing the text keeps the color of the cell
+    let color = type_cell_data
+        .as_ref()
+        .filter(|type_cell_data| field_type.is_text() && type_cell_data.is_text())
+        .and_then(|type_cell_data| type_cell_data.color.clone());
 
     let cell_str = match TypeOptionCellExt::new_with_cell_data_cache(field_rev, cell_data_cache)
         .get_type_option_cell_data_handler(&field_type)
@@ -71,7 +85,9 @@ pub fn apply_cell_data_changeset<C: ToCellChangesetString, T: AsRef<FieldRevisio
         None => "".to_string(),
         Some(handler) => handler.handle_cell_changeset(changeset, type_cell_data, field_rev)?,
     };
-    Ok(TypeCellData::new(cell_str, field_type).to_json())
+    let mut type_cell_data = TypeCellData::new(cell_str, field_type);
+    type_cell_data.color = color;
+    Ok(type_cell_data.to_json())
 }
 
 pub fn decode_type_cell_data<T: TryInto<TypeCellData, Error = FlowyError> + Debug>(
@@ -79,10 +95,18 @@ pub fn decode_type_cell_data<T: TryInto<TypeCellData, Error = FlowyError> + Debu
     field_rev: &FieldRevision,
     cell_data_cache: Option<AtomicCellDataCache>,
 ) -> (FieldType, CellProtobufBlob) {
+    if FieldType::try_from_type_rev(field_rev.ty).is_none() {
+        return (
+            FieldType::RichText,
+            CellProtobufBlob::new(UNKNOWN_FIELD_TYPE_PLACEHOLDER),
+        );
+    }
     let to_field_type = field_rev.ty.into();
     match data.try_into() {
         Ok(type_cell_data) => {
-            let TypeCellData { cell_str, field_type } = type_cell_data;
+            let TypeCellData {
+                cell_str, field_type, ..
+            } = type_cell_data;
             match try_decode_cell_str(cell_str, &field_type, &to_field_type, field_rev, cell_data_cache) {
                 Ok(cell_bytes) => (field_type, cell_bytes),
                 Err(e) => {
@@ -207,6 +231,69 @@ pub fn delete_select_option_cell(option_ids: Vec<String>, field_rev: &FieldRevis
     CellRevision::new(data)
 }
 
+/// Parses the display string of the cell with the parser of the field type and returns the
+/// changeset that sets the cell to it. For example, "Mar 14, 2022" is parsed into the timestamp of
+/// the date and the names of the options are mapped to their ids. This is the inverse of
+/// converting the cells to text.
+pub fn cell_changeset_from_display_str(display_str: &str, field_rev: &FieldRevision) -> FlowyResult<String> {
+    let field_type: FieldType = field_rev.ty.into();
+    match field_type {
+        FieldType::RichText | FieldType::URL => Ok(display_str.to_owned()),
+        FieldType::Number => {
+            let number = NumberTypeOptionPB::from(field_rev).number_str_from_display_str(display_str)?;
+            Ok(CanonicalNumberChangeset { number }.to_cell_changeset_str())
+        }
+        FieldType::DateTime => {
+            let date = if display_str.trim().is_empty() {
+                None
+            } else {
+                let timestamp = DateTypeOptionPB::from(field_rev).timestamp_from_display_str(display_str)?;
+                Some(timestamp.to_string())
+            };
+            let changeset = DateCellChangeset {
+                date,
+                time: None,
+                is_utc: true,
+            };
+            Ok(changeset.to_cell_changeset_str())
+        }
+        FieldType::Checkbox => {
+            let cell_data = CheckboxCellData::from_str(display_str.trim())?;
+            Ok(cell_data.to_string())
+        }
+        FieldType::SingleSelect | FieldType::MultiSelect | FieldType::Checklist => {
+            let type_option = select_type_option_from_field_rev(field_rev)?;
+            let mut insert_option_ids = vec![];
+            for name in display_str.split(SELECTION_IDS_SEPARATOR) {
+                let name = name.trim();
+                if name.is_empty() {
+                    continue;
+                }
+                match type_option.option_id_for_name(name) {
+                    None => return Err(GridError::option_not_found(name).into()),
+                    Some(option_id) => insert_option_ids.push(option_id),
+                }
+            }
+            // The options that are not in the display string are unselected.
+            let delete_option_ids = type_option
+                .options()
+                .iter()
+                .filter(|option| !insert_option_ids.contains(&option.id))
+                .map(|option| option.id.clone())
+                .collect();
+            let changeset = SelectOptionCellChangeset {
+                insert_option_ids,
+                delete_option_ids,
+            };
+            Ok(changeset.to_cell_changeset_str())
+        }
+        FieldType::Formula | FieldType::CreatedBy | FieldType::LastEditedBy => Err(FlowyError::new(
+            ErrorCode::FieldInvalidOperation,
+            &format!("The cells of the {:?} field are computed", field_type),
+        )),
+    }
+}
+
 /// Deserialize the String into cell specific data type.  
 pub trait FromCellString {
     fn from_cell_str(s: &str) -> FlowyResult<Self>
@@ -1,5 +1,6 @@
 use crate::entities::FieldType;
 use crate::services::cell::{FromCellString, IntoCellData};
+use crate::services::errors::GridError;
 use bytes::Bytes;
 use flowy_error::{internal_error, FlowyError, FlowyResult};
 use grid_rev_model::CellRevision;
@@ -19,6 +20,11 @@ pub struct TypeCellData {
     #[serde(rename = "data")]
     pub cell_str: String,
     pub field_type: FieldType,
+
+    /// The background color of the text cell, in the `#RRGGBB` format, that highlights the cell.
+    /// It's not part of the content, so transforming the cell to another field type drops it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
 }
 
 impl TypeCellData {
@@ -26,13 +32,14 @@ impl TypeCellData {
         Self {
             cell_str: "".to_string(),
             field_type: field_type.clone(),
+            color: None,
         }
     }
 
     pub fn from_json_str(s: &str) -> FlowyResult<Self> {
         let type_cell_data: TypeCellData = serde_json::from_str(s).map_err(|err| {
             let msg = format!("Deserialize {} to type cell data failed.{}", s, err);
-            FlowyError::internal().context(msg)
+            GridError::invalid_cell_data(msg)
         })?;
         Ok(type_cell_data)
     }
@@ -83,7 +90,11 @@ impl std::convert::TryFrom<CellRevision> for TypeCellData {
 
 impl TypeCellData {
     pub fn new(cell_str: String, field_type: FieldType) -> Self {
-        TypeCellData { cell_str, field_type }
+        TypeCellData {
+            cell_str,
+            field_type,
+            color: None,
+        }
     }
 
     pub fn to_json(&self) -> String {
@@ -0,0 +1,176 @@
+use flowy_error::{ErrorCode, FlowyError};
+use grid_rev_model::FieldTypeRevision;
+use std::fmt;
+
+/// The errors of the grid operations. Each kind has its own [ErrorCode], so the client can match
+/// on the `code` of the [FlowyError] that the [GridError] is converted into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    FieldNotFound {
+        field_id: String,
+    },
+    RowNotFound {
+        row_id: String,
+    },
+    GroupNotFound {
+        group_id: String,
+    },
+    OptionNotFound {
+        option: String,
+    },
+    OptionNameIsEmpty,
+    DuplicateOptionName {
+        name: String,
+    },
+    /// The operation is not supported by the type of the field, e.g. counting the checked cells
+    /// of a number field.
+    TypeMismatch {
+        field_id: String,
+        expected: &'static str,
+    },
+    /// The operation doesn't apply to the field type, e.g. mapping the cells to options when
+    /// switching to a non-select field.
+    UnsupportedFieldType {
+        ty: FieldTypeRevision,
+    },
+    InvalidCellData {
+        msg: String,
+    },
+    /// The type-option data is malformed or inconsistent, e.g. two select options share an id.
+    InvalidTypeOption {
+        msg: String,
+    },
+    /// The field type was added by a newer version, so its cells are kept as they are.
+    UnknownFieldType {
+        field_id: String,
+        ty: FieldTypeRevision,
+    },
+    ReadOnlyField {
+        field_id: String,
+    },
+}
+
+impl GridError {
+    pub fn field_not_found(field_id: &str) -> Self {
+        GridError::FieldNotFound {
+            field_id: field_id.to_owned(),
+        }
+    }
+
+    pub fn row_not_found(row_id: &str) -> Self {
+        GridError::RowNotFound {
+            row_id: row_id.to_owned(),
+        }
+    }
+
+    pub fn group_not_found(group_id: &str) -> Self {
+        GridError::GroupNotFound {
+            group_id: group_id.to_owned(),
+        }
+    }
+
+    pub fn option_not_found(option: &str) -> Self {
+        GridError::OptionNotFound {
+            option: option.to_owned(),
+        }
+    }
+
+    pub fn duplicate_option_name(name: &str) -> Self {
+        GridError::DuplicateOptionName { name: name.to_owned() }
+    }
+
+    pub fn unsupported_field_type(ty: FieldTypeRevision) -> Self {
+        GridError::UnsupportedFieldType { ty }
+    }
+
+    pub fn type_mismatch(field_id: &str, expected: &'static str) -> Self {
+        GridError::TypeMismatch {
+            field_id: field_id.to_owned(),
+            expected,
+        }
+    }
+
+    pub fn invalid_cell_data<T: ToString>(msg: T) -> Self {
+        GridError::InvalidCellData { msg: msg.to_string() }
+    }
+
+    pub fn invalid_type_option<T: ToString>(msg: T) -> Self {
+        GridError::InvalidTypeOption { msg: msg.to_string() }
+    }
+
+    pub fn unknown_field_type(field_id: &str, ty: FieldTypeRevision) -> Self {
+        GridError::UnknownFieldType {
+            field_id: field_id.to_owned(),
+            ty,
+        }
+    }
+
+    pub fn read_only_field(field_id: &str) -> Self {
+        GridError::ReadOnlyField {
+            field_id: field_id.to_owned(),
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            GridError::FieldNotFound { .. } => ErrorCode::FieldRecordNotFound,
+            GridError::RowNotFound { .. } => ErrorCode::RowNotFound,
+            GridError::GroupNotFound { .. } => ErrorCode::GroupNotFound,
+            GridError::OptionNotFound { .. } => ErrorCode::SelectOptionNotFound,
+            GridError::OptionNameIsEmpty => ErrorCode::SelectOptionNameIsEmpty,
+            GridError::DuplicateOptionName { .. } => ErrorCode::FieldInvalidOperation,
+            GridError::TypeMismatch { .. } => ErrorCode::FieldTypeMismatch,
+            GridError::UnsupportedFieldType { .. } => ErrorCode::FieldTypeMismatch,
+            GridError::InvalidCellData { .. } => ErrorCode::InvalidCellData,
+            GridError::InvalidTypeOption { .. } => ErrorCode::InvalidData,
+            GridError::UnknownFieldType { .. } => ErrorCode::FieldInvalidOperation,
+            GridError::ReadOnlyField { .. } => ErrorCode::FieldInvalidOperation,
+        }
+    }
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::FieldNotFound { field_id } => write!(f, "Field:{} not found", field_id),
+            GridError::RowNotFound { row_id } => write!(f, "Row:{} not found", row_id),
+            GridError::GroupNotFound { group_id } => write!(f, "Group:{} not found", group_id),
+            GridError::OptionNotFound { option } => write!(f, "Select option:{} not found", option),
+            GridError::OptionNameIsEmpty => write!(f, "The name of the option should not be empty"),
+            GridError::DuplicateOptionName { name } => write!(f, "The option:{} already exists", name),
+            GridError::TypeMismatch { field_id, expected } => {
+                write!(f, "Field:{} is not a {} field", field_id, expected)
+            }
+            GridError::UnsupportedFieldType { ty } => {
+                write!(f, "The field type:{} doesn't support this operation", ty)
+            }
+            GridError::InvalidCellData { msg } => write!(f, "{}", msg),
+            GridError::InvalidTypeOption { msg } => write!(f, "{}", msg),
+            GridError::UnknownFieldType { field_id, ty } => {
+                write!(f, "Field:{} has the unknown field type:{}", field_id, ty)
+            }
+            GridError::ReadOnlyField { field_id } => write!(f, "Field:{} is read-only", field_id),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl std::convert::From<GridError> for FlowyError {
+    fn from(error: GridError) -> Self {
+        FlowyError::new(error.code(), &error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::errors::GridError;
+    use flowy_error::{ErrorCode, FlowyError};
+
+    #[test]
+    fn grid_error_to_flowy_error_test() {
+        let error: FlowyError = GridError::type_mismatch("f1", "checkbox").into();
+        assert_eq!(error.code, ErrorCode::FieldTypeMismatch.value());
+        assert_eq!(error.msg, "Field:f1 is not a checkbox field");
+    }
+}
@@ -0,0 +1,85 @@
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Appends a footer line of the per-column aggregations: the sum of the number columns and
+    /// the checked/unchecked counts of the checkbox columns. The other columns are left empty.
+    pub include_aggregation_footer: bool,
+}
+
+/// Builds the CSV text line by line. The cells that contain a comma, a quote or a line break
+/// are quoted.
+pub struct CSVBuilder {
+    writer: csv::Writer<Vec<u8>>,
+}
+
+impl std::default::Default for CSVBuilder {
+    fn default() -> Self {
+        let writer = csv::WriterBuilder::new().flexible(true).from_writer(vec![]);
+        Self { writer }
+    }
+}
+
+impl CSVBuilder {
+    pub fn push_line<T: AsRef<str>>(&mut self, cells: &[T]) {
+        let cells = cells.iter().map(|cell| cell.as_ref());
+        if let Err(e) = self.writer.write_record(cells) {
+            tracing::error!("Write the CSV line failed: {:?}", e);
+        }
+    }
+
+    /// Returns the CSV text. The last line doesn't end with a line break.
+    pub fn build(self) -> String {
+        let bytes = match self.writer.into_inner() {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                tracing::error!("Build the CSV text failed: {:?}", e);
+                return "".to_owned();
+            }
+        };
+        let text = String::from_utf8(bytes).unwrap_or_default();
+        match text.strip_suffix('\n') {
+            None => text,
+            Some(text) => text.to_owned(),
+        }
+    }
+}
+
+/// Splits the tab-separated text that is copied from a table, e.g. a spreadsheet, into lines of
+/// cells. The line break at the end of the text doesn't start a new line.
+pub fn parse_tsv(text: &str) -> Vec<Vec<String>> {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    if text.is_empty() {
+        return vec![];
+    }
+    text.split('\n')
+        .map(|line| {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            line.split('\t').map(|cell| cell.to_owned()).collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::export::{parse_tsv, CSVBuilder};
+
+    #[test]
+    fn csv_builder_escape_test() {
+        let mut builder = CSVBuilder::default();
+        builder.push_line(&["Name", "Price"]);
+        builder.push_line(&["a, b", "say \"hi\""]);
+        builder.push_line(&["", "1"]);
+        assert_eq!(builder.build(), "Name,Price\n\"a, b\",\"say \"\"hi\"\"\"\n,1");
+    }
+
+    #[test]
+    fn parse_tsv_test() {
+        assert_eq!(
+            parse_tsv("a\tb\r\n\t1\n"),
+            vec![
+                vec!["a".to_owned(), "b".to_owned()],
+                vec!["".to_owned(), "1".to_owned()]
+            ]
+        );
+        assert!(parse_tsv("").is_empty());
+    }
+}
@@ -40,6 +40,8 @@ impl FieldBuilder {
             width: field.width,
             type_options: IndexMap::default(),
             is_primary: field.is_primary,
+            read_only: field.read_only,
+            last_modified_rev_id: 0,
         };
         Self {
             field_rev,
@@ -77,6 +79,11 @@ impl FieldBuilder {
         self
     }
 
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.field_rev.read_only = read_only;
+        self
+    }
+
     pub fn build(self) -> FieldRevision {
         let mut field_rev = self.field_rev;
         field_rev.insert_type_option(self.type_option_builder.serializer());
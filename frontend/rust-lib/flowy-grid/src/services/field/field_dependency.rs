@@ -0,0 +1,212 @@
+use crate::entities::FieldType;
+use crate::services::field::FormulaTypeOptionPB;
+use flowy_error::{FlowyError, FlowyResult};
+use grid_rev_model::{FieldRevision, TypeOptionDataDeserializer};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Returns the ids of the fields that the cells of the field are computed from. It's empty for
+/// the fields that aren't computed from other fields.
+///
+/// * `field_type`: the type of the field
+/// * `type_option_data`: the json string of the field's type option
+/// * `field_revs`: the fields of the grid, used to resolve the fields referenced by name
+pub fn type_option_field_dependencies(
+    field_type: &FieldType,
+    type_option_data: &str,
+    field_revs: &[Arc<FieldRevision>],
+) -> Vec<String> {
+    match field_type {
+        FieldType::RichText
+        | FieldType::Number
+        | FieldType::DateTime
+        | FieldType::SingleSelect
+        | FieldType::MultiSelect
+        | FieldType::Checkbox
+        | FieldType::URL
+        | FieldType::Checklist
+        | FieldType::CreatedBy
+        | FieldType::LastEditedBy => vec![],
+        FieldType::Formula => FormulaTypeOptionPB::from_json_str(type_option_data)
+            .field_names()
+            .iter()
+            .flat_map(|name| field_revs.iter().find(|field_rev| &field_rev.name == name))
+            .map(|field_rev| field_rev.id.clone())
+            .collect(),
+    }
+}
+
+/// [FieldDependencyGraph] records which fields each computed field reads. It's used to find out
+/// the fields that need to be recomputed when a field changes, and to reject the cycles.
+#[derive(Debug, Default)]
+pub struct FieldDependencyGraph {
+    dependencies: HashMap<String, Vec<String>>,
+}
+
+impl FieldDependencyGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_field_revs(field_revs: &[Arc<FieldRevision>]) -> Self {
+        let mut graph = Self::new();
+        for field_rev in field_revs {
+            let field_type: FieldType = field_rev.ty.into();
+            if let Some(type_option_data) = field_rev.get_type_option_str(field_rev.ty) {
+                graph.insert(
+                    &field_rev.id,
+                    type_option_field_dependencies(&field_type, type_option_data, field_revs),
+                );
+            }
+        }
+        graph
+    }
+
+    /// Replaces the dependencies of the field.
+    pub fn insert(&mut self, field_id: &str, dependencies: Vec<String>) {
+        if dependencies.is_empty() {
+            self.dependencies.remove(field_id);
+        } else {
+            self.dependencies.insert(field_id.to_owned(), dependencies);
+        }
+    }
+
+    /// Returns the ids of the fields that the field reads directly.
+    pub fn dependencies(&self, field_id: &str) -> Vec<String> {
+        self.dependencies.get(field_id).cloned().unwrap_or_default()
+    }
+
+    /// Returns the ids of the fields that need to be recomputed when the field with `field_id`
+    /// changes. A field always comes after the fields it depends on.
+    pub fn dependents(&self, field_id: &str) -> Vec<String> {
+        let mut visited = vec![];
+        let mut dependents = vec![];
+        self.visit_dependents(field_id, &mut visited, &mut dependents);
+        dependents.reverse();
+        dependents
+    }
+
+    /// Returns the ids of the fields that need to be recomputed when any of the fields with
+    /// `field_ids` changes. A field always comes after the fields it depends on.
+    pub fn dependents_of_fields(&self, field_ids: &[String]) -> Vec<String> {
+        let dependents = field_ids
+            .iter()
+            .flat_map(|field_id| self.dependents(field_id))
+            .collect::<HashSet<String>>();
+        self.computed_fields()
+            .into_iter()
+            .filter(|field_id| dependents.contains(field_id))
+            .collect()
+    }
+
+    fn visit_dependents(&self, field_id: &str, visited: &mut Vec<String>, dependents: &mut Vec<String>) {
+        let mut direct_dependents = self
+            .dependencies
+            .iter()
+            .filter(|(_, dependencies)| dependencies.iter().any(|dependency| dependency == field_id))
+            .map(|(dependent, _)| dependent.clone())
+            .collect::<Vec<String>>();
+        direct_dependents.sort();
+        for dependent in direct_dependents {
+            if !visited.contains(&dependent) {
+                visited.push(dependent.clone());
+                self.visit_dependents(&dependent, visited, dependents);
+                dependents.push(dependent);
+            }
+        }
+    }
+
+    /// Returns the ids of the fields that are computed from other fields. A field always comes
+    /// after the fields it depends on.
+    pub fn computed_fields(&self) -> Vec<String> {
+        let mut field_ids = self.dependencies.keys().collect::<Vec<&String>>();
+        field_ids.sort();
+        let mut finished: Vec<&str> = vec![];
+        for field_id in field_ids {
+            let mut path: Vec<&str> = vec![];
+            // The cycles are rejected when the fields are saved
+            let _ = self.visit_dependencies(field_id, &mut path, &mut finished);
+        }
+        finished
+            .into_iter()
+            .filter(|field_id| self.dependencies.contains_key(*field_id))
+            .map(|field_id| field_id.to_owned())
+            .collect()
+    }
+
+    /// Returns an error if any field depends on itself, directly or through other fields.
+    pub fn validate(&self) -> FlowyResult<()> {
+        let mut finished: Vec<&str> = vec![];
+        for field_id in self.dependencies.keys() {
+            let mut path: Vec<&str> = vec![];
+            self.visit_dependencies(field_id, &mut path, &mut finished)?;
+        }
+        Ok(())
+    }
+
+    fn visit_dependencies<'a>(
+        &'a self,
+        field_id: &'a str,
+        path: &mut Vec<&'a str>,
+        finished: &mut Vec<&'a str>,
+    ) -> FlowyResult<()> {
+        if finished.contains(&field_id) {
+            return Ok(());
+        }
+        if path.contains(&field_id) {
+            let msg = format!("Field:{} depends on itself", field_id);
+            return Err(FlowyError::invalid_data().context(msg));
+        }
+
+        path.push(field_id);
+        if let Some(dependencies) = self.dependencies.get(field_id) {
+            for dependency in dependencies {
+                self.visit_dependencies(dependency, path, finished)?;
+            }
+        }
+        path.pop();
+        finished.push(field_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::field::FieldDependencyGraph;
+
+    #[test]
+    fn field_dependencies_test() {
+        let mut graph = FieldDependencyGraph::new();
+        graph.insert("rollup", vec!["price".to_owned()]);
+        graph.insert("total", vec!["rollup".to_owned(), "price".to_owned()]);
+        assert!(graph.validate().is_ok());
+
+        assert_eq!(graph.dependencies("rollup"), vec!["price".to_owned()]);
+        assert!(graph.dependencies("price").is_empty());
+
+        // The rollup is recomputed before the total that reads it
+        assert_eq!(graph.dependents("price"), vec!["rollup".to_owned(), "total".to_owned()]);
+        assert_eq!(graph.computed_fields(), vec!["rollup".to_owned(), "total".to_owned()]);
+
+        // The other fields of the row are not recomputed
+        graph.insert("tax", vec!["discount".to_owned()]);
+        assert_eq!(
+            graph.dependents_of_fields(&["rollup".to_owned(), "discount".to_owned()]),
+            vec!["tax".to_owned(), "total".to_owned()]
+        );
+    }
+
+    #[test]
+    fn field_dependencies_cycle_test() {
+        let mut graph = FieldDependencyGraph::new();
+        graph.insert("rollup", vec!["price".to_owned()]);
+        graph.insert("price", vec!["total".to_owned()]);
+        assert!(graph.validate().is_ok());
+
+        graph.insert("total", vec!["rollup".to_owned()]);
+        assert!(graph.validate().is_err());
+
+        graph.insert("total", vec![]);
+        assert!(graph.validate().is_ok());
+    }
+}
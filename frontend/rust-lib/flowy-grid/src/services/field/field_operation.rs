@@ -1,9 +1,72 @@
+use crate::entities::FieldType;
 use crate::services::field::{MultiSelectTypeOptionPB, SingleSelectTypeOptionPB};
 use crate::services::grid_editor::GridRevisionEditor;
 use flowy_error::FlowyResult;
 use grid_rev_model::{TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use std::sync::Arc;
 
+/// Describes the content that a cell loses when its field is switched to another field type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldTransformWarning {
+    pub row_id: String,
+    pub message: String,
+}
+
+/// Describes the type-option data that a field misses after it's switched to another field type,
+/// so the client can prompt the user to configure the field before switching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldSwitchWarning {
+    /// The select field has no options to choose from.
+    NoSelectOptions,
+    /// The formula field has no expression to evaluate.
+    EmptyFormula,
+}
+
+/// How much of the cell content survives switching a field from one type to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformQuality {
+    /// The cells display the same content after the switch.
+    Lossless,
+    /// Some cells lose part of their content, e.g. the texts that are not a checkbox value.
+    Lossy,
+}
+
+/// Returns each conversion between two field types that transforms the cells, as
+/// `(from, to, quality)`. The cells of the conversions that are not listed are displayed as
+/// empty after the switch.
+pub fn supported_transforms() -> Vec<(FieldType, FieldType, TransformQuality)> {
+    use TransformQuality::*;
+    vec![
+        // The text shows the display string of the cell
+        (FieldType::Number, FieldType::RichText, Lossless),
+        (FieldType::DateTime, FieldType::RichText, Lossless),
+        (FieldType::SingleSelect, FieldType::RichText, Lossless),
+        (FieldType::MultiSelect, FieldType::RichText, Lossless),
+        (FieldType::Checkbox, FieldType::RichText, Lossless),
+        // Only the URL is kept unless the text field keeps the raw content
+        (FieldType::URL, FieldType::RichText, Lossy),
+        // Only the texts like "Yes" or "false" are checkbox values
+        (FieldType::RichText, FieldType::Checkbox, Lossy),
+        // Only the texts that are numbers are kept
+        (FieldType::RichText, FieldType::Number, Lossy),
+        // The timestamp is converted to the date unit of the number field
+        (FieldType::DateTime, FieldType::Number, Lossy),
+        // An option is added for each distinct text
+        (FieldType::RichText, FieldType::SingleSelect, Lossless),
+        (FieldType::RichText, FieldType::MultiSelect, Lossless),
+        (FieldType::RichText, FieldType::Checklist, Lossless),
+        // The Yes and No options are added
+        (FieldType::Checkbox, FieldType::SingleSelect, Lossless),
+        (FieldType::Checkbox, FieldType::MultiSelect, Lossless),
+        (FieldType::Checkbox, FieldType::Checklist, Lossless),
+        // The options are copied, and the single select keeps the first selected option
+        (FieldType::SingleSelect, FieldType::MultiSelect, Lossless),
+        (FieldType::SingleSelect, FieldType::Checklist, Lossless),
+        (FieldType::MultiSelect, FieldType::SingleSelect, Lossy),
+        (FieldType::MultiSelect, FieldType::Checklist, Lossless),
+    ]
+}
+
 pub async fn edit_field_type_option<T>(
     field_id: &str,
     editor: Arc<GridRevisionEditor>,
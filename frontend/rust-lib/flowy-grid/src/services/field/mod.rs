@@ -1,9 +1,13 @@
 mod field_builder;
+mod field_dependency;
 mod field_operation;
 mod type_option_builder;
+mod type_option_migration;
 pub(crate) mod type_options;
 
 pub use field_builder::*;
+pub use field_dependency::*;
 pub use field_operation::*;
 pub use type_option_builder::*;
+pub use type_option_migration::*;
 pub use type_options::*;
@@ -1,7 +1,10 @@
 use crate::entities::FieldType;
+use crate::services::errors::GridError;
 use crate::services::field::type_options::*;
 use bytes::Bytes;
+use flowy_error::FlowyResult;
 use grid_rev_model::TypeOptionDataSerializer;
+use std::collections::HashSet;
 
 pub trait TypeOptionBuilder {
     /// Returns the type of the type-option data
@@ -21,6 +24,9 @@ pub fn default_type_option_builder_from_type(field_type: &FieldType) -> Box<dyn
         FieldType::Checkbox => CheckboxTypeOptionPB::default().into(),
         FieldType::URL => URLTypeOptionPB::default().into(),
         FieldType::Checklist => ChecklistTypeOptionPB::default().into(),
+        FieldType::Formula => FormulaTypeOptionPB::default().into(),
+        FieldType::CreatedBy => CreatedByTypeOptionPB::default().into(),
+        FieldType::LastEditedBy => LastEditedByTypeOptionPB::default().into(),
     };
 
     type_option_builder_from_json_str(&s, field_type)
@@ -36,6 +42,9 @@ pub fn type_option_builder_from_json_str(s: &str, field_type: &FieldType) -> Box
         FieldType::Checkbox => Box::new(CheckboxTypeOptionBuilder::from_json_str(s)),
         FieldType::URL => Box::new(URLTypeOptionBuilder::from_json_str(s)),
         FieldType::Checklist => Box::new(ChecklistTypeOptionBuilder::from_json_str(s)),
+        FieldType::Formula => Box::new(FormulaTypeOptionBuilder::from_json_str(s)),
+        FieldType::CreatedBy => Box::new(CreatedByTypeOptionBuilder::from_json_str(s)),
+        FieldType::LastEditedBy => Box::new(LastEditedByTypeOptionBuilder::from_json_str(s)),
     }
 }
 
@@ -50,5 +59,49 @@ pub fn type_option_builder_from_bytes<T: Into<Bytes>>(bytes: T, field_type: &Fie
         FieldType::Checkbox => Box::new(CheckboxTypeOptionBuilder::from_protobuf_bytes(bytes)),
         FieldType::URL => Box::new(URLTypeOptionBuilder::from_protobuf_bytes(bytes)),
         FieldType::Checklist => Box::new(ChecklistTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::Formula => Box::new(FormulaTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::CreatedBy => Box::new(CreatedByTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::LastEditedBy => Box::new(LastEditedByTypeOptionBuilder::from_protobuf_bytes(bytes)),
     }
 }
+
+/// Checks that the bytes are the protobuf of the `field_type`'s type-option data and that the data
+/// is consistent, e.g. the ids of the select options are unique. Unlike
+/// [type_option_builder_from_bytes], which falls back to the default type-option data, it returns
+/// an error for the malformed bytes.
+pub fn validate_type_option<T: Into<Bytes>>(field_type: &FieldType, bytes: T) -> FlowyResult<()> {
+    let bytes = bytes.into();
+    match field_type {
+        FieldType::RichText => parse_type_option::<RichTextTypeOptionPB>(bytes).map(|_| ()),
+        FieldType::Number => parse_type_option::<NumberTypeOptionPB>(bytes).map(|_| ()),
+        FieldType::DateTime => parse_type_option::<DateTypeOptionPB>(bytes).map(|_| ()),
+        FieldType::SingleSelect => {
+            validate_select_options(&parse_type_option::<SingleSelectTypeOptionPB>(bytes)?.options)
+        }
+        FieldType::MultiSelect => {
+            validate_select_options(&parse_type_option::<MultiSelectTypeOptionPB>(bytes)?.options)
+        }
+        FieldType::Checkbox => parse_type_option::<CheckboxTypeOptionPB>(bytes).map(|_| ()),
+        FieldType::URL => parse_type_option::<URLTypeOptionPB>(bytes).map(|_| ()),
+        FieldType::Checklist => validate_select_options(&parse_type_option::<ChecklistTypeOptionPB>(bytes)?.options),
+        FieldType::Formula => parse_type_option::<FormulaTypeOptionPB>(bytes).map(|_| ()),
+        FieldType::CreatedBy => parse_type_option::<CreatedByTypeOptionPB>(bytes).map(|_| ()),
+        FieldType::LastEditedBy => parse_type_option::<LastEditedByTypeOptionPB>(bytes).map(|_| ()),
+    }
+}
+
+fn parse_type_option<T: TryFrom<Bytes>>(bytes: Bytes) -> FlowyResult<T> {
+    T::try_from(bytes).map_err(|_| GridError::invalid_type_option("The type-option data is malformed").into())
+}
+
+fn validate_select_options(options: &[SelectOptionPB]) -> FlowyResult<()> {
+    let mut option_ids = HashSet::new();
+    for option in options {
+        if !option_ids.insert(option.id.as_str()) {
+            return Err(
+                GridError::invalid_type_option(format!("The select option id:{} is duplicated", option.id)).into(),
+            );
+        }
+    }
+    Ok(())
+}
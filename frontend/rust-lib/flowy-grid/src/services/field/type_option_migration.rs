@@ -0,0 +1,83 @@
+use crate::entities::FieldType;
+use crate::services::field::type_option_builder_from_json_str;
+use grid_rev_model::{FieldRevision, FieldTypeRevision};
+use serde_json::Value;
+
+/// Upgrades the type-option data that was saved by an older version, which lacks the properties
+/// that were added since then, e.g. the `include_time` of the date type option. The missing
+/// properties are added with their default values, while the existing ones, including the ones
+/// that are unknown to this version, are kept as they are.
+///
+/// Returns None if nothing is missing or the data is not a JSON object.
+pub fn migrate_type_option_str(field_type: &FieldType, type_option_str: &str) -> Option<String> {
+    let mut value = serde_json::from_str::<Value>(type_option_str).ok()?;
+    let object = value.as_object_mut()?;
+
+    // The current schema serializes every property, so it tells which ones are missing
+    let current_str = type_option_builder_from_json_str(type_option_str, field_type)
+        .serializer()
+        .json_str();
+    let current_value = serde_json::from_str::<Value>(&current_str).ok()?;
+
+    let mut is_changed = false;
+    for (key, default_value) in current_value.as_object()? {
+        if !object.contains_key(key) {
+            object.insert(key.clone(), default_value.clone());
+            is_changed = true;
+        }
+    }
+
+    if is_changed {
+        serde_json::to_string(&value).ok()
+    } else {
+        None
+    }
+}
+
+/// Migrates each type-option data of the field, see [migrate_type_option_str]. The data of the
+/// unknown field types are skipped. Returns true if any of them is migrated.
+pub fn migrate_field_type_options(field_rev: &mut FieldRevision) -> bool {
+    let mut is_changed = false;
+    for (field_type_id, type_option_str) in field_rev.type_options.iter_mut() {
+        let field_type = match field_type_id
+            .parse::<FieldTypeRevision>()
+            .ok()
+            .and_then(FieldType::try_from_type_rev)
+        {
+            None => continue,
+            Some(field_type) => field_type,
+        };
+        if let Some(migrated_str) = migrate_type_option_str(&field_type, type_option_str) {
+            *type_option_str = migrated_str;
+            is_changed = true;
+        }
+    }
+    is_changed
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::field::{migrate_type_option_str, DateFormat, DateTypeOptionPB};
+    use grid_rev_model::TypeOptionDataDeserializer;
+
+    #[test]
+    fn migrate_date_type_option_without_include_time_test() {
+        let legacy_str = r#"{"date_format":"ISO","time_format":"TwentyFourHour"}"#;
+        let migrated_str = migrate_type_option_str(&FieldType::DateTime, legacy_str).unwrap();
+        let type_option = DateTypeOptionPB::from_json_str(&migrated_str);
+        assert!(matches!(type_option.date_format, DateFormat::ISO));
+        assert!(!type_option.include_time);
+
+        // The migrated data is up to date
+        assert!(migrate_type_option_str(&FieldType::DateTime, &migrated_str).is_none());
+    }
+
+    #[test]
+    fn migrate_type_option_keeps_unknown_property_test() {
+        let legacy_str = r#"{"date_format":"ISO","time_format":"TwentyFourHour","from_newer_version":true}"#;
+        let migrated_str = migrate_type_option_str(&FieldType::DateTime, legacy_str).unwrap();
+        assert!(migrated_str.contains(r#""from_newer_version":true"#));
+        assert!(migrated_str.contains(r#""include_time":false"#));
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::entities::FieldType;
-    use crate::services::cell::CellDataDecoder;
+    use crate::services::cell::{insert_checkbox_cell, CellDataDecoder, FromCellString};
     use crate::services::field::type_options::checkbox_type_option::*;
     use crate::services::field::FieldBuilder;
 
@@ -27,6 +27,33 @@ mod tests {
         assert_checkbox(&type_option, "", "", &field_type, &field_rev);
     }
 
+    #[test]
+    fn checkbox_default_state_test() {
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Checkbox).build();
+        assert!(is_checkbox_default(None));
+
+        let unchecked_cell_rev = insert_checkbox_cell(false, &field_rev);
+        assert!(!is_checkbox_default(Some(&unchecked_cell_rev)));
+
+        let checked_cell_rev = insert_checkbox_cell(true, &field_rev);
+        assert!(!is_checkbox_default(Some(&checked_cell_rev)));
+    }
+
+    #[test]
+    fn checkbox_label_test() {
+        let mut type_option = CheckboxTypeOptionPB::default();
+        let checked = CheckboxCellData::from_cell_str(CHECK).unwrap();
+        let unchecked = CheckboxCellData::from_cell_str(UNCHECK).unwrap();
+        assert_eq!(type_option.label(&checked), CHECK);
+        assert_eq!(type_option.label(&unchecked), UNCHECK);
+
+        type_option.checked_label = "Done".to_owned();
+        type_option.unchecked_label = "Pending".to_owned();
+        assert_eq!(type_option.label(&checked), "Done");
+        assert_eq!(type_option.label(&unchecked), "Pending");
+        assert_eq!(type_option.label(&CheckboxCellData::default()), "");
+    }
+
     fn assert_checkbox(
         type_option: &CheckboxTypeOptionPB,
         input_str: &str,
@@ -1,14 +1,14 @@
 use crate::entities::{CheckboxFilterPB, FieldType};
 use crate::impl_type_option;
-use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, DecodedCellData, FromCellString, TypeCellData};
 use crate::services::field::{
     default_order, BoxTypeOptionBuilder, CheckboxCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData,
-    TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, CHECK, UNCHECK,
 };
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
 use flowy_error::FlowyResult;
-use grid_rev_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use grid_rev_model::{CellRevision, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::str::FromStr;
@@ -23,6 +23,12 @@ impl CheckboxTypeOptionBuilder {
         self.0.is_selected = is_selected;
         self
     }
+
+    pub fn labels(mut self, checked_label: &str, unchecked_label: &str) -> Self {
+        self.0.checked_label = checked_label.to_owned();
+        self.0.unchecked_label = unchecked_label.to_owned();
+        self
+    }
 }
 
 impl TypeOptionBuilder for CheckboxTypeOptionBuilder {
@@ -39,9 +45,42 @@ impl TypeOptionBuilder for CheckboxTypeOptionBuilder {
 pub struct CheckboxTypeOptionPB {
     #[pb(index = 1)]
     pub is_selected: bool,
+
+    /// The text of a checked cell when it's converted to text. Falls back to [CHECK] if it's empty.
+    #[pb(index = 2)]
+    #[serde(default)]
+    pub checked_label: String,
+
+    /// The text of an unchecked cell when it's converted to text. Falls back to [UNCHECK] if it's
+    /// empty.
+    #[pb(index = 3)]
+    #[serde(default)]
+    pub unchecked_label: String,
 }
 impl_type_option!(CheckboxTypeOptionPB, FieldType::Checkbox);
 
+impl CheckboxTypeOptionPB {
+    /// Returns the label of the cell data. The empty cell, which was never checked or unchecked,
+    /// has no label.
+    pub fn label(&self, cell_data: &CheckboxCellData) -> String {
+        if cell_data.is_check() {
+            label_or(&self.checked_label, CHECK)
+        } else if cell_data.is_uncheck() {
+            label_or(&self.unchecked_label, UNCHECK)
+        } else {
+            "".to_owned()
+        }
+    }
+}
+
+fn label_or(label: &str, default_label: &str) -> String {
+    if label.is_empty() {
+        default_label.to_owned()
+    } else {
+        label.to_owned()
+    }
+}
+
 impl TypeOption for CheckboxTypeOptionPB {
     type CellData = CheckboxCellData;
     type CellChangeset = CheckboxCellChangeset;
@@ -129,6 +168,8 @@ impl TypeOptionCellDataFilter for CheckboxTypeOptionPB {
     }
 }
 
+/// The unchecked cells are ordered before the checked ones, so sorting by ascending puts the
+/// unchecked rows first and sorting by descending puts the checked rows first.
 impl TypeOptionCellDataCompare for CheckboxTypeOptionPB {
     fn apply_cmp(
         &self,
@@ -143,3 +184,15 @@ impl TypeOptionCellDataCompare for CheckboxTypeOptionPB {
         }
     }
 }
+
+/// Returns true if the checkbox cell is in its default state, which means it was never checked or
+/// unchecked. An explicitly unchecked cell is not in the default state although both look the
+/// same, so a form can tell whether the user interacted with the checkbox.
+pub fn is_checkbox_default(cell_rev: Option<&CellRevision>) -> bool {
+    cell_rev
+        .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+        .filter(|type_cell_data| type_cell_data.is_checkbox())
+        .and_then(|type_cell_data| CheckboxCellData::from_cell_str(&type_cell_data.cell_str).ok())
+        .map(|cell_data| cell_data.is_empty())
+        .unwrap_or(true)
+}
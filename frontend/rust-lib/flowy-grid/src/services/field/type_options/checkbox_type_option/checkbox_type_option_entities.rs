@@ -20,6 +20,56 @@ impl CheckboxCellData {
     }
 }
 
+/// The state of all the checkbox cells of a field. The header of the field uses it to display a
+/// checked, an unchecked or an indeterminate checkbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxAggregation {
+    /// There is no cell
+    Empty,
+    AllChecked,
+    AllUnchecked,
+    /// Some cells are checked and the others are not
+    Mixed,
+}
+
+impl CheckboxAggregation {
+    /// The cells that are not checked, including the empty cells, are treated as unchecked.
+    pub fn from_cell_data<T: IntoIterator<Item = CheckboxCellData>>(cell_data: T) -> Self {
+        Self::from_count(CheckboxCount::from_cell_data(cell_data))
+    }
+
+    pub fn from_count(count: CheckboxCount) -> Self {
+        match (count.checked, count.unchecked) {
+            (0, 0) => CheckboxAggregation::Empty,
+            (_, 0) => CheckboxAggregation::AllChecked,
+            (0, _) => CheckboxAggregation::AllUnchecked,
+            _ => CheckboxAggregation::Mixed,
+        }
+    }
+}
+
+/// The number of the checked and the unchecked cells of a checkbox field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CheckboxCount {
+    pub checked: usize,
+    pub unchecked: usize,
+}
+
+impl CheckboxCount {
+    /// The cells that are not checked, including the empty cells, are counted as unchecked.
+    pub fn from_cell_data<T: IntoIterator<Item = CheckboxCellData>>(cell_data: T) -> Self {
+        let mut count = CheckboxCount::default();
+        for cell_data in cell_data {
+            if cell_data.is_check() {
+                count.checked += 1;
+            } else {
+                count.unchecked += 1;
+            }
+        }
+        count
+    }
+}
+
 impl AsRef<[u8]> for CheckboxCellData {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
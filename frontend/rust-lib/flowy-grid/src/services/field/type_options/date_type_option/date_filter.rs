@@ -2,6 +2,31 @@ use crate::entities::{DateFilterConditionPB, DateFilterPB};
 use chrono::NaiveDateTime;
 
 impl DateFilterPB {
+    /// Returns true if the filter compares the cell with another date field's cell in the same row
+    pub fn is_field_comparison(&self) -> bool {
+        matches!(
+            self.condition,
+            DateFilterConditionPB::DateBeforeField | DateFilterConditionPB::DateAfterField
+        )
+    }
+
+    /// Compares the cell's date with the other field's date in the same row. The row is excluded
+    /// if either of the cells is empty.
+    pub fn is_visible_with_other_field<T: Into<Option<i64>>>(&self, cell_timestamp: T, other_timestamp: T) -> bool {
+        match (cell_timestamp.into(), other_timestamp.into()) {
+            (Some(timestamp), Some(other_timestamp)) => {
+                let cell_date = NaiveDateTime::from_timestamp(timestamp, 0).date();
+                let other_date = NaiveDateTime::from_timestamp(other_timestamp, 0).date();
+                match self.condition {
+                    DateFilterConditionPB::DateBeforeField => cell_date < other_date,
+                    DateFilterConditionPB::DateAfterField => cell_date > other_date,
+                    _ => true,
+                }
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_visible<T: Into<Option<i64>>>(&self, cell_timestamp: T) -> bool {
         match cell_timestamp.into() {
             None => DateFilterConditionPB::DateIsEmpty == self.condition,
@@ -34,7 +59,11 @@ impl DateFilterPB {
                         let end_time = NaiveDateTime::from_timestamp(*self.end.as_ref().unwrap(), 0);
                         let end_date = end_time.date();
 
-                        cell_date >= start_date && cell_date <= end_date
+                        if self.exclusive_end {
+                            cell_date >= start_date && cell_date < end_date
+                        } else {
+                            cell_date >= start_date && cell_date <= end_date
+                        }
                     }
                     Some(timestamp) => {
                         let expected_timestamp = NaiveDateTime::from_timestamp(timestamp, 0);
@@ -68,6 +97,7 @@ mod tests {
             timestamp: Some(1668387885),
             end: None,
             start: None,
+            ..Default::default()
         };
 
         for (val, visible) in vec![(1668387885, true), (1647251762, false)] {
@@ -81,6 +111,7 @@ mod tests {
             timestamp: Some(1668387885),
             start: None,
             end: None,
+            ..Default::default()
         };
 
         for (val, visible, msg) in vec![(1668387884, false, "1"), (1647251762, true, "2")] {
@@ -95,6 +126,7 @@ mod tests {
             timestamp: Some(1668387885),
             start: None,
             end: None,
+            ..Default::default()
         };
 
         for (val, visible) in vec![(1668387884, true), (1668387885, true)] {
@@ -108,6 +140,7 @@ mod tests {
             timestamp: Some(1668387885),
             start: None,
             end: None,
+            ..Default::default()
         };
 
         for (val, visible) in vec![(1668387888, false), (1668531885, true), (0, false)] {
@@ -122,6 +155,7 @@ mod tests {
             start: Some(1668272685), // 11/13
             end: Some(1668618285),   // 11/17
             timestamp: None,
+            ..Default::default()
         };
 
         for (val, visible, _msg) in vec![
@@ -133,6 +167,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn date_filter_within_exclusive_end_test() {
+        let mut filter = DateFilterPB {
+            condition: DateFilterConditionPB::DateWithIn,
+            start: Some(1668272685), // 11/13
+            end: Some(1668618285),   // 11/17
+            timestamp: None,
+            ..Default::default()
+        };
+
+        // The date on the end bound
+        assert!(filter.is_visible(1668618285));
+
+        filter.exclusive_end = true;
+        assert!(!filter.is_visible(1668618285));
+        assert!(filter.is_visible(1668272685));
+        assert!(filter.is_visible(1668531885));
+    }
+
     #[test]
     fn date_filter_is_empty_test() {
         let filter = DateFilterPB {
@@ -140,10 +193,33 @@ mod tests {
             start: None,
             end: None,
             timestamp: None,
+            ..Default::default()
         };
 
         for (val, visible) in vec![(None, true), (Some(123), false)] {
             assert_eq!(filter.is_visible(val), visible);
         }
     }
+
+    #[test]
+    fn date_filter_before_other_field_test() {
+        let filter = DateFilterPB {
+            condition: DateFilterConditionPB::DateBeforeField,
+            start: None,
+            end: None,
+            timestamp: None,
+            other_field_id: Some("start".to_owned()),
+            ..Default::default()
+        };
+
+        for (val, other_val, visible) in vec![
+            (Some(1668272685), Some(1668618285), true),
+            (Some(1668618285), Some(1668272685), false),
+            (Some(1668272685), Some(1668272685), false),
+            (None, Some(1668272685), false),
+            (Some(1668272685), None, false),
+        ] {
+            assert_eq!(filter.is_visible_with_other_field(val, other_val), visible);
+        }
+    }
 }
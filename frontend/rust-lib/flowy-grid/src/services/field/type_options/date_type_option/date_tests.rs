@@ -67,6 +67,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn date_type_option_all_day_test() {
+        let mut type_option = DateTypeOptionPB::default();
+        type_option.include_time = true;
+        type_option.all_day = true;
+        let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+
+        for time_format in TimeFormat::iter() {
+            type_option.time_format = time_format;
+            let time = match time_format {
+                TimeFormat::TwentyFourHour => "23:00",
+                TimeFormat::TwelveHour => "11:23 pm",
+            };
+            let changeset = DateCellChangeset {
+                date: Some("1647251762".to_owned()),
+                time: Some(time.to_owned()),
+                is_utc: false,
+            };
+            let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+            // The time is dropped, so the date starts at 2022-03-14 00:00:00 UTC
+            assert_eq!(cell_str, "1647216000");
+
+            let display_str = type_option.decode_cell_data_to_str(cell_data);
+            assert_eq!(display_str, "Mar 14,2022 All day");
+            assert_eq!(
+                type_option.timestamp_from_display_str(&display_str).unwrap(),
+                1647216000
+            );
+
+            let decoded_data = type_option
+                .decode_cell_str(cell_str, &FieldType::DateTime, &field_rev)
+                .unwrap();
+            let decoded_data = type_option.convert_to_protobuf(decoded_data);
+            assert!(decoded_data.is_all_day);
+            assert!(decoded_data.time.is_empty());
+        }
+    }
+
     #[test]
     fn date_type_option_invalid_date_str_test() {
         let type_option = DateTypeOptionPB::default();
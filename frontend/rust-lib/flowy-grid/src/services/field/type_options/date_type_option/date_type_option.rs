@@ -1,6 +1,7 @@
-use crate::entities::{DateFilterPB, FieldType};
+use crate::entities::{CalculationTypePB, DateFilterPB, FieldType};
 use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::errors::GridError;
 use crate::services::field::{
     default_order, BoxTypeOptionBuilder, DateCellChangeset, DateCellData, DateCellDataPB, DateFormat, TimeFormat,
     TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
@@ -8,12 +9,13 @@ use crate::services::field::{
 };
 use bytes::Bytes;
 use chrono::format::strftime::StrftimeItems;
-use chrono::{NaiveDateTime, Timelike};
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
 use flowy_derive::ProtoBuf;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use grid_rev_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use strum::IntoEnumIterator;
 
 // Date
 #[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
@@ -26,9 +28,19 @@ pub struct DateTypeOptionPB {
 
     #[pb(index = 3)]
     pub include_time: bool,
+
+    /// The dates of the cells last the whole day. Unlike disabling the `include_time`, the time
+    /// of the typed dates is dropped when they are stored, and the cells are displayed with the
+    /// [ALL_DAY] suffix instead of the time.
+    #[pb(index = 4)]
+    #[serde(default)]
+    pub all_day: bool,
 }
 impl_type_option!(DateTypeOptionPB, FieldType::DateTime);
 
+/// The suffix of the date cells whose field is [DateTypeOptionPB::all_day].
+pub const ALL_DAY: &str = "All day";
+
 impl TypeOption for DateTypeOptionPB {
     type CellData = DateCellData;
     type CellChangeset = DateCellChangeset;
@@ -67,13 +79,18 @@ impl DateTypeOptionPB {
         let date = format!("{}", utc.format_with_items(StrftimeItems::new(fmt)));
 
         let mut time = "".to_string();
-        if has_time && self.include_time {
+        if has_time && self.include_time && !self.all_day {
             let fmt = format!("{}{}", self.date_format.format_str(), self.time_format.format_str());
             time = format!("{}", utc.format_with_items(StrftimeItems::new(&fmt))).replace(&date, "");
         }
 
         let timestamp = native.timestamp();
-        DateCellDataPB { date, time, timestamp }
+        DateCellDataPB {
+            date,
+            time,
+            timestamp,
+            is_all_day: self.all_day,
+        }
     }
 
     fn date_fmt(&self, time: &Option<String>) -> String {
@@ -122,6 +139,67 @@ impl DateTypeOptionPB {
         Ok(utc.timestamp())
     }
 
+    /// Parses the date as it's displayed, e.g. "Mar 14, 2022", with or without the time. The
+    /// date format of the field is tried first, then the other date formats. The whitespaces
+    /// are ignored, so "Mar 14,2022" is accepted as well.
+    pub fn timestamp_from_display_str(&self, s: &str) -> FlowyResult<i64> {
+        let s = s.trim();
+        let s = if self.all_day {
+            s.strip_suffix(ALL_DAY).unwrap_or(s)
+        } else {
+            s
+        };
+        let s = s.split_whitespace().collect::<String>();
+        let time_fmt = self.time_format.format_str().replace(' ', "");
+        for date_format in std::iter::once(self.date_format).chain(DateFormat::iter()) {
+            let date_fmt = date_format.format_str().replace(' ', "");
+            if let Ok(date) = NaiveDate::parse_from_str(&s, &date_fmt) {
+                return Ok(self.utc_date_time_from_native(date.and_hms(0, 0, 0)).timestamp());
+            }
+            if let Ok(native) = NaiveDateTime::parse_from_str(&s, &format!("{}{}", date_fmt, time_fmt)) {
+                return Ok(self.utc_date_time_from_native(native).timestamp());
+            }
+        }
+        let msg = format!("Parse {} failed", s);
+        Err(FlowyError::new(ErrorCode::InvalidDateTimeFormat, &msg))
+    }
+
+    /// Returns the earliest or the latest date of the cells, formatted as the cells are displayed.
+    /// The empty cells are skipped, and an empty string is returned if all the cells are empty.
+    pub fn calculate_cell_strs<T: AsRef<str>>(
+        &self,
+        cell_strs: &[T],
+        calculation_type: &CalculationTypePB,
+    ) -> FlowyResult<String> {
+        let timestamps = cell_strs
+            .iter()
+            .flat_map(|cell_str| DateCellData::from_cell_str(cell_str.as_ref().trim()).ok()?.0);
+        let timestamp = match calculation_type {
+            CalculationTypePB::Earliest => timestamps.min(),
+            CalculationTypePB::Latest => timestamps.max(),
+            CalculationTypePB::Sum | CalculationTypePB::Average => {
+                // The date field can only be calculated by date
+                return Err(GridError::unsupported_field_type(FieldType::DateTime.into()).into());
+            }
+        };
+        Ok(match timestamp {
+            None => "".to_owned(),
+            Some(timestamp) => self.decode_cell_data_to_str(DateCellData(Some(timestamp))),
+        })
+    }
+
+    /// Returns the text of the cell after switching the field to text. Unlike the display string,
+    /// the time is appended if the `include_time` is enabled.
+    pub fn text_with_time(&self, cell_data: DateCellData) -> String {
+        // The time is empty if the include_time is disabled.
+        let date_cell_data = self.today_desc_from_timestamp(cell_data.clone());
+        if date_cell_data.time.is_empty() {
+            self.decode_cell_data_to_str(cell_data)
+        } else {
+            format!("{} {}", date_cell_data.date, date_cell_data.time)
+        }
+    }
+
     fn utc_date_time_from_native(&self, naive: chrono::NaiveDateTime) -> chrono::DateTime<chrono::Utc> {
         chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc)
     }
@@ -148,7 +226,12 @@ impl CellDataDecoder for DateTypeOptionPB {
     }
 
     fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
-        self.today_desc_from_timestamp(cell_data).date
+        let date_cell_data = self.today_desc_from_timestamp(cell_data);
+        if !date_cell_data.date.is_empty() && date_cell_data.is_all_day {
+            format!("{} {}", date_cell_data.date, ALL_DAY)
+        } else {
+            date_cell_data.date
+        }
     }
 }
 
@@ -160,6 +243,11 @@ impl CellDataChangeset for DateTypeOptionPB {
     ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
         let cell_data = match changeset.date_timestamp() {
             None => 0,
+            // The all-day date starts at the beginning of the day
+            Some(date_timestamp) if self.all_day => NaiveDateTime::from_timestamp(date_timestamp, 0)
+                .date()
+                .and_hms(0, 0, 0)
+                .timestamp(),
             Some(date_timestamp) => match (self.include_time, changeset.time) {
                 (true, Some(time)) => {
                     let time = Some(time.trim().to_uppercase());
@@ -221,6 +309,11 @@ impl DateTypeOptionBuilder {
         self.0.time_format = time_format;
         self
     }
+
+    pub fn all_day(mut self, all_day: bool) -> Self {
+        self.0.all_day = all_day;
+        self
+    }
 }
 impl TypeOptionBuilder for DateTypeOptionBuilder {
     fn field_type(&self) -> FieldType {
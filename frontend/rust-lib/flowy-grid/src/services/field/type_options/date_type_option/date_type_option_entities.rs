@@ -18,6 +18,10 @@ pub struct DateCellDataPB {
 
     #[pb(index = 3)]
     pub timestamp: i64,
+
+    /// The date lasts the whole day, so it has no time.
+    #[pb(index = 4)]
+    pub is_all_day: bool,
 }
 
 #[derive(Clone, Debug, Default, ProtoBuf)]
@@ -0,0 +1,490 @@
+use flowy_error::{FlowyError, FlowyResult};
+use rust_decimal::Decimal;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// The cell value of a formula that references a field that doesn't exist.
+pub const FORMULA_REF_ERROR: &str = "#REF!";
+/// The cell value of a formula that can't be evaluated, e.g. dividing by zero.
+pub const FORMULA_ERROR: &str = "#ERROR!";
+
+/// The value of a formula expression or of the field that the expression references.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormulaValue {
+    Number(Decimal),
+    Text(String),
+    Bool(bool),
+}
+
+impl FormulaValue {
+    /// The empty text is treated as zero, so that the empty number cells can be used in arithmetic.
+    fn as_number(&self) -> Result<Decimal, FormulaError> {
+        match self {
+            FormulaValue::Number(number) => Ok(*number),
+            FormulaValue::Bool(value) => Ok(if *value { Decimal::ONE } else { Decimal::ZERO }),
+            FormulaValue::Text(s) if s.trim().is_empty() => Ok(Decimal::ZERO),
+            FormulaValue::Text(s) => Decimal::from_str(s.trim()).map_err(|_| FormulaError::Invalid),
+        }
+    }
+
+    fn is_truthy(&self) -> bool {
+        match self {
+            FormulaValue::Number(number) => !number.is_zero(),
+            FormulaValue::Text(s) => !s.is_empty(),
+            FormulaValue::Bool(value) => *value,
+        }
+    }
+}
+
+impl ToString for FormulaValue {
+    fn to_string(&self) -> String {
+        match self {
+            FormulaValue::Number(number) => number.normalize().to_string(),
+            FormulaValue::Text(s) => s.clone(),
+            FormulaValue::Bool(value) => value.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormulaError {
+    /// The expression references a field that doesn't exist.
+    Reference,
+    Invalid,
+}
+
+impl FormulaError {
+    pub fn cell_str(&self) -> &'static str {
+        match self {
+            FormulaError::Reference => FORMULA_REF_ERROR,
+            FormulaError::Invalid => FORMULA_ERROR,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormulaOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Eq,
+    NotEq,
+    Less,
+    LessEq,
+    Greater,
+    GreaterEq,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormulaFunction {
+    Sum,
+    If,
+    Concat,
+}
+
+impl FormulaFunction {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "sum" => Some(FormulaFunction::Sum),
+            "if" => Some(FormulaFunction::If),
+            "concat" => Some(FormulaFunction::Concat),
+            _ => None,
+        }
+    }
+}
+
+/// The parsed formula expression. The fields are referenced by name in braces, e.g. `{Price} * 2`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormulaExpr {
+    Value(FormulaValue),
+    Field(String),
+    Negate(Box<FormulaExpr>),
+    Binary {
+        operator: FormulaOperator,
+        left: Box<FormulaExpr>,
+        right: Box<FormulaExpr>,
+    },
+    Function {
+        function: FormulaFunction,
+        args: Vec<FormulaExpr>,
+    },
+}
+
+impl FormulaExpr {
+    pub fn parse(s: &str) -> FlowyResult<FormulaExpr> {
+        let tokens = tokenize(s)?;
+        let mut parser = FormulaParser { tokens, position: 0 };
+        let expr = parser.parse_expr()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(token) => Err(syntax_error(format!("Unexpected {:?}", token))),
+        }
+    }
+
+    /// Returns the names of the fields that the expression references, in the order they appear.
+    pub fn field_names(&self) -> Vec<String> {
+        let mut names = vec![];
+        self.visit_field_names(&mut names);
+        names
+    }
+
+    fn visit_field_names(&self, names: &mut Vec<String>) {
+        match self {
+            FormulaExpr::Value(_) => {}
+            FormulaExpr::Field(name) => {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+            FormulaExpr::Negate(expr) => expr.visit_field_names(names),
+            FormulaExpr::Binary { left, right, .. } => {
+                left.visit_field_names(names);
+                right.visit_field_names(names);
+            }
+            FormulaExpr::Function { args, .. } => args.iter().for_each(|arg| arg.visit_field_names(names)),
+        }
+    }
+
+    /// Evaluates the expression. The `field_value` returns the value of the field with the given
+    /// name, or `None` if there is no such field.
+    pub fn evaluate<F>(&self, field_value: &F) -> Result<FormulaValue, FormulaError>
+    where
+        F: Fn(&str) -> Option<FormulaValue>,
+    {
+        match self {
+            FormulaExpr::Value(value) => Ok(value.clone()),
+            FormulaExpr::Field(name) => field_value(name).ok_or(FormulaError::Reference),
+            FormulaExpr::Negate(expr) => Ok(FormulaValue::Number(-expr.evaluate(field_value)?.as_number()?)),
+            FormulaExpr::Binary { operator, left, right } => {
+                let left = left.evaluate(field_value)?;
+                let right = right.evaluate(field_value)?;
+                evaluate_binary(*operator, &left, &right)
+            }
+            FormulaExpr::Function { function, args } => match function {
+                FormulaFunction::Sum => {
+                    let mut sum = Decimal::ZERO;
+                    for arg in args {
+                        sum = sum
+                            .checked_add(arg.evaluate(field_value)?.as_number()?)
+                            .ok_or(FormulaError::Invalid)?;
+                    }
+                    Ok(FormulaValue::Number(sum))
+                }
+                FormulaFunction::If => {
+                    if args.len() < 2 || args.len() > 3 {
+                        return Err(FormulaError::Invalid);
+                    }
+                    // Only the chosen branch is evaluated.
+                    if args[0].evaluate(field_value)?.is_truthy() {
+                        args[1].evaluate(field_value)
+                    } else {
+                        match args.get(2) {
+                            None => Ok(FormulaValue::Text("".to_owned())),
+                            Some(arg) => arg.evaluate(field_value),
+                        }
+                    }
+                }
+                FormulaFunction::Concat => {
+                    let mut s = String::new();
+                    for arg in args {
+                        s.push_str(&arg.evaluate(field_value)?.to_string());
+                    }
+                    Ok(FormulaValue::Text(s))
+                }
+            },
+        }
+    }
+}
+
+fn evaluate_binary(
+    operator: FormulaOperator,
+    left: &FormulaValue,
+    right: &FormulaValue,
+) -> Result<FormulaValue, FormulaError> {
+    let number = |value: Option<Decimal>| value.map(FormulaValue::Number).ok_or(FormulaError::Invalid);
+    match operator {
+        FormulaOperator::Add => number(left.as_number()?.checked_add(right.as_number()?)),
+        FormulaOperator::Sub => number(left.as_number()?.checked_sub(right.as_number()?)),
+        FormulaOperator::Mul => number(left.as_number()?.checked_mul(right.as_number()?)),
+        FormulaOperator::Div => number(left.as_number()?.checked_div(right.as_number()?)),
+        FormulaOperator::Eq => Ok(FormulaValue::Bool(compare(left, right) == Ordering::Equal)),
+        FormulaOperator::NotEq => Ok(FormulaValue::Bool(compare(left, right) != Ordering::Equal)),
+        FormulaOperator::Less => Ok(FormulaValue::Bool(compare(left, right) == Ordering::Less)),
+        FormulaOperator::LessEq => Ok(FormulaValue::Bool(compare(left, right) != Ordering::Greater)),
+        FormulaOperator::Greater => Ok(FormulaValue::Bool(compare(left, right) == Ordering::Greater)),
+        FormulaOperator::GreaterEq => Ok(FormulaValue::Bool(compare(left, right) != Ordering::Less)),
+    }
+}
+
+/// Compares the values as numbers if both of them are numeric, otherwise compares their text.
+fn compare(left: &FormulaValue, right: &FormulaValue) -> Ordering {
+    match (left, right) {
+        (FormulaValue::Text(left), FormulaValue::Text(right)) => left.cmp(right),
+        (FormulaValue::Bool(left), FormulaValue::Bool(right)) => left.cmp(right),
+        _ => match (left.as_number(), right.as_number()) {
+            (Ok(left), Ok(right)) => left.cmp(&right),
+            _ => left.to_string().cmp(&right.to_string()),
+        },
+    }
+}
+
+/// Replaces the references to the field named `old_name` with `new_name`. The text literals are
+/// kept as they are, even if they look like a reference.
+pub fn rename_field_reference(expression: &str, old_name: &str, new_name: &str) -> String {
+    let chars = expression.chars().collect::<Vec<char>>();
+    let mut output = String::with_capacity(expression.len());
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if c != '"' && c != '{' {
+            output.push(c);
+            index += 1;
+            continue;
+        }
+
+        let end_char = if c == '"' { '"' } else { '}' };
+        match chars[index + 1..].iter().position(|ch| *ch == end_char) {
+            None => {
+                output.extend(&chars[index..]);
+                break;
+            }
+            Some(len) => {
+                let end = index + 1 + len;
+                let content = chars[index + 1..end].iter().collect::<String>();
+                if c == '{' && content.trim() == old_name {
+                    output.push('{');
+                    output.push_str(new_name);
+                    output.push('}');
+                } else {
+                    output.extend(&chars[index..=end]);
+                }
+                index = end + 1;
+            }
+        }
+    }
+    output
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(Decimal),
+    Text(String),
+    Field(String),
+    Ident(String),
+    Operator(FormulaOperator),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+fn syntax_error(msg: String) -> FlowyError {
+    FlowyError::invalid_data().context(format!("Invalid formula: {}", msg))
+}
+
+fn tokenize(s: &str) -> FlowyResult<Vec<Token>> {
+    let chars = s.chars().collect::<Vec<char>>();
+    let mut tokens = vec![];
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if c.is_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit() || c == '.' {
+            let start = index;
+            while index < chars.len() && (chars[index].is_ascii_digit() || chars[index] == '.') {
+                index += 1;
+            }
+            let literal = chars[start..index].iter().collect::<String>();
+            let number =
+                Decimal::from_str(&literal).map_err(|_| syntax_error(format!("Invalid number {}", literal)))?;
+            tokens.push(Token::Number(number));
+            continue;
+        }
+
+        if c == '"' || c == '{' {
+            let end_char = if c == '"' { '"' } else { '}' };
+            let start = index + 1;
+            index = start;
+            while index < chars.len() && chars[index] != end_char {
+                index += 1;
+            }
+            if index == chars.len() {
+                return Err(syntax_error(format!("Missing {}", end_char)));
+            }
+            let content = chars[start..index].iter().collect::<String>();
+            index += 1;
+            if c == '"' {
+                tokens.push(Token::Text(content));
+            } else {
+                tokens.push(Token::Field(content.trim().to_owned()));
+            }
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let start = index;
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                index += 1;
+            }
+            tokens.push(Token::Ident(chars[start..index].iter().collect()));
+            continue;
+        }
+
+        let next = chars.get(index + 1).cloned();
+        let (token, len) = match (c, next) {
+            ('<', Some('=')) => (Token::Operator(FormulaOperator::LessEq), 2),
+            ('>', Some('=')) => (Token::Operator(FormulaOperator::GreaterEq), 2),
+            ('!', Some('=')) => (Token::Operator(FormulaOperator::NotEq), 2),
+            ('=', Some('=')) => (Token::Operator(FormulaOperator::Eq), 2),
+            ('=', _) => (Token::Operator(FormulaOperator::Eq), 1),
+            ('<', _) => (Token::Operator(FormulaOperator::Less), 1),
+            ('>', _) => (Token::Operator(FormulaOperator::Greater), 1),
+            ('+', _) => (Token::Operator(FormulaOperator::Add), 1),
+            ('-', _) => (Token::Operator(FormulaOperator::Sub), 1),
+            ('*', _) => (Token::Operator(FormulaOperator::Mul), 1),
+            ('/', _) => (Token::Operator(FormulaOperator::Div), 1),
+            ('(', _) => (Token::LeftParen, 1),
+            (')', _) => (Token::RightParen, 1),
+            (',', _) => (Token::Comma, 1),
+            _ => return Err(syntax_error(format!("Unexpected character {}", c))),
+        };
+        tokens.push(token);
+        index += len;
+    }
+    Ok(tokens)
+}
+
+/// A recursive descent parser. From the lowest precedence to the highest:
+/// comparison, addition and subtraction, multiplication and division, negation.
+struct FormulaParser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl FormulaParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek_operator(&self) -> Option<FormulaOperator> {
+        match self.peek() {
+            Some(Token::Operator(operator)) => Some(*operator),
+            _ => None,
+        }
+    }
+
+    fn parse_expr(&mut self) -> FlowyResult<FormulaExpr> {
+        let left = self.parse_additive()?;
+        match self.peek_operator() {
+            Some(
+                operator @ (FormulaOperator::Eq
+                | FormulaOperator::NotEq
+                | FormulaOperator::Less
+                | FormulaOperator::LessEq
+                | FormulaOperator::Greater
+                | FormulaOperator::GreaterEq),
+            ) => {
+                self.position += 1;
+                let right = self.parse_additive()?;
+                Ok(binary(operator, left, right))
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn parse_additive(&mut self) -> FlowyResult<FormulaExpr> {
+        let mut left = self.parse_multiplicative()?;
+        while let Some(operator @ (FormulaOperator::Add | FormulaOperator::Sub)) = self.peek_operator() {
+            self.position += 1;
+            let right = self.parse_multiplicative()?;
+            left = binary(operator, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_multiplicative(&mut self) -> FlowyResult<FormulaExpr> {
+        let mut left = self.parse_unary()?;
+        while let Some(operator @ (FormulaOperator::Mul | FormulaOperator::Div)) = self.peek_operator() {
+            self.position += 1;
+            let right = self.parse_unary()?;
+            left = binary(operator, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> FlowyResult<FormulaExpr> {
+        if self.peek_operator() == Some(FormulaOperator::Sub) {
+            self.position += 1;
+            let expr = self.parse_unary()?;
+            return Ok(FormulaExpr::Negate(Box::new(expr)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> FlowyResult<FormulaExpr> {
+        match self.advance() {
+            Some(Token::Number(number)) => Ok(FormulaExpr::Value(FormulaValue::Number(number))),
+            Some(Token::Text(s)) => Ok(FormulaExpr::Value(FormulaValue::Text(s))),
+            Some(Token::Field(name)) => Ok(FormulaExpr::Field(name)),
+            Some(Token::LeftParen) => {
+                let expr = self.parse_expr()?;
+                self.expect(Token::RightParen)?;
+                Ok(expr)
+            }
+            Some(Token::Ident(ident)) => match ident.to_lowercase().as_str() {
+                "true" => Ok(FormulaExpr::Value(FormulaValue::Bool(true))),
+                "false" => Ok(FormulaExpr::Value(FormulaValue::Bool(false))),
+                _ => {
+                    let function = FormulaFunction::from_name(&ident)
+                        .ok_or_else(|| syntax_error(format!("Unknown function {}", ident)))?;
+                    self.expect(Token::LeftParen)?;
+                    let args = self.parse_args()?;
+                    Ok(FormulaExpr::Function { function, args })
+                }
+            },
+            Some(token) => Err(syntax_error(format!("Unexpected {:?}", token))),
+            None => Err(syntax_error("Unexpected end of the formula".to_owned())),
+        }
+    }
+
+    fn parse_args(&mut self) -> FlowyResult<Vec<FormulaExpr>> {
+        let mut args = vec![];
+        if self.peek() == Some(&Token::RightParen) {
+            self.position += 1;
+            return Ok(args);
+        }
+        loop {
+            args.push(self.parse_expr()?);
+            match self.advance() {
+                Some(Token::Comma) => continue,
+                Some(Token::RightParen) => return Ok(args),
+                _ => return Err(syntax_error("Expected , or )".to_owned())),
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> FlowyResult<()> {
+        match self.advance() {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(syntax_error(format!("Expected {:?}", expected))),
+        }
+    }
+}
+
+fn binary(operator: FormulaOperator, left: FormulaExpr, right: FormulaExpr) -> FormulaExpr {
+    FormulaExpr::Binary {
+        operator,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
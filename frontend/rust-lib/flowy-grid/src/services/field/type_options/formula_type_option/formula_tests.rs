@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::cell::{insert_checkbox_cell, insert_number_cell, insert_text_cell};
+    use crate::services::field::*;
+    use grid_rev_model::{FieldRevision, RowRevision};
+    use std::sync::Arc;
+
+    #[test]
+    fn formula_arithmetic_test() {
+        assert_formula("1 + 2 * 3", "7");
+        assert_formula("(1 + 2) * 3", "9");
+        assert_formula("10 / 4 - 1", "1.5");
+        assert_formula("-2 * -3", "6");
+        assert_formula("1 / 0", FORMULA_ERROR);
+    }
+
+    #[test]
+    fn formula_comparison_test() {
+        assert_formula("1 < 2", "true");
+        assert_formula("2 <= 1", "false");
+        assert_formula("1 + 1 = 2", "true");
+        assert_formula(r#""a" != "b""#, "true");
+    }
+
+    #[test]
+    fn formula_function_test() {
+        assert_formula("sum(1, 2, 3)", "6");
+        assert_formula(r#"if(1 > 2, "yes", "no")"#, "no");
+        assert_formula(r#"if(true, "yes")"#, "yes");
+        assert_formula(r#"concat("a", 1, true)"#, "a1true");
+        assert_formula("max(1, 2)", FORMULA_ERROR);
+    }
+
+    #[test]
+    fn formula_syntax_error_test() {
+        assert!(FormulaExpr::parse("1 +").is_err());
+        assert!(FormulaExpr::parse("(1 + 2").is_err());
+        assert!(FormulaExpr::parse("{Price").is_err());
+        assert!(FormulaExpr::parse("1 2").is_err());
+    }
+
+    #[test]
+    fn formula_field_names_test() {
+        let expr = FormulaExpr::parse("{Price} * {Qty} + sum({Price}, { Tax })").unwrap();
+        assert_eq!(
+            expr.field_names(),
+            vec!["Price".to_owned(), "Qty".to_owned(), "Tax".to_owned()]
+        );
+    }
+
+    #[test]
+    fn formula_rename_field_test() {
+        let mut type_option = FormulaTypeOptionPB {
+            expression: r#"{Price} * { Price } + concat({Qty}, "{Price}")"#.to_owned(),
+        };
+        assert!(type_option.rename_field("Price", "Cost"));
+        assert_eq!(type_option.expression, r#"{Cost} * {Cost} + concat({Qty}, "{Price}")"#);
+        assert!(!type_option.rename_field("Tax", "Vat"));
+    }
+
+    #[test]
+    fn formula_multiplication_test() {
+        let price_field_rev = FieldBuilder::from_field_type(&FieldType::Number).name("Price").build();
+        let qty_field_rev = FieldBuilder::from_field_type(&FieldType::Number).name("Qty").build();
+        let mut row_rev = RowRevision::new("");
+        row_rev
+            .cells
+            .insert(price_field_rev.id.clone(), insert_number_cell(3, &price_field_rev));
+        row_rev
+            .cells
+            .insert(qty_field_rev.id.clone(), insert_number_cell(4, &qty_field_rev));
+        let field_revs = vec![Arc::new(price_field_rev), Arc::new(qty_field_rev)];
+
+        assert_eq!(compute("{Price} * {Qty}", &row_rev, &field_revs), "12");
+        assert_eq!(compute("{Price} * {Unknown}", &row_rev, &field_revs), FORMULA_REF_ERROR);
+    }
+
+    #[test]
+    fn formula_if_over_other_fields_test() {
+        let price_field_rev = FieldBuilder::from_field_type(&FieldType::Number).name("Price").build();
+        let urgent_field_rev = FieldBuilder::from_field_type(&FieldType::Checkbox)
+            .name("Urgent")
+            .build();
+        let name_field_rev = FieldBuilder::from_field_type(&FieldType::RichText).name("Name").build();
+        let mut row_rev = RowRevision::new("");
+        row_rev
+            .cells
+            .insert(price_field_rev.id.clone(), insert_number_cell(5, &price_field_rev));
+        row_rev.cells.insert(
+            urgent_field_rev.id.clone(),
+            insert_checkbox_cell(true, &urgent_field_rev),
+        );
+        row_rev.cells.insert(
+            name_field_rev.id.clone(),
+            insert_text_cell("Apple".to_owned(), &name_field_rev),
+        );
+        let field_revs = vec![
+            Arc::new(price_field_rev),
+            Arc::new(urgent_field_rev),
+            Arc::new(name_field_rev),
+        ];
+
+        let expression = r#"if({Urgent}, concat({Name}, ":", {Price} * 2), "-")"#;
+        assert_eq!(compute(expression, &row_rev, &field_revs), "Apple:10");
+        assert_eq!(
+            compute(r#"if({Price} >= 10, "High", "Low")"#, &row_rev, &field_revs),
+            "Low"
+        );
+    }
+
+    fn assert_formula(expression: &str, expected: &str) {
+        let row_rev = RowRevision::new("");
+        let field_revs: Vec<Arc<FieldRevision>> = vec![];
+        assert_eq!(compute(expression, &row_rev, &field_revs), expected);
+    }
+
+    fn compute(expression: &str, row_rev: &RowRevision, field_revs: &[Arc<FieldRevision>]) -> String {
+        let type_option = FormulaTypeOptionPB {
+            expression: expression.to_owned(),
+        };
+        type_option.compute_cell_str(row_rev, field_revs)
+    }
+}
@@ -0,0 +1,210 @@
+use crate::entities::{FieldType, TextFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{stringify_cell_data, CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    rename_field_reference, BoxTypeOptionBuilder, CheckboxCellData, FormulaError, FormulaExpr, FormulaValue,
+    NumberTypeOptionPB, StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{FlowyError, FlowyResult};
+use grid_rev_model::{FieldRevision, RowRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::str::FromStr;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct FormulaTypeOptionBuilder(FormulaTypeOptionPB);
+impl_into_box_type_option_builder!(FormulaTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(FormulaTypeOptionBuilder, FormulaTypeOptionPB);
+
+impl FormulaTypeOptionBuilder {
+    pub fn expression(mut self, expression: &str) -> Self {
+        self.0.expression = expression.to_owned();
+        self
+    }
+}
+
+impl TypeOptionBuilder for FormulaTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Formula
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
+
+/// The cells of the formula field are computed from the other fields of the same row. The
+/// `expression` references the fields by name, e.g. `{Price} * {Qty}`. It supports the arithmetic,
+/// the comparisons and the `sum`, `if` and `concat` functions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct FormulaTypeOptionPB {
+    #[pb(index = 1)]
+    #[serde(default)]
+    pub expression: String,
+}
+impl_type_option!(FormulaTypeOptionPB, FieldType::Formula);
+
+impl FormulaTypeOptionPB {
+    /// Returns the names of the fields that the expression references. It's empty if the
+    /// expression is invalid.
+    pub fn field_names(&self) -> Vec<String> {
+        match FormulaExpr::parse(&self.expression) {
+            Ok(expr) => expr.field_names(),
+            Err(_) => vec![],
+        }
+    }
+
+    /// Points the references to the field named `old_name` to its new name. Returns false if the
+    /// expression doesn't reference the field.
+    pub fn rename_field(&mut self, old_name: &str, new_name: &str) -> bool {
+        if !self.field_names().iter().any(|name| name == old_name) {
+            return false;
+        }
+        self.expression = rename_field_reference(&self.expression, old_name, new_name);
+        true
+    }
+
+    /// Evaluates the expression against the cells of the row. Returns `#REF!` if the expression
+    /// references an unknown field and `#ERROR!` if it can't be evaluated.
+    pub fn compute_cell_str(&self, row_rev: &RowRevision, field_revs: &[Arc<FieldRevision>]) -> String {
+        if self.expression.trim().is_empty() {
+            return "".to_owned();
+        }
+
+        let expr = match FormulaExpr::parse(&self.expression) {
+            Ok(expr) => expr,
+            Err(_) => return FormulaError::Invalid.cell_str().to_owned(),
+        };
+        let field_value = |name: &str| {
+            let field_rev = field_revs.iter().find(|field_rev| field_rev.name == name)?;
+            Some(formula_value_from_cell(row_rev, field_rev))
+        };
+        match expr.evaluate(&field_value) {
+            Ok(value) => value.to_string(),
+            Err(err) => err.cell_str().to_owned(),
+        }
+    }
+}
+
+/// The number cells are read as numbers, the checkbox cells as booleans, and other cells as
+/// their display text.
+fn formula_value_from_cell(row_rev: &RowRevision, field_rev: &FieldRevision) -> FormulaValue {
+    let field_type: FieldType = field_rev.ty.into();
+    let type_cell_data = match row_rev.cells.get(&field_rev.id) {
+        None => TypeCellData::from_field_type(&field_type),
+        Some(cell_rev) => {
+            TypeCellData::try_from(cell_rev).unwrap_or_else(|_| TypeCellData::from_field_type(&field_type))
+        }
+    };
+
+    match field_type {
+        FieldType::Number if type_cell_data.is_number() => {
+            let number_cell_data = NumberTypeOptionPB::from(field_rev).format_cell_data(&type_cell_data.cell_str);
+            match number_cell_data.ok().and_then(|cell_data| *cell_data.decimal()) {
+                None => FormulaValue::Text("".to_owned()),
+                Some(decimal) => FormulaValue::Number(decimal),
+            }
+        }
+        FieldType::Checkbox if type_cell_data.is_checkbox() => {
+            let is_check = CheckboxCellData::from_cell_str(&type_cell_data.cell_str)
+                .map(|cell_data| cell_data.is_check())
+                .unwrap_or(false);
+            FormulaValue::Bool(is_check)
+        }
+        FieldType::Formula => match Decimal::from_str(&type_cell_data.cell_str) {
+            Ok(decimal) => FormulaValue::Number(decimal),
+            Err(_) => FormulaValue::Text(type_cell_data.cell_str),
+        },
+        _ => {
+            let s = stringify_cell_data(
+                type_cell_data.cell_str,
+                &type_cell_data.field_type,
+                &field_type,
+                field_rev,
+            );
+            FormulaValue::Text(s)
+        }
+    }
+}
+
+impl TypeOption for FormulaTypeOptionPB {
+    type CellData = StrCellData;
+    type CellChangeset = String;
+    type CellProtobufType = StrCellData;
+    type CellFilter = TextFilterPB;
+}
+
+impl TypeOptionTransform for FormulaTypeOptionPB {}
+
+impl TypeOptionCellData for FormulaTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        cell_data
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        StrCellData::from_cell_str(&cell_str)
+    }
+}
+
+impl CellDataDecoder for FormulaTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        // The cells that were written before switching to the formula are stale until the
+        // formula is computed.
+        if !decoded_field_type.is_formula() {
+            return Ok(StrCellData::default());
+        }
+        StrCellData::from_cell_str(&cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        cell_data.to_string()
+    }
+}
+
+impl CellDataChangeset for FormulaTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        _changeset: <Self as TypeOption>::CellChangeset,
+        _type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        Err(FlowyError::invalid_data().context("The formula cell is computed, it can't be edited"))
+    }
+}
+
+impl TypeOptionCellDataFilter for FormulaTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_formula() {
+            return false;
+        }
+
+        filter.is_visible(cell_data)
+    }
+}
+
+impl TypeOptionCellDataCompare for FormulaTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        match (Decimal::from_str(cell_data), Decimal::from_str(other_cell_data)) {
+            (Ok(left), Ok(right)) => left.cmp(&right),
+            _ => cell_data.0.cmp(&other_cell_data.0),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod formula_expression;
+mod formula_tests;
+mod formula_type_option;
+
+pub use formula_expression::*;
+pub use formula_type_option::*;
@@ -1,17 +1,21 @@
 pub mod checkbox_type_option;
 pub mod date_type_option;
+pub mod formula_type_option;
 pub mod number_type_option;
 pub mod selection_type_option;
 pub mod text_type_option;
 mod type_option;
 mod type_option_cell;
 pub mod url_type_option;
+pub mod user_type_option;
 
 pub use checkbox_type_option::*;
 pub use date_type_option::*;
+pub use formula_type_option::*;
 pub use number_type_option::*;
 pub use selection_type_option::*;
 pub use text_type_option::*;
 pub use type_option::*;
 pub use type_option_cell::*;
 pub use url_type_option::*;
+pub use user_type_option::*;
@@ -1,6 +1,7 @@
 use flowy_derive::ProtoBuf_Enum;
 use lazy_static::lazy_static;
 
+use rust_decimal::{Decimal, RoundingStrategy};
 use rusty_money::define_currency_set;
 use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
@@ -51,6 +52,8 @@ pub enum NumberFormat {
     ArgentinePeso = 34,
     UruguayanPeso = 35,
     Percent = 36,
+    /// Displays the numbers in the scientific notation, e.g. `1.23e6`.
+    Scientific = 37,
 }
 
 impl std::default::Default for NumberFormat {
@@ -59,6 +62,92 @@ impl std::default::Default for NumberFormat {
     }
 }
 
+/// Decides how a number is rounded to the scale of the number field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum RoundingMode {
+    HalfUp = 0,
+    HalfEven = 1,
+    Floor = 2,
+    Ceil = 3,
+}
+
+impl std::default::Default for RoundingMode {
+    fn default() -> Self {
+        RoundingMode::HalfUp
+    }
+}
+
+impl RoundingMode {
+    pub fn round(&self, decimal: Decimal, scale: u32) -> Decimal {
+        let strategy = match self {
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+            RoundingMode::Ceil => RoundingStrategy::ToPositiveInfinity,
+        };
+        decimal.round_dp_with_strategy(scale, strategy)
+    }
+}
+
+/// Decides what a date cell is converted to when it's transformed into a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum DateToNumberUnit {
+    /// The number of seconds since the epoch, which is the timestamp of the date.
+    Seconds = 0,
+    /// The number of whole days since the epoch.
+    Days = 1,
+}
+
+impl std::default::Default for DateToNumberUnit {
+    fn default() -> Self {
+        DateToNumberUnit::Seconds
+    }
+}
+
+impl DateToNumberUnit {
+    pub fn convert_timestamp(&self, timestamp: i64) -> i64 {
+        match self {
+            DateToNumberUnit::Seconds => timestamp,
+            DateToNumberUnit::Days => timestamp.div_euclid(86400),
+        }
+    }
+}
+
+/// Decides which separators the typed numbers use. The numbers are stored with the `.` decimal
+/// separator and without the grouping separators whatever the locale is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum NumberLocale {
+    /// `1,234.56`
+    En = 0,
+    /// `1.234,56`
+    De = 1,
+    /// `1 234,56`
+    Fr = 2,
+}
+
+impl std::default::Default for NumberLocale {
+    fn default() -> Self {
+        NumberLocale::En
+    }
+}
+
+impl NumberLocale {
+    /// Converts the number typed in the locale to the canonical form, e.g. "1.234,56" to
+    /// "1234.56" for [NumberLocale::De]. The input of [NumberLocale::En] is returned as it is.
+    pub fn normalize(&self, s: &str) -> String {
+        let (grouping_separator, decimal_separator) = match self {
+            NumberLocale::En => return s.to_owned(),
+            NumberLocale::De => ('.', ','),
+            NumberLocale::Fr => (' ', ','),
+        };
+
+        s.chars()
+            .filter(|c| *c != grouping_separator && !(grouping_separator == ' ' && c.is_whitespace()))
+            .map(|c| if c == decimal_separator { '.' } else { c })
+            .collect()
+    }
+}
+
 define_currency_set!(
     number_currency {
         NUMBER : {
@@ -445,6 +534,7 @@ impl NumberFormat {
             NumberFormat::ArgentinePeso => number_currency::ARS,
             NumberFormat::UruguayanPeso => number_currency::UYU,
             NumberFormat::Percent => number_currency::PERCENT,
+            NumberFormat::Scientific => number_currency::NUMBER,
         }
     }
 
@@ -1,4 +1,4 @@
-use crate::entities::{NumberFilterConditionPB, NumberFilterPB};
+use crate::entities::{NumberFilterConditionPB, NumberFilterPB, NumberFilterRange};
 
 use crate::services::field::NumberCellData;
 
@@ -22,6 +22,10 @@ impl NumberFilterPB {
         match num_cell_data.decimal().as_ref() {
             None => false,
             Some(cell_decimal) => {
+                if self.condition == NumberFilterConditionPB::NumberIsBetween {
+                    return self.is_between(cell_decimal);
+                }
+
                 let decimal = Decimal::from_str(&self.content).unwrap_or_else(|_| Decimal::zero());
                 match self.condition {
                     NumberFilterConditionPB::Equal => cell_decimal == &decimal,
@@ -35,11 +39,25 @@ impl NumberFilterPB {
             }
         }
     }
+
+    fn is_between(&self, cell_decimal: &Decimal) -> bool {
+        let range = match NumberFilterRange::from_str(&self.content) {
+            Ok(range) => range,
+            Err(_) => return false,
+        };
+        match (Decimal::from_str(&range.min), Decimal::from_str(&range.max)) {
+            (Ok(min), Ok(max)) => {
+                let (min, max) = if min > max { (max, min) } else { (min, max) };
+                cell_decimal >= &min && cell_decimal <= &max
+            }
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::entities::{NumberFilterConditionPB, NumberFilterPB};
+    use crate::entities::{NumberFilterConditionPB, NumberFilterPB, NumberFilterRange};
     use crate::services::field::{NumberCellData, NumberFormat};
     #[test]
     fn number_filter_equal_test() {
@@ -82,4 +100,20 @@ mod tests {
             assert_eq!(number_filter.is_visible(&data), visible);
         }
     }
+
+    #[test]
+    fn number_filter_between_test() {
+        let content = NumberFilterRange {
+            min: "10".to_owned(),
+            max: "20".to_owned(),
+        };
+        let number_filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::NumberIsBetween,
+            content: content.to_string(),
+        };
+        for (num_str, visible) in [("10", true), ("15", true), ("20", true), ("21", false), ("", false)] {
+            let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
+            assert_eq!(number_filter.is_visible(&data), visible);
+        }
+    }
 }
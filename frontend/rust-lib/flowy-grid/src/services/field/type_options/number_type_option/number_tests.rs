@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod tests {
-    use crate::entities::FieldType;
-    use crate::services::cell::CellDataDecoder;
+    use crate::entities::{CalculationTypePB, FieldType};
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder};
     use crate::services::field::FieldBuilder;
 
-    use crate::services::field::{strip_currency_symbol, NumberFormat, NumberTypeOptionPB};
+    use crate::services::field::{
+        strip_currency_symbol, DateToNumberUnit, NumberFormat, NumberLocale, NumberTypeOptionPB, RoundingMode,
+        TypeOptionTransform,
+    };
     use grid_rev_model::FieldRevision;
     use strum::IntoEnumIterator;
 
@@ -107,6 +110,7 @@ mod tests {
                     assert_number(&type_option, "18443", "UYU18.443", &field_type, &field_rev)
                 }
                 NumberFormat::Percent => assert_number(&type_option, "18443", "18,443%", &field_type, &field_rev),
+                NumberFormat::Scientific => assert_number(&type_option, "18443", "1.84e4", &field_type, &field_rev),
             }
         }
     }
@@ -337,6 +341,12 @@ mod tests {
                     assert_number(&type_option, "10.1", "10.1%", &field_type, &field_rev);
                     assert_number(&type_option, "100", "100%", &field_type, &field_rev);
                 }
+                NumberFormat::Scientific => {
+                    assert_number(&type_option, "1234567", "1.23e6", &field_type, &field_rev);
+                    assert_number(&type_option, "0.000123", "1.23e-4", &field_type, &field_rev);
+                    assert_number(&type_option, "1.23e6", "1.23e6", &field_type, &field_rev);
+                    assert_number(&type_option, "", "", &field_type, &field_rev);
+                }
             }
         }
     }
@@ -426,10 +436,208 @@ mod tests {
                     assert_number(&type_option, "18443", "-UYU18.443", &field_type, &field_rev)
                 }
                 NumberFormat::Percent => assert_number(&type_option, "18443", "-18,443%", &field_type, &field_rev),
+                NumberFormat::Scientific => assert_number(&type_option, "18443", "-1.84e4", &field_type, &field_rev),
             }
         }
     }
 
+    /// Round the number to the scale with each rounding mode.
+    #[test]
+    fn number_type_option_rounding_mode_test() {
+        let field_type = FieldType::Number;
+        let field_rev = FieldBuilder::from_field_type(&field_type).build();
+        for (rounding_mode, expected_2_5, expected_3_5) in [
+            (RoundingMode::HalfUp, "3", "4"),
+            (RoundingMode::HalfEven, "2", "4"),
+            (RoundingMode::Floor, "2", "3"),
+            (RoundingMode::Ceil, "3", "4"),
+        ] {
+            let type_option = NumberTypeOptionPB {
+                scale: 0,
+                rounding_mode: Some(rounding_mode),
+                ..Default::default()
+            };
+            assert_number(&type_option, "2.5", expected_2_5, &field_type, &field_rev);
+            assert_number(&type_option, "3.5", expected_3_5, &field_type, &field_rev);
+        }
+
+        // The number is displayed as it is without the rounding mode
+        let type_option = NumberTypeOptionPB::default();
+        assert_number(&type_option, "2.5", "2.5", &field_type, &field_rev);
+    }
+
+    /// Writing a number with more digits than the scale, which is only rounded when it's displayed
+    /// unless the field rounds it on store.
+    #[test]
+    fn number_type_option_round_on_store_test() {
+        let field_type = FieldType::Number;
+        let field_rev = FieldBuilder::from_field_type(&field_type).build();
+        let mut type_option = NumberTypeOptionPB {
+            scale: 2,
+            rounding_mode: Some(RoundingMode::HalfUp),
+            ..Default::default()
+        };
+        let (cell_str, _) = type_option.apply_changeset("1.23456".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "1.23456");
+        assert_number(&type_option, &cell_str, "1.23", &field_type, &field_rev);
+
+        type_option.round_on_store = true;
+        let (cell_str, _) = type_option.apply_changeset("1.23456".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "1.23");
+        assert_number(&type_option, &cell_str, "1.23", &field_type, &field_rev);
+
+        // The number is stored and displayed as it is without the rounding mode
+        type_option.rounding_mode = None;
+        let (cell_str, _) = type_option.apply_changeset("1.23456".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "1.23456");
+        assert_number(&type_option, &cell_str, "1.23456", &field_type, &field_rev);
+    }
+
+    #[test]
+    fn number_type_option_accept_fractions_test() {
+        let mut type_option = NumberTypeOptionPB::default();
+        // The fraction is not a number unless the field accepts the fractions
+        let (_, cell_data) = type_option.apply_changeset("3 1/4".to_owned(), None).unwrap();
+        assert_eq!(cell_data.0, "");
+
+        type_option.accept_fractions = true;
+        let (cell_str, _) = type_option.apply_changeset("3 1/4".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "3.25");
+        let (cell_str, _) = type_option.apply_changeset("1/2".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "0.5");
+        let (cell_str, _) = type_option.apply_changeset("-1 1/2".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "-1.5");
+
+        assert!(type_option.apply_changeset("1/0".to_owned(), None).is_err());
+        assert!(type_option.apply_changeset("1/2/3".to_owned(), None).is_err());
+    }
+
+    /// Converting the date 2022/03/14 to a number with the seconds and the days units.
+    #[test]
+    fn number_type_option_transform_date_test() {
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Number).build();
+        let date_cell_str = "1647251762";
+        for (date_unit, expected_str) in [
+            (DateToNumberUnit::Seconds, "1647251762"),
+            (DateToNumberUnit::Days, "19065"),
+        ] {
+            let type_option = NumberTypeOptionPB {
+                date_unit,
+                ..Default::default()
+            };
+            let cell_data = type_option
+                .transform_type_option_cell_str(date_cell_str, &FieldType::DateTime, &field_rev)
+                .unwrap();
+            assert_eq!(type_option.decode_cell_data_to_str(cell_data), expected_str);
+        }
+
+        // The cells of the other field types are not transformed
+        let type_option = NumberTypeOptionPB::default();
+        assert!(type_option
+            .transform_type_option_cell_str("123", &FieldType::RichText, &field_rev)
+            .is_none());
+    }
+
+    /// Typing the numbers with the German separators.
+    #[test]
+    fn number_type_option_german_locale_test() {
+        let mut type_option = NumberTypeOptionPB {
+            locale: NumberLocale::De,
+            ..Default::default()
+        };
+        let (cell_str, _) = type_option.apply_changeset("1.234,56".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "1234.56");
+        assert_eq!(
+            type_option
+                .format_cell_data(&cell_str)
+                .unwrap()
+                .decimal()
+                .unwrap()
+                .to_string(),
+            "1234.56"
+        );
+
+        type_option.set_format(NumberFormat::EUR);
+        let (cell_str, _) = type_option.apply_changeset("1.000.000,5".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "1000000.5");
+
+        // The input of the default locale is stored as it's typed
+        let type_option = NumberTypeOptionPB::default();
+        let (cell_str, _) = type_option.apply_changeset("1234.56".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "1234.56");
+    }
+
+    #[test]
+    fn number_type_option_exceeds_safe_integer_test() {
+        let type_option = NumberTypeOptionPB::default();
+        let cell_data = type_option.format_cell_data("12345678901234567890").unwrap();
+        assert!(cell_data.exceeds_safe_integer());
+        assert_eq!(cell_data.to_string(), "12345678901234567890");
+
+        assert!(!type_option
+            .format_cell_data("9007199254740991")
+            .unwrap()
+            .exceeds_safe_integer());
+        assert!(type_option
+            .format_cell_data("-9007199254740992.5")
+            .unwrap()
+            .exceeds_safe_integer());
+        assert!(!type_option.format_cell_data("").unwrap().exceeds_safe_integer());
+
+        // The cell keeps all the digits of the number
+        let (cell_str, _) = type_option
+            .apply_changeset("12345678901234567890".to_owned(), None)
+            .unwrap();
+        assert_eq!(cell_str, "12345678901234567890");
+    }
+
+    /// Formatting the numbers in the scientific notation and parsing them back.
+    #[test]
+    fn number_type_option_scientific_format_test() {
+        let mut type_option = NumberTypeOptionPB::default();
+        type_option.set_format(NumberFormat::Scientific);
+        let field_type = FieldType::Number;
+        let field_rev = FieldBuilder::from_field_type(&field_type).build();
+        assert_number(&type_option, "1234567", "1.23e6", &field_type, &field_rev);
+        assert_eq!(type_option.number_str_from_display_str("1.23e6").unwrap(), "1230000");
+
+        // The number typed in the scientific notation is stored as the plain number
+        let (cell_str, cell_data) = type_option.apply_changeset("1.23e6".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "1230000");
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), "1.23e6");
+
+        type_option.significant_digits = Some(5);
+        assert_number(&type_option, "1234567", "1.2346e6", &field_type, &field_rev);
+        type_option.significant_digits = Some(1);
+        assert_number(&type_option, "1234567", "1e6", &field_type, &field_rev);
+    }
+
+    /// Calculating the cells, which has the decimal places of the currency, and the average of
+    /// the plain numbers is rounded.
+    #[test]
+    fn number_type_option_calculate_cell_strs_test() {
+        let mut type_option = NumberTypeOptionPB::default();
+        let cell_strs = ["1", "2", "", "7"];
+        let average = type_option
+            .calculate_cell_strs(&cell_strs, &CalculationTypePB::Average, false)
+            .unwrap();
+        assert_eq!(average.to_string(), "3.33333333");
+        let sum = type_option
+            .calculate_cell_strs(&cell_strs, &CalculationTypePB::Sum, false)
+            .unwrap();
+        assert_eq!(sum.to_string(), "10");
+
+        type_option.set_format(NumberFormat::USD);
+        let average = type_option
+            .calculate_cell_strs(&cell_strs, &CalculationTypePB::Average, false)
+            .unwrap();
+        assert_eq!(average.to_string(), "$3.33");
+        let sum = type_option
+            .calculate_cell_strs(&cell_strs, &CalculationTypePB::Sum, false)
+            .unwrap();
+        assert_eq!(sum.to_string(), "$10.00");
+    }
+
     fn assert_number(
         type_option: &NumberTypeOptionPB,
         input_str: &str,
@@ -1,10 +1,11 @@
-use crate::entities::{FieldType, NumberFilterPB};
+use crate::entities::{CalculationTypePB, FieldType, NumberFilterPB};
 use crate::impl_type_option;
-use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::errors::GridError;
 use crate::services::field::type_options::number_type_option::format::*;
 use crate::services::field::{
-    BoxTypeOptionBuilder, NumberCellData, StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData,
-    TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    BoxTypeOptionBuilder, CanonicalNumberChangeset, DateCellData, NumberCellData, StrCellData, TypeOption,
+    TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
@@ -41,6 +42,36 @@ impl NumberTypeOptionBuilder {
         self.0.sign_positive = positive;
         self
     }
+
+    pub fn rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.0.rounding_mode = Some(rounding_mode);
+        self
+    }
+
+    pub fn date_unit(mut self, date_unit: DateToNumberUnit) -> Self {
+        self.0.date_unit = date_unit;
+        self
+    }
+
+    pub fn locale(mut self, locale: NumberLocale) -> Self {
+        self.0.locale = locale;
+        self
+    }
+
+    pub fn significant_digits(mut self, significant_digits: u32) -> Self {
+        self.0.significant_digits = Some(significant_digits);
+        self
+    }
+
+    pub fn round_on_store(mut self, round_on_store: bool) -> Self {
+        self.0.round_on_store = round_on_store;
+        self
+    }
+
+    pub fn accept_fractions(mut self, accept_fractions: bool) -> Self {
+        self.0.accept_fractions = accept_fractions;
+        self
+    }
 }
 
 impl TypeOptionBuilder for NumberTypeOptionBuilder {
@@ -70,7 +101,48 @@ pub struct NumberTypeOptionPB {
 
     #[pb(index = 5)]
     pub name: String,
+
+    /// Rounds the numbers to the `scale` when they are displayed. The numbers are displayed as
+    /// they are if it's None.
+    #[pb(index = 6, one_of)]
+    #[serde(default)]
+    pub rounding_mode: Option<RoundingMode>,
+
+    /// The unit of the numbers that the date cells are converted to.
+    #[pb(index = 7)]
+    #[serde(default)]
+    pub date_unit: DateToNumberUnit,
+
+    /// The separators of the numbers that are typed into the cells.
+    #[pb(index = 8)]
+    #[serde(default)]
+    pub locale: NumberLocale,
+
+    /// The number of the significant digits of the [NumberFormat::Scientific] format. It's
+    /// [DEFAULT_SIGNIFICANT_DIGITS] if it's None.
+    #[pb(index = 9, one_of)]
+    #[serde(default)]
+    pub significant_digits: Option<u32>,
+
+    /// Rounds the numbers to the `scale` with the `rounding_mode` before they are stored. By
+    /// default, the cells keep the numbers as they are typed and the `scale` only applies when
+    /// they are displayed. Like the display, nothing is rounded if the `rounding_mode` is None.
+    #[pb(index = 10)]
+    #[serde(default)]
+    pub round_on_store: bool,
+
+    /// Accepts the fractions like "1/2" and the mixed numbers like "3 1/4", which are stored as
+    /// the decimal numbers, e.g. "0.5" and "3.25".
+    #[pb(index = 11)]
+    #[serde(default)]
+    pub accept_fractions: bool,
 }
+
+pub const DEFAULT_SIGNIFICANT_DIGITS: u32 = 3;
+
+/// The decimal places that the average of the cells is rounded to if the format of the field
+/// isn't a currency.
+pub const AVERAGE_DECIMAL_PLACES: u32 = 8;
 impl_type_option!(NumberTypeOptionPB, FieldType::Number);
 
 impl TypeOption for NumberTypeOptionPB {
@@ -101,16 +173,158 @@ impl NumberTypeOptionPB {
                 Ok(value, ..) => Ok(NumberCellData::from_decimal(value)),
                 Err(_) => Ok(NumberCellData::new()),
             },
+            NumberFormat::Scientific => {
+                if s.is_empty() {
+                    return Ok(NumberCellData::new());
+                }
+                match Decimal::from_str(s).or_else(|_| Decimal::from_scientific(s)) {
+                    Ok(decimal) => Ok(NumberCellData::from_scientific(
+                        decimal,
+                        self.sign_positive,
+                        self.significant_digits.unwrap_or(DEFAULT_SIGNIFICANT_DIGITS),
+                    )),
+                    Err(_) => Err(GridError::invalid_cell_data("Should only contain numbers").into()),
+                }
+            }
             _ => NumberCellData::from_format_str(s, self.sign_positive, &self.format),
         }
     }
 
+    /// Parses the number as it's displayed, e.g. "$1,200" or "1.2e3", into the plain number that
+    /// is stored in the cell, e.g. "1200". The separators are read with the `locale` of the field,
+    /// and the fractions like "3 1/4" are parsed if the field accepts them.
+    pub fn number_str_from_display_str(&self, s: &str) -> FlowyResult<String> {
+        if self.accept_fractions {
+            if let Some(value) = parse_fraction(s) {
+                return Ok(value?.to_string());
+            }
+        }
+        let mut num_str = strip_currency_symbol(self.locale.normalize(s.trim()));
+        num_str.retain(|c| c != ',' && !c.is_whitespace());
+        if num_str.is_empty() {
+            return Ok(num_str);
+        }
+        match Decimal::from_str(&num_str).or_else(|_| Decimal::from_scientific(&num_str)) {
+            Ok(decimal) => Ok(decimal.to_string()),
+            Err(_) => Err(GridError::invalid_cell_data("Should only contain numbers").into()),
+        }
+    }
+
+    /// Same as [Self::format_cell_data], but rounds the number with the `rounding_mode`.
+    fn format_display_cell_data(&self, s: &str) -> FlowyResult<NumberCellData> {
+        let cell_data = self.format_cell_data(s)?;
+        match (self.rounding_mode, cell_data.decimal()) {
+            (Some(rounding_mode), Some(decimal)) => {
+                let rounded = rounding_mode.round(*decimal, self.scale);
+                match self.format {
+                    NumberFormat::Num => Ok(NumberCellData::from_decimal(rounded)),
+                    _ => self.format_cell_data(&rounded.to_string()),
+                }
+            }
+            _ => Ok(cell_data),
+        }
+    }
+
+    /// Calculates the cells and formats the result with the format of the field. The cells that
+    /// can't be parsed are skipped. The empty cells count as zero if `empty_as_zero` is true,
+    /// otherwise they are skipped too, which changes the average but not the sum.
+    ///
+    /// The result of a currency format has the decimal places of the currency, and the average
+    /// of the other formats is rounded to [AVERAGE_DECIMAL_PLACES].
+    pub fn calculate_cell_strs<T: AsRef<str>>(
+        &self,
+        cell_strs: &[T],
+        calculation_type: &CalculationTypePB,
+        empty_as_zero: bool,
+    ) -> FlowyResult<NumberCellData> {
+        let decimals = cell_strs
+            .iter()
+            .flat_map(|cell_str| {
+                let cell_str = cell_str.as_ref().trim();
+                if cell_str.is_empty() {
+                    return if empty_as_zero { Some(Decimal::ZERO) } else { None };
+                }
+                self.format_cell_data(cell_str)
+                    .ok()
+                    .and_then(|cell_data| *cell_data.decimal())
+            })
+            .collect::<Vec<Decimal>>();
+
+        let sum = decimals.iter().copied().sum::<Decimal>();
+        let value = match calculation_type {
+            CalculationTypePB::Sum => sum,
+            CalculationTypePB::Average => {
+                if decimals.is_empty() {
+                    Decimal::ZERO
+                } else {
+                    sum / Decimal::from(decimals.len())
+                }
+            }
+            CalculationTypePB::Earliest | CalculationTypePB::Latest => {
+                // The number field can't be calculated by date
+                return Err(GridError::unsupported_field_type(FieldType::Number.into()).into());
+            }
+        };
+        let value = match self.format {
+            NumberFormat::Num | NumberFormat::Scientific => match calculation_type {
+                CalculationTypePB::Average => value.round_dp(AVERAGE_DECIMAL_PLACES),
+                _ => value,
+            },
+            // Display the result with the decimal places of the currency, e.g. "$3.00"
+            _ => {
+                let mut value = value;
+                value.rescale(self.format.currency().exponent);
+                value
+            }
+        };
+        self.format_cell_data(&value.to_string())
+    }
+
     pub fn set_format(&mut self, format: NumberFormat) {
         self.format = format;
         self.symbol = format.symbol();
     }
 }
 
+/// Parses the fraction like "1/2" or the mixed number like "3 1/4". Returns None if the string
+/// is not a fraction, or an error if it's not a valid one, e.g. "1/0".
+fn parse_fraction(s: &str) -> Option<FlowyResult<Decimal>> {
+    let s = s.trim();
+    if !s.contains('/') {
+        return None;
+    }
+
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        None => (false, s),
+        Some(unsigned) => (true, unsigned.trim_start()),
+    };
+    let (whole, fraction) = match unsigned.rsplit_once(char::is_whitespace) {
+        None => (None, unsigned),
+        Some((whole, fraction)) => (Some(whole.trim()), fraction),
+    };
+    let value = fraction.split_once('/').and_then(|(numerator, denominator)| {
+        let numerator = numerator.parse::<u64>().ok()?;
+        let denominator = denominator.parse::<u64>().ok()?;
+        if denominator == 0 {
+            return None;
+        }
+        let whole = match whole {
+            None => 0,
+            Some(whole) => whole.parse::<u64>().ok()?,
+        };
+        Some(Decimal::from(whole) + Decimal::from(numerator) / Decimal::from(denominator))
+    });
+    match value {
+        None => Some(Err(GridError::invalid_cell_data(format!(
+            "{} is not a valid fraction",
+            s
+        ))
+        .into())),
+        Some(value) if negative => Some(Ok(-value)),
+        Some(value) => Some(Ok(value)),
+    }
+}
+
 pub(crate) fn strip_currency_symbol<T: ToString>(s: T) -> String {
     let mut s = s.to_string();
     for symbol in CURRENCY_SYMBOL.iter() {
@@ -122,7 +336,27 @@ pub(crate) fn strip_currency_symbol<T: ToString>(s: T) -> String {
     s
 }
 
-impl TypeOptionTransform for NumberTypeOptionPB {}
+impl TypeOptionTransform for NumberTypeOptionPB {
+    fn transformable(&self) -> bool {
+        true
+    }
+
+    fn transform_type_option(&mut self, _old_type_option_field_type: FieldType, _old_type_option_data: String) {}
+
+    fn transform_type_option_cell_str(
+        &self,
+        cell_str: &str,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> Option<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_date() {
+            return None;
+        }
+
+        let timestamp = DateCellData::from_cell_str(cell_str).ok()?.0?;
+        Some(self.date_unit.convert_timestamp(timestamp).to_string().into())
+    }
+}
 
 impl CellDataDecoder for NumberTypeOptionPB {
     fn decode_cell_str(
@@ -136,12 +370,12 @@ impl CellDataDecoder for NumberTypeOptionPB {
         }
 
         let str_cell_data = self.decode_type_option_cell_str(cell_str)?;
-        let s = self.format_cell_data(&str_cell_data)?.to_string();
+        let s = self.format_display_cell_data(&str_cell_data)?.to_string();
         Ok(s.into())
     }
 
     fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
-        match self.format_cell_data(&cell_data) {
+        match self.format_display_cell_data(&cell_data) {
             Ok(cell_data) => cell_data.to_string(),
             Err(_) => "".to_string(),
         }
@@ -156,8 +390,28 @@ impl CellDataChangeset for NumberTypeOptionPB {
         changeset: <Self as TypeOption>::CellChangeset,
         _type_cell_data: Option<TypeCellData>,
     ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-        let data = changeset.trim().to_string();
-        let number_cell_data = self.format_cell_data(&data)?;
+        let mut data = match serde_json::from_str::<CanonicalNumberChangeset>(&changeset) {
+            Ok(canonical) => canonical.number,
+            Err(_) => match parse_fraction(&changeset) {
+                Some(value) if self.accept_fractions => value?.to_string(),
+                _ => self.locale.normalize(changeset.trim()),
+            },
+        };
+        // The numbers that are typed in the scientific notation are stored as the plain numbers,
+        // so they can be read with any format.
+        if let Ok(decimal) = Decimal::from_scientific(&data) {
+            data = decimal.to_string();
+        }
+        let mut number_cell_data = self.format_cell_data(&data)?;
+        if self.round_on_store {
+            if let (Some(rounding_mode), Some(decimal)) = (self.rounding_mode, *number_cell_data.decimal()) {
+                data = rounding_mode.round(decimal, self.scale).to_string();
+                number_cell_data = self.format_cell_data(&data)?;
+            }
+        }
+        if number_cell_data.exceeds_safe_integer() {
+            tracing::warn!("The number {} exceeds the safe integer range of f64", data);
+        }
         Ok((data, number_cell_data.to_string().into()))
     }
 }
@@ -198,6 +452,12 @@ impl std::default::Default for NumberTypeOptionPB {
             symbol,
             sign_positive: true,
             name: "Number".to_string(),
+            rounding_mode: None,
+            date_unit: DateToNumberUnit::default(),
+            locale: NumberLocale::default(),
+            significant_digits: None,
+            round_on_store: false,
+            accept_fractions: false,
         }
     }
 }
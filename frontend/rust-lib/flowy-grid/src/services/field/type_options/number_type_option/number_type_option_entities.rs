@@ -1,12 +1,18 @@
-use crate::services::cell::{CellBytesCustomParser, CellProtobufBlobParser, DecodedCellData};
+use crate::services::cell::{CellBytesCustomParser, CellProtobufBlobParser, DecodedCellData, ToCellChangesetString};
+use crate::services::errors::GridError;
 use crate::services::field::number_currency::Currency;
 use crate::services::field::{strip_currency_symbol, NumberFormat, STRIP_SYMBOL};
 use bytes::Bytes;
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::FlowyResult;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rusty_money::Money;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+/// The largest integer that a f64 represents exactly, 2^53 - 1.
+const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+
 #[derive(Default)]
 pub struct NumberCellData {
     decimal: Option<Decimal>,
@@ -40,7 +46,7 @@ impl NumberCellData {
                     if num_str.chars().all(char::is_numeric) {
                         Self::from_format_str(&num_str, sign_positive, format)
                     } else {
-                        Err(FlowyError::invalid_data().context("Should only contain numbers"))
+                        Err(GridError::invalid_cell_data("Should only contain numbers").into())
                     }
                 }
             },
@@ -54,6 +60,21 @@ impl NumberCellData {
         }
     }
 
+    /// Formats the number in the scientific notation with the `significant_digits`, e.g. "1.23e6"
+    /// for 1234567 with 3 significant digits.
+    pub fn from_scientific(mut decimal: Decimal, sign_positive: bool, significant_digits: u32) -> Self {
+        decimal.set_sign_positive(sign_positive);
+        let precision = significant_digits.max(1) as usize - 1;
+        let display = match decimal.to_f64() {
+            None => decimal.to_string(),
+            Some(value) => format!("{:.*e}", precision, value),
+        };
+        Self {
+            decimal: Some(decimal),
+            money: Some(display),
+        }
+    }
+
     pub fn from_money(money: Money<Currency>) -> Self {
         Self {
             decimal: Some(*money.amount()),
@@ -68,6 +89,16 @@ impl NumberCellData {
     pub fn is_empty(&self) -> bool {
         self.decimal.is_none()
     }
+
+    /// Returns true if the integer part of the number is out of the range of the integers that a
+    /// f64 represents exactly. The cell keeps the number as the entered string and it's parsed
+    /// into a [Decimal], so only the consumers that read the number as a f64 lose precision.
+    pub fn exceeds_safe_integer(&self) -> bool {
+        match self.decimal {
+            None => false,
+            Some(decimal) => decimal.trunc().abs() > Decimal::from(MAX_SAFE_INTEGER),
+        }
+    }
 }
 
 // impl FromStr for NumberCellData {
@@ -102,6 +133,19 @@ impl DecodedCellData for NumberCellData {
     }
 }
 
+/// The changeset of the number that is parsed from the display string of the cell. The number is
+/// in the canonical form already, e.g. "1234.56", so it's not read with the locale of the field.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CanonicalNumberChangeset {
+    pub number: String,
+}
+
+impl ToCellChangesetString for CanonicalNumberChangeset {
+    fn to_cell_changeset_str(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
 pub struct NumberCellDataParser();
 impl CellProtobufBlobParser for NumberCellDataParser {
     type Object = NumberCellData;
@@ -2,12 +2,14 @@ mod checklist_filter;
 mod checklist_type_option;
 mod multi_select_type_option;
 mod select_filter;
+mod select_option_recency;
 mod select_type_option;
 mod single_select_type_option;
 mod type_option_transform;
 
 pub use checklist_type_option::*;
 pub use multi_select_type_option::*;
+pub use select_option_recency::*;
 pub use select_type_option::*;
 pub use single_select_type_option::*;
 
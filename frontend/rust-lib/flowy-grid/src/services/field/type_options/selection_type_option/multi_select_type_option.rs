@@ -1,12 +1,13 @@
 use crate::entities::{FieldType, SelectOptionFilterPB};
 use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, FromCellString, TypeCellData};
+use crate::services::errors::GridError;
 use std::cmp::{min, Ordering};
 
 use crate::services::field::{
-    default_order, BoxTypeOptionBuilder, SelectOptionCellChangeset, SelectOptionCellDataPB, SelectOptionIds,
-    SelectOptionPB, SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption, TypeOptionBuilder,
-    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    cmp_select_option, default_order, BoxTypeOptionBuilder, SelectOptionCellChangeset, SelectOptionCellDataPB,
+    SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption,
+    TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
 };
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
@@ -22,6 +23,21 @@ pub struct MultiSelectTypeOptionPB {
 
     #[pb(index = 2)]
     pub disable_color: bool,
+
+    /// Sorts the options by their positions instead of their names if it's true.
+    #[pb(index = 3)]
+    #[serde(default)]
+    pub is_scale: bool,
+
+    /// Prefixes the option names with their icons in the text of the cells if it's true.
+    #[pb(index = 4)]
+    #[serde(default)]
+    pub include_icon_in_text: bool,
+
+    /// The maximum number of the options that a cell can select. None means unlimited.
+    #[pb(index = 5, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_selected: Option<i32>,
 }
 impl_type_option!(MultiSelectTypeOptionPB, FieldType::MultiSelect);
 
@@ -54,6 +70,10 @@ impl SelectTypeOptionSharedAction for MultiSelectTypeOptionPB {
     fn mut_options(&mut self) -> &mut Vec<SelectOptionPB> {
         &mut self.options
     }
+
+    fn include_icon_in_text(&self) -> bool {
+        self.include_icon_in_text
+    }
 }
 
 impl CellDataChangeset for MultiSelectTypeOptionPB {
@@ -62,11 +82,17 @@ impl CellDataChangeset for MultiSelectTypeOptionPB {
         changeset: <Self as TypeOption>::CellChangeset,
         type_cell_data: Option<TypeCellData>,
     ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-        let insert_option_ids = changeset
-            .insert_option_ids
-            .into_iter()
-            .filter(|insert_option_id| self.options.iter().any(|option| &option.id == insert_option_id))
-            .collect::<Vec<String>>();
+        // Keeps the order in which the options were inserted instead of the order of the options
+        // in the type option. The duplicated ids are ignored.
+        let mut insert_option_ids: Vec<String> = vec![];
+        for insert_option_id in changeset.insert_option_ids {
+            if self.options.iter().any(|option| option.id == insert_option_id)
+                && !insert_option_ids.contains(&insert_option_id)
+            {
+                insert_option_ids.push(insert_option_id);
+            }
+        }
+        let has_inserted = !insert_option_ids.is_empty();
 
         let select_option_ids = match type_cell_data {
             None => SelectOptionIds::from(insert_option_ids),
@@ -86,6 +112,18 @@ impl CellDataChangeset for MultiSelectTypeOptionPB {
                 select_ids
             }
         };
+
+        // Only the writes that select more options are rejected, so a cell that exceeds a
+        // lowered limit can still unselect its options.
+        if let Some(max_selected) = self.max_selected {
+            if has_inserted && select_option_ids.len() > max_selected.max(0) as usize {
+                return Err(GridError::invalid_cell_data(format!(
+                    "The cell can select at most {} options",
+                    max_selected
+                ))
+                .into());
+            }
+        }
         Ok((select_option_ids.to_string(), select_option_ids))
     }
 }
@@ -120,7 +158,7 @@ impl TypeOptionCellDataCompare for MultiSelectTypeOptionPB {
                     .get(i)
                     .and_then(|id| self.options.iter().find(|option| &option.id == id)),
             ) {
-                (Some(left), Some(right)) => left.name.cmp(&right.name),
+                (Some(left), Some(right)) => cmp_select_option(&self.options, self.is_scale, left, right),
                 (Some(_), None) => Ordering::Greater,
                 (None, Some(_)) => Ordering::Less,
                 (None, None) => default_order(),
@@ -142,6 +180,11 @@ impl MultiSelectTypeOptionBuilder {
         self.0.options.push(opt);
         self
     }
+
+    pub fn max_selected(mut self, max_selected: Option<i32>) -> Self {
+        self.0.max_selected = max_selected;
+        self
+    }
 }
 
 impl TypeOptionBuilder for MultiSelectTypeOptionBuilder {
@@ -157,10 +200,11 @@ impl TypeOptionBuilder for MultiSelectTypeOptionBuilder {
 #[cfg(test)]
 mod tests {
     use crate::entities::FieldType;
-    use crate::services::cell::CellDataChangeset;
+    use crate::services::cell::{stringify_cell_data, CellDataChangeset, TypeCellData};
     use crate::services::field::type_options::selection_type_option::*;
     use crate::services::field::{CheckboxTypeOptionBuilder, FieldBuilder, TypeOptionBuilder, TypeOptionTransform};
     use crate::services::field::{MultiSelectTypeOptionBuilder, MultiSelectTypeOptionPB};
+    use flowy_error::ErrorCode;
 
     #[test]
     fn multi_select_transform_with_checkbox_type_option_test() {
@@ -291,4 +335,94 @@ mod tests {
         let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
         assert!(select_option_ids.is_empty());
     }
+
+    #[test]
+    fn multi_select_archived_option_test() {
+        let google = SelectOptionPB::new("Google");
+        let facebook = SelectOptionPB::new("Facebook");
+        let multi_select = MultiSelectTypeOptionBuilder::default()
+            .add_option(google.clone())
+            .add_option(facebook.clone());
+
+        let mut field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+        let mut type_option = MultiSelectTypeOptionPB::from(&field_rev);
+        let changeset = SelectOptionCellChangeset::from_insert_option_id(&google.id);
+        let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
+
+        type_option.set_option_archived(&google.id, true).unwrap();
+        assert!(type_option.set_option_archived("unknown", true).is_err());
+        assert_eq!(type_option.active_options(), vec![facebook]);
+        field_rev.insert_type_option(&type_option);
+
+        // The cell that references the archived option still displays it
+        let cell_data = type_option.get_selected_options(SelectOptionIds::from(vec![google.id]));
+        assert_eq!(cell_data.select_options[0].name, "Google");
+        assert_eq!(cell_data.options.len(), 1);
+        assert_eq!(
+            stringify_cell_data(cell_str, &FieldType::MultiSelect, &FieldType::MultiSelect, &field_rev),
+            "Google"
+        );
+    }
+
+    #[test]
+    fn multi_select_keep_the_order_of_selected_options_test() {
+        let google = SelectOptionPB::new("Google");
+        let facebook = SelectOptionPB::new("Facebook");
+        let twitter = SelectOptionPB::new("Twitter");
+        let multi_select = MultiSelectTypeOptionBuilder::default()
+            .add_option(google.clone())
+            .add_option(facebook.clone())
+            .add_option(twitter.clone());
+
+        let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+        let type_option = MultiSelectTypeOptionPB::from(&field_rev);
+        let option_ids = vec![twitter.id.clone(), google.id.clone(), twitter.id.clone()];
+        let changeset = SelectOptionCellChangeset::from_insert_options(option_ids);
+        let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
+
+        let changeset = SelectOptionCellChangeset::from_insert_option_id(&facebook.id);
+        let type_cell_data = TypeCellData::new(cell_str, FieldType::MultiSelect);
+        let (cell_str, select_option_ids) = type_option.apply_changeset(changeset, Some(type_cell_data)).unwrap();
+        assert_eq!(&*select_option_ids, &vec![twitter.id, google.id, facebook.id]);
+
+        // The text transform stringifies the cell with the multi-select type option
+        assert_eq!(
+            stringify_cell_data(cell_str, &FieldType::MultiSelect, &FieldType::MultiSelect, &field_rev),
+            "Twitter,Google,Facebook"
+        );
+    }
+
+    #[test]
+    fn multi_select_max_selected_test() {
+        let google = SelectOptionPB::new("Google");
+        let facebook = SelectOptionPB::new("Facebook");
+        let twitter = SelectOptionPB::new("Twitter");
+        let multi_select = MultiSelectTypeOptionBuilder::default()
+            .add_option(google.clone())
+            .add_option(facebook.clone())
+            .add_option(twitter.clone())
+            .max_selected(Some(2));
+
+        let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+        let type_option = MultiSelectTypeOptionPB::from(&field_rev);
+        assert_eq!(type_option.max_selected, Some(2));
+
+        let changeset = SelectOptionCellChangeset::from_insert_options(vec![
+            google.id.clone(),
+            facebook.id.clone(),
+            twitter.id.clone(),
+        ]);
+        let error = type_option.apply_changeset(changeset, None).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidCellData.value());
+
+        let option_ids = vec![google.id, facebook.id];
+        let changeset = SelectOptionCellChangeset::from_insert_options(option_ids.clone());
+        let (cell_str, select_option_ids) = type_option.apply_changeset(changeset, None).unwrap();
+        assert_eq!(&*select_option_ids, &option_ids);
+
+        // Selecting one more option exceeds the limit as well
+        let changeset = SelectOptionCellChangeset::from_insert_option_id(&twitter.id);
+        let type_cell_data = TypeCellData::new(cell_str, FieldType::MultiSelect);
+        assert!(type_option.apply_changeset(changeset, Some(type_cell_data)).is_err());
+    }
 }
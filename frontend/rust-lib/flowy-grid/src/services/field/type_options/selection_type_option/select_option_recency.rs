@@ -0,0 +1,93 @@
+use crate::services::persistence::kv::{GridKVPersistence, KVTransaction, KeyValue};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use parking_lot::RwLock;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+pub type AtomicSelectOptionRecency = Arc<RwLock<SelectOptionRecency>>;
+
+/// The number of the recently-used options that are kept for each field.
+const MAX_RECENT_OPTIONS: usize = 10;
+
+/// The recently-used options of a field as they are saved in the key-value store.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RecentSelectOptionIdsPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub option_ids: Vec<String>,
+}
+
+impl std::convert::From<RecentSelectOptionIdsPB> for KeyValue {
+    fn from(value: RecentSelectOptionIdsPB) -> Self {
+        let key = recent_option_ids_key(&value.field_id);
+        let bytes: Bytes = value.try_into().unwrap();
+        KeyValue::new(key, bytes.to_vec())
+    }
+}
+
+fn recent_option_ids_key(field_id: &str) -> String {
+    format!("{}:recent_select_options", field_id)
+}
+
+/// Keeps the ids of the options that were recently written to the select cells of each field,
+/// most recent first, so the option picker can show them before the others. The lists are saved
+/// in the key-value store, and each one is loaded the first time its field is read or written.
+pub struct SelectOptionRecency {
+    option_ids_by_field_id: HashMap<String, VecDeque<String>>,
+    kv_persistence: Arc<GridKVPersistence>,
+}
+
+impl SelectOptionRecency {
+    pub fn new(kv_persistence: Arc<GridKVPersistence>) -> AtomicSelectOptionRecency {
+        Arc::new(RwLock::new(Self {
+            option_ids_by_field_id: HashMap::new(),
+            kv_persistence,
+        }))
+    }
+
+    /// Moves the `option_ids` to the front of the field's list, keeping their order, and drops
+    /// the oldest ids beyond [MAX_RECENT_OPTIONS]. The updated list is saved right away.
+    pub fn record(&mut self, field_id: &str, option_ids: &[String]) {
+        if option_ids.is_empty() {
+            return;
+        }
+        let recent_ids = self.load(field_id);
+        for option_id in option_ids.iter().rev() {
+            recent_ids.retain(|id| id != option_id);
+            recent_ids.push_front(option_id.clone());
+        }
+        recent_ids.truncate(MAX_RECENT_OPTIONS);
+
+        let value = RecentSelectOptionIdsPB {
+            field_id: field_id.to_owned(),
+            option_ids: recent_ids.iter().cloned().collect(),
+        };
+        if let Err(e) = self.kv_persistence.set(value) {
+            tracing::error!("Save the recent options of the field:{} failed: {:?}", field_id, e);
+        }
+    }
+
+    /// Returns the ids of the field's recently-used options, most recent first.
+    pub fn recent_option_ids(&mut self, field_id: &str) -> Vec<String> {
+        self.load(field_id).iter().cloned().collect()
+    }
+
+    fn load(&mut self, field_id: &str) -> &mut VecDeque<String> {
+        let kv_persistence = self.kv_persistence.clone();
+        self.option_ids_by_field_id
+            .entry(field_id.to_owned())
+            .or_insert_with(|| {
+                let key = recent_option_ids_key(field_id);
+                match kv_persistence.get::<RecentSelectOptionIdsPB>(&key) {
+                    Ok(value) => value.map(|value| value.option_ids.into()).unwrap_or_default(),
+                    Err(e) => {
+                        tracing::error!("Load the recent options of the field:{} failed: {:?}", field_id, e);
+                        VecDeque::new()
+                    }
+                }
+            })
+    }
+}
@@ -5,6 +5,7 @@ use crate::services::cell::{
     ToCellChangesetString,
 };
 
+use crate::services::errors::GridError;
 use crate::services::field::selection_type_option::type_option_transform::SelectOptionTypeOptionTransformHelper;
 use crate::services::field::{
     CheckboxCellData, ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SingleSelectTypeOptionPB, TypeOption,
@@ -12,10 +13,12 @@ use crate::services::field::{
 };
 use bytes::Bytes;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use flowy_error::{internal_error, ErrorCode, FlowyResult};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
 use grid_rev_model::{FieldRevision, TypeOptionDataSerializer};
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{HashMap, HashSet};
 
 pub const SELECTION_IDS_SEPARATOR: &str = ",";
 
@@ -30,6 +33,27 @@ pub struct SelectOptionPB {
 
     #[pb(index = 3)]
     pub color: SelectOptionColorPB,
+
+    /// A custom color in `#RRGGBB` format. The palette `color` is used if it's None.
+    #[pb(index = 4, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_color: Option<String>,
+
+    /// The archived option is hidden from the picker and doesn't get a group, but the cells that
+    /// already reference it still render it.
+    #[pb(index = 5)]
+    #[serde(default)]
+    pub archived: bool,
+
+    /// An emoji or icon that is shown before the name.
+    #[pb(index = 6, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// Explains what the option means, e.g. when a status is used. It's shown on hover.
+    #[pb(index = 7, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 pub fn gen_option_id() -> String {
@@ -42,6 +66,10 @@ impl SelectOptionPB {
             id: gen_option_id(),
             name: name.to_owned(),
             color: SelectOptionColorPB::default(),
+            custom_color: None,
+            archived: false,
+            icon: None,
+            description: None,
         }
     }
 
@@ -50,10 +78,75 @@ impl SelectOptionPB {
             id: nanoid!(4),
             name: name.to_owned(),
             color,
+            custom_color: None,
+            archived: false,
+            icon: None,
+            description: None,
+        }
+    }
+
+    /// Sets the custom color of the option. Returns an error if the `hex` is not in `#RRGGBB` format.
+    pub fn set_custom_color(&mut self, hex: &str) -> FlowyResult<()> {
+        if !is_hex_color(hex) {
+            let msg = format!("Invalid color: {}, expected the #RRGGBB format", hex);
+            return Err(FlowyError::invalid_data().context(msg));
+        }
+        self.custom_color = Some(hex.to_owned());
+        Ok(())
+    }
+
+    /// Removes the custom color, the option falls back to the palette color.
+    pub fn clear_custom_color(&mut self) {
+        self.custom_color = None;
+    }
+
+    /// Returns the name prefixed with the icon, e.g. `🔥 Urgent`. It's the name if there is no icon.
+    pub fn name_with_icon(&self) -> String {
+        match self.icon.as_ref().filter(|icon| !icon.is_empty()) {
+            None => self.name.clone(),
+            Some(icon) => format!("{} {}", icon, self.name),
+        }
+    }
+
+    /// Returns the name of the palette color, or the custom color if the option has one.
+    pub fn color_name(&self) -> String {
+        match self.custom_color.as_ref() {
+            None => self.color.name().to_owned(),
+            Some(custom_color) => custom_color.clone(),
+        }
+    }
+
+    /// Returns the hex of the text color that is readable on top of the option's color, which is
+    /// the custom color if the option has one.
+    pub fn contrast_text_hex(&self) -> &'static str {
+        match self.custom_color.as_ref() {
+            None => self.color.contrast_text_hex(),
+            Some(custom_color) => contrast_text_hex(custom_color),
         }
     }
 }
 
+pub(crate) fn is_hex_color(s: &str) -> bool {
+    s.len() == 7 && s.starts_with('#') && s[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Returns the hex of the text color that is readable on top of the `#RRGGBB` color. It's dark for
+/// the light colors and white for the dark ones.
+fn contrast_text_hex(hex: &str) -> &'static str {
+    let channel = |index: usize| {
+        hex.get(index..index + 2)
+            .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+            .unwrap_or_default() as f64
+    };
+    // The perceived brightness, see https://www.w3.org/TR/AERT/#color-contrast
+    let brightness = (channel(1) * 299.0 + channel(3) * 587.0 + channel(5) * 114.0) / 1000.0;
+    if brightness > 128.0 {
+        "#333333"
+    } else {
+        "#FFFFFF"
+    }
+}
+
 #[derive(ProtoBuf_Enum, PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
 #[repr(u8)]
 pub enum SelectOptionColorPB {
@@ -74,11 +167,65 @@ impl std::default::Default for SelectOptionColorPB {
     }
 }
 
+impl SelectOptionColorPB {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SelectOptionColorPB::Purple => "Purple",
+            SelectOptionColorPB::Pink => "Pink",
+            SelectOptionColorPB::LightPink => "LightPink",
+            SelectOptionColorPB::Orange => "Orange",
+            SelectOptionColorPB::Yellow => "Yellow",
+            SelectOptionColorPB::Lime => "Lime",
+            SelectOptionColorPB::Green => "Green",
+            SelectOptionColorPB::Aqua => "Aqua",
+            SelectOptionColorPB::Blue => "Blue",
+        }
+    }
+
+    /// Returns the hex of the color in the default palette, e.g. `#E8E0FF` for [SelectOptionColorPB::Purple].
+    pub fn hex(&self) -> &'static str {
+        match self {
+            SelectOptionColorPB::Purple => "#E8E0FF",
+            SelectOptionColorPB::Pink => "#FFE7FD",
+            SelectOptionColorPB::LightPink => "#FFE7EE",
+            SelectOptionColorPB::Orange => "#FFEFE3",
+            SelectOptionColorPB::Yellow => "#FFF2CD",
+            SelectOptionColorPB::Lime => "#F5FFDC",
+            SelectOptionColorPB::Green => "#DDFFD6",
+            SelectOptionColorPB::Aqua => "#DEFFF1",
+            SelectOptionColorPB::Blue => "#E1FBFF",
+        }
+    }
+
+    /// Returns the hex of the text color that is readable on top of the color. All the palette
+    /// colors are light, so it's dark for each of them.
+    pub fn contrast_text_hex(&self) -> &'static str {
+        contrast_text_hex(self.hex())
+    }
+}
+
 pub fn make_selected_options(ids: SelectOptionIds, options: &[SelectOptionPB]) -> Vec<SelectOptionPB> {
     ids.iter()
         .flat_map(|option_id| options.iter().find(|option| &option.id == option_id).cloned())
         .collect()
 }
+
+/// Compares two options by their names. If the options form an ordinal scale, e.g. Low < Medium < High,
+/// they are compared by their positions in `options` instead.
+pub(crate) fn cmp_select_option(
+    options: &[SelectOptionPB],
+    is_scale: bool,
+    left: &SelectOptionPB,
+    right: &SelectOptionPB,
+) -> Ordering {
+    if is_scale {
+        let position = |target: &SelectOptionPB| options.iter().position(|option| option.id == target.id);
+        position(left).cmp(&position(right))
+    } else {
+        left.name.cmp(&right.name)
+    }
+}
+
 /// Defines the shared actions used by SingleSelect or Multi-Select.
 pub trait SelectTypeOptionSharedAction: TypeOptionDataSerializer + Send + Sync {
     /// Returns `None` means there is no limited
@@ -105,6 +252,107 @@ pub trait SelectTypeOptionSharedAction: TypeOptionDataSerializer + Send + Sync {
         }
     }
 
+    /// Renames the options in one go. The ids of the options are unchanged, so the cells that
+    /// reference the options are unaffected. Nothing is renamed if any of the renames is invalid.
+    fn rename_options(&mut self, renames: Vec<SelectOptionRenamePB>) -> FlowyResult<()> {
+        let mut options = self.options().clone();
+        for rename in renames {
+            match options.iter_mut().find(|option| option.id == rename.option_id) {
+                None => return Err(GridError::option_not_found(&rename.option_id).into()),
+                Some(option) => option.name = rename.name,
+            }
+        }
+
+        let mut names = HashSet::new();
+        if let Some(option) = options.iter().find(|option| !names.insert(option.name.as_str())) {
+            return Err(GridError::duplicate_option_name(&option.name).into());
+        }
+
+        *self.mut_options() = options;
+        Ok(())
+    }
+
+    /// Archives or restores the option. Returns an error if the option doesn't exist.
+    fn set_option_archived(&mut self, option_id: &str, archived: bool) -> FlowyResult<()> {
+        match self.mut_options().iter_mut().find(|option| option.id == option_id) {
+            None => Err(GridError::option_not_found(option_id).into()),
+            Some(option) => {
+                option.archived = archived;
+                Ok(())
+            }
+        }
+    }
+
+    /// Sets the palette color of the option and clears its custom color, otherwise the new color
+    /// wouldn't be shown. Returns an error if the option doesn't exist.
+    fn set_option_color(&mut self, option_id: &str, color: SelectOptionColorPB) -> FlowyResult<()> {
+        match self.mut_options().iter_mut().find(|option| option.id == option_id) {
+            None => Err(GridError::option_not_found(option_id).into()),
+            Some(option) => {
+                option.color = color;
+                option.custom_color = None;
+                Ok(())
+            }
+        }
+    }
+
+    /// Inserts a copy of the option right after it and returns the copy. The copy has a new id and
+    /// its name ends with " (copy)", so no cell selects it. Returns an error if the option doesn't
+    /// exist.
+    fn duplicate_option(&mut self, option_id: &str) -> FlowyResult<SelectOptionPB> {
+        let index = match self.options().iter().position(|option| option.id == option_id) {
+            None => return Err(GridError::option_not_found(option_id).into()),
+            Some(index) => index,
+        };
+        let mut copy = self.options()[index].clone();
+        copy.id = gen_option_id();
+        copy.name = format!("{} (copy)", copy.name);
+        // The names of the options are unique
+        while self.option_id_for_name(&copy.name).is_some() {
+            copy.name = format!("{} (copy)", copy.name);
+        }
+        self.mut_options().insert(index + 1, copy.clone());
+        Ok(copy)
+    }
+
+    /// Prefixes the names of the options with their icons when the cells are converted to text.
+    fn include_icon_in_text(&self) -> bool {
+        false
+    }
+
+    /// Returns the options that are not archived.
+    fn active_options(&self) -> Vec<SelectOptionPB> {
+        self.options()
+            .iter()
+            .filter(|option| !option.archived)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the id of the option with the `name`. The transforms look up the option before
+    /// creating one, so the cells with the same content are mapped to the same option.
+    fn option_id_for_name(&self, name: &str) -> Option<String> {
+        self.options()
+            .iter()
+            .find(|option| option.name == name)
+            .map(|option| option.id.clone())
+    }
+
+    /// Returns the options ordered by the number of the cells that select them, the most used
+    /// option first. The options that are used equally often keep their stored order.
+    fn options_by_usage(&self, cell_data: &[SelectOptionIds]) -> Vec<SelectOptionPB> {
+        let mut usage: HashMap<&str, usize> = HashMap::new();
+        for ids in cell_data {
+            for option_id in ids.iter() {
+                *usage.entry(option_id.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut options = self.options().clone();
+        options.sort_by_key(|option| Reverse(usage.get(option.id.as_str()).copied().unwrap_or_default()));
+        options
+    }
+
     fn create_option(&self, name: &str) -> SelectOptionPB {
         let color = new_select_option_color(self.options());
         SelectOptionPB::with_color(name, color)
@@ -120,7 +368,7 @@ pub trait SelectTypeOptionSharedAction: TypeOptionDataSerializer + Send + Sync {
             }
         }
         SelectOptionCellDataPB {
-            options: self.options().clone(),
+            options: self.active_options(),
             select_options,
         }
     }
@@ -149,6 +397,14 @@ where
         );
     }
 
+    fn transform_type_option_with_cells(&mut self, old_type_option_field_type: FieldType, old_cell_strs: &[String]) {
+        SelectOptionTypeOptionTransformHelper::transform_type_option_with_cells(
+            self,
+            &old_type_option_field_type,
+            old_cell_strs,
+        );
+    }
+
     fn transform_type_option_cell_str(
         &self,
         cell_str: &str,
@@ -169,7 +425,10 @@ where
                 }
                 Err(_) => None,
             },
-            FieldType::RichText => SelectOptionIds::from_cell_str(cell_str).ok(),
+            FieldType::RichText => match self.option_id_for_name(cell_str.trim()) {
+                Some(option_id) => Some(SelectOptionIds::from(vec![option_id])),
+                None => SelectOptionIds::from_cell_str(cell_str).ok(),
+            },
             _ => Some(SelectOptionIds::from(vec![])),
         }
     }
@@ -189,10 +448,17 @@ where
     }
 
     fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        let include_icon = self.include_icon_in_text();
         self.get_selected_options(cell_data)
             .select_options
             .into_iter()
-            .map(|option| option.name)
+            .map(|option| {
+                if include_icon {
+                    option.name_with_icon()
+                } else {
+                    option.name
+                }
+            })
             .collect::<Vec<String>>()
             .join(SELECTION_IDS_SEPARATOR)
     }
@@ -499,6 +765,16 @@ pub struct SelectOptionChangesetPB {
     pub delete_options: Vec<SelectOptionPB>,
 }
 
+/// Renames the option with `option_id` to `name`.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct SelectOptionRenamePB {
+    #[pb(index = 1)]
+    pub option_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+}
+
 pub struct SelectOptionChangeset {
     pub cell_path: CellPathParams,
     pub insert_options: Vec<SelectOptionPB>,
@@ -511,6 +787,16 @@ impl TryInto<SelectOptionChangeset> for SelectOptionChangesetPB {
 
     fn try_into(self) -> Result<SelectOptionChangeset, Self::Error> {
         let cell_identifier = self.cell_identifier.try_into()?;
+        let is_invalid_color = self
+            .insert_options
+            .iter()
+            .chain(self.update_options.iter())
+            .filter_map(|option| option.custom_color.as_ref())
+            .any(|hex| !is_hex_color(hex));
+        if is_invalid_color {
+            return Err(ErrorCode::InvalidData);
+        }
+
         Ok(SelectOptionChangeset {
             cell_path: cell_identifier,
             insert_options: self.insert_options,
@@ -531,3 +817,32 @@ impl std::convert::From<SelectOptionCellDataPB> for SelectedSelectOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::services::field::{SelectOptionColorPB, SelectOptionPB};
+
+    #[test]
+    fn select_option_color_hex_test() {
+        assert_eq!(SelectOptionColorPB::Purple.hex(), "#E8E0FF");
+        assert_eq!(SelectOptionColorPB::Purple.contrast_text_hex(), "#333333");
+
+        assert_eq!(SelectOptionColorPB::Yellow.hex(), "#FFF2CD");
+        assert_eq!(SelectOptionColorPB::Yellow.contrast_text_hex(), "#333333");
+
+        assert_eq!(SelectOptionColorPB::Blue.hex(), "#E1FBFF");
+        assert_eq!(SelectOptionColorPB::Blue.contrast_text_hex(), "#333333");
+    }
+
+    #[test]
+    fn select_option_custom_color_contrast_test() {
+        let mut option = SelectOptionPB::new("Urgent");
+        assert_eq!(option.contrast_text_hex(), "#333333");
+
+        option.set_custom_color("#1F2A44").unwrap();
+        assert_eq!(option.contrast_text_hex(), "#FFFFFF");
+
+        option.set_custom_color("#FFD166").unwrap();
+        assert_eq!(option.contrast_text_hex(), "#333333");
+    }
+}
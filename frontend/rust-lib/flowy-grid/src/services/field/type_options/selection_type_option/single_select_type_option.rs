@@ -4,11 +4,11 @@ use crate::services::cell::{CellDataChangeset, FromCellString, TypeCellData};
 use std::cmp::Ordering;
 
 use crate::services::field::{
-    default_order, BoxTypeOptionBuilder, SelectOptionCellDataPB, SelectedSelectOptions, TypeOption, TypeOptionBuilder,
-    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    cmp_select_option, default_order, BoxTypeOptionBuilder, SelectOptionCellDataPB, SelectedSelectOptions, TypeOption,
+    TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
 };
 use crate::services::field::{
-    SelectOptionCellChangeset, SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction,
+    SelectOptionCellChangeset, SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction, SELECTION_IDS_SEPARATOR,
 };
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
@@ -24,9 +24,45 @@ pub struct SingleSelectTypeOptionPB {
 
     #[pb(index = 2)]
     pub disable_color: bool,
+
+    /// Sorts the options by their positions instead of their names if it's true.
+    #[pb(index = 3)]
+    #[serde(default)]
+    pub is_scale: bool,
+
+    /// Prefixes the option names with their icons in the text of the cells if it's true.
+    #[pb(index = 4)]
+    #[serde(default)]
+    pub include_icon_in_text: bool,
+
+    /// Appends the color names of the options in brackets in the text of the cells if it's true,
+    /// when the field is switched to a text field. So the text keeps the colors of the options.
+    #[pb(index = 5)]
+    #[serde(default)]
+    pub include_color_in_text: bool,
 }
 impl_type_option!(SingleSelectTypeOptionPB, FieldType::SingleSelect);
 
+impl SingleSelectTypeOptionPB {
+    /// Returns the text of the cell with the color names of the options in brackets, e.g.
+    /// `Urgent [Orange]`. It's used when the field is switched to a text field.
+    pub fn text_with_colors(&self, cell_data: SelectOptionIds) -> String {
+        self.get_selected_options(cell_data)
+            .select_options
+            .into_iter()
+            .map(|option| {
+                let text = if self.include_icon_in_text {
+                    option.name_with_icon()
+                } else {
+                    option.name.clone()
+                };
+                format!("{} [{}]", text, option.color_name())
+            })
+            .collect::<Vec<String>>()
+            .join(SELECTION_IDS_SEPARATOR)
+    }
+}
+
 impl TypeOption for SingleSelectTypeOptionPB {
     type CellData = SelectOptionIds;
     type CellChangeset = SelectOptionCellChangeset;
@@ -40,7 +76,16 @@ impl TypeOptionCellData for SingleSelectTypeOptionPB {
     }
 
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
-        SelectOptionIds::from_cell_str(&cell_str)
+        let mut select_option_ids = SelectOptionIds::from_cell_str(&cell_str)?;
+        // The malformed cell may contain more than one option id, only the first one is kept.
+        if select_option_ids.len() > 1 {
+            tracing::warn!(
+                "The single select cell should contain one option id, but got: {}",
+                cell_str
+            );
+            select_option_ids.truncate(1);
+        }
+        Ok(select_option_ids)
     }
 }
 
@@ -56,6 +101,10 @@ impl SelectTypeOptionSharedAction for SingleSelectTypeOptionPB {
     fn mut_options(&mut self) -> &mut Vec<SelectOptionPB> {
         &mut self.options
     }
+
+    fn include_icon_in_text(&self) -> bool {
+        self.include_icon_in_text
+    }
 }
 
 impl CellDataChangeset for SingleSelectTypeOptionPB {
@@ -77,6 +126,12 @@ impl CellDataChangeset for SingleSelectTypeOptionPB {
             SelectOptionIds::from(insert_option_ids)
         } else {
             // Just take the first select option
+            if insert_option_ids.len() > 1 {
+                tracing::warn!(
+                    "The single select cell should contain one option id, but got: {:?}",
+                    insert_option_ids
+                );
+            }
             let _ = insert_option_ids.drain(1..);
             SelectOptionIds::from(insert_option_ids)
         };
@@ -113,7 +168,7 @@ impl TypeOptionCellDataCompare for SingleSelectTypeOptionPB {
                 .first()
                 .and_then(|id| self.options.iter().find(|option| &option.id == id)),
         ) {
-            (Some(left), Some(right)) => left.name.cmp(&right.name),
+            (Some(left), Some(right)) => cmp_select_option(&self.options, self.is_scale, left, right),
             (Some(_), None) => Ordering::Greater,
             (None, Some(_)) => Ordering::Less,
             (None, None) => default_order(),
@@ -145,9 +200,10 @@ impl TypeOptionBuilder for SingleSelectTypeOptionBuilder {
 #[cfg(test)]
 mod tests {
     use crate::entities::FieldType;
-    use crate::services::cell::CellDataChangeset;
+    use crate::services::cell::{stringify_cell_data, CellDataChangeset};
     use crate::services::field::type_options::*;
     use crate::services::field::{FieldBuilder, TypeOptionBuilder};
+    use bytes::Bytes;
 
     #[test]
     fn single_select_transform_with_checkbox_type_option_test() {
@@ -200,6 +256,21 @@ mod tests {
         assert_eq!(&*select_option_ids, &vec![google.id]);
     }
 
+    #[test]
+    fn single_select_decode_multi_option_ids_test() {
+        let google = SelectOptionPB::new("Google");
+        let facebook = SelectOptionPB::new("Facebook");
+        let single_select = SingleSelectTypeOptionBuilder::default()
+            .add_option(google.clone())
+            .add_option(facebook.clone());
+
+        let field_rev = FieldBuilder::new(single_select).name("Platform").build();
+        let type_option = SingleSelectTypeOptionPB::from(&field_rev);
+        let cell_str = SelectOptionIds::from(vec![google.id.clone(), facebook.id]).to_string();
+        let select_option_ids = type_option.decode_type_option_cell_str(cell_str).unwrap();
+        assert_eq!(&*select_option_ids, &vec![google.id]);
+    }
+
     #[test]
     fn single_select_unselect_multi_option_test() {
         let google = SelectOptionPB::new("Google");
@@ -247,4 +318,132 @@ mod tests {
         let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
         assert!(select_option_ids.is_empty());
     }
+
+    #[test]
+    fn single_select_option_custom_color_test() {
+        let mut google = SelectOptionPB::new("Google");
+        google.set_custom_color("#1A2b3C").unwrap();
+        assert!(google.set_custom_color("1A2B3C").is_err());
+        assert!(google.set_custom_color("#1A2B3").is_err());
+        assert!(google.set_custom_color("#1A2B3G").is_err());
+
+        // The invalid colors don't override the valid one
+        assert_eq!(google.custom_color, Some("#1A2b3C".to_owned()));
+
+        let single_select = SingleSelectTypeOptionBuilder::default().add_option(google.clone());
+        let field_rev = FieldBuilder::new(single_select).name("Platform").build();
+        let type_option = SingleSelectTypeOptionPB::from(&field_rev);
+        assert_eq!(type_option.options, vec![google]);
+        assert_eq!(type_option.options[0].color, SelectOptionColorPB::default());
+    }
+
+    #[test]
+    fn single_select_duplicate_option_test() {
+        let google = SelectOptionPB::new("Google");
+        let facebook = SelectOptionPB::new("Facebook");
+        let single_select = SingleSelectTypeOptionBuilder::default()
+            .add_option(google.clone())
+            .add_option(facebook.clone());
+
+        let field_rev = FieldBuilder::new(single_select).name("Platform").build();
+        let mut type_option = SingleSelectTypeOptionPB::from(&field_rev);
+
+        let copy = type_option.duplicate_option(&google.id).unwrap();
+        assert_ne!(copy.id, google.id);
+        assert_eq!(copy.name, "Google (copy)");
+        assert_eq!(copy.color, google.color);
+        assert_eq!(type_option.options, vec![google.clone(), copy, facebook]);
+
+        // The name of the second copy doesn't collide with the first one
+        let copy = type_option.duplicate_option(&google.id).unwrap();
+        assert_eq!(copy.name, "Google (copy) (copy)");
+        assert!(type_option.duplicate_option("unknown").is_err());
+    }
+
+    #[test]
+    fn single_select_rename_multi_option_test() {
+        let google = SelectOptionPB::new("Google");
+        let facebook = SelectOptionPB::new("Facebook");
+        let twitter = SelectOptionPB::new("Twitter");
+        let single_select = SingleSelectTypeOptionBuilder::default()
+            .add_option(google.clone())
+            .add_option(facebook.clone())
+            .add_option(twitter.clone());
+
+        let field_rev = FieldBuilder::new(single_select).name("Platform").build();
+        let mut type_option = SingleSelectTypeOptionPB::from(&field_rev);
+        let changeset = SelectOptionCellChangeset::from_insert_option_id(&google.id);
+        let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+
+        // Renaming an option to the name of another option is rejected
+        let renames = vec![SelectOptionRenamePB {
+            option_id: google.id.clone(),
+            name: "Twitter".to_owned(),
+        }];
+        assert!(type_option.rename_options(renames).is_err());
+        assert_eq!(type_option.options[0].name, "Google");
+
+        // Swapping the names is fine because the resulting names are unique
+        let renames = vec![
+            SelectOptionRenamePB {
+                option_id: google.id.clone(),
+                name: "Facebook".to_owned(),
+            },
+            SelectOptionRenamePB {
+                option_id: facebook.id.clone(),
+                name: "Google".to_owned(),
+            },
+        ];
+        type_option.rename_options(renames).unwrap();
+        let names = type_option
+            .options
+            .iter()
+            .map(|option| option.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["Facebook", "Google", "Twitter"]);
+
+        // The cell still references the same option
+        let cell_data = type_option.get_selected_options(select_option_ids);
+        assert_eq!(cell_data.select_options.len(), 1);
+        assert_eq!(cell_data.select_options[0].id, google.id);
+        assert_eq!(cell_data.select_options[0].name, "Facebook");
+    }
+
+    #[test]
+    fn single_select_option_description_test() {
+        let mut done = SelectOptionPB::new("Done");
+        done.description = Some("Reviewed and merged".to_owned());
+        let single_select = SingleSelectTypeOptionBuilder::default()
+            .add_option(done.clone())
+            .add_option(SelectOptionPB::new("Todo"));
+        let field_rev = FieldBuilder::new(single_select).name("Status").build();
+        let type_option = SingleSelectTypeOptionPB::from(&field_rev);
+        assert_eq!(type_option.options[0], done);
+        assert_eq!(type_option.options[1].description, None);
+
+        let bytes: Bytes = type_option.clone().try_into().unwrap();
+        let type_option_from_bytes = SingleSelectTypeOptionPB::try_from(bytes).unwrap();
+        assert_eq!(type_option_from_bytes.options, type_option.options);
+    }
+
+    #[test]
+    fn single_select_include_icon_in_text_test() {
+        let mut urgent = SelectOptionPB::new("Urgent");
+        urgent.icon = Some("🔥".to_owned());
+        let single_select = SingleSelectTypeOptionBuilder::default().add_option(urgent.clone());
+        let mut field_rev = FieldBuilder::new(single_select).name("Status").build();
+        let field_type = FieldType::SingleSelect;
+        assert_eq!(
+            stringify_cell_data(urgent.id.clone(), &field_type, &field_type, &field_rev),
+            "Urgent"
+        );
+
+        let mut type_option = SingleSelectTypeOptionPB::from(&field_rev);
+        type_option.include_icon_in_text = true;
+        field_rev.insert_type_option(&type_option);
+        assert_eq!(
+            stringify_cell_data(urgent.id, &field_type, &field_type, &field_rev),
+            "🔥 Urgent"
+        );
+    }
 }
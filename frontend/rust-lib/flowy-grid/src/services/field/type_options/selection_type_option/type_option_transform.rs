@@ -53,6 +53,30 @@ impl SelectOptionTypeOptionTransformHelper {
         }
     }
 
+    /// Adds an option for each distinct text of the cells when switching from the text field type.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_field_type`: the FieldType of the passed-in cells
+    ///
+    pub fn transform_type_option_with_cells<T>(shared: &mut T, old_field_type: &FieldType, old_cell_strs: &[String])
+    where
+        T: SelectTypeOptionSharedAction,
+    {
+        if old_field_type != &FieldType::RichText {
+            return;
+        }
+
+        for cell_str in old_cell_strs {
+            let name = cell_str.trim();
+            if name.is_empty() || shared.option_id_for_name(name).is_some() {
+                continue;
+            }
+            let option = shared.create_option(name);
+            shared.mut_options().push(option);
+        }
+    }
+
     // pub fn transform_e_option_cell_data<T>(
     //     //     shared: &T,
     //     //     cell_data: String,
@@ -1,6 +1,14 @@
 use crate::entities::{TextFilterConditionPB, TextFilterPB};
 
 impl TextFilterPB {
+    /// Returns true if the filter only checks whether the cell is empty or not.
+    pub fn is_emptiness_check(&self) -> bool {
+        matches!(
+            self.condition,
+            TextFilterConditionPB::TextIsEmpty | TextFilterConditionPB::TextIsNotEmpty
+        )
+    }
+
     pub fn is_visible<T: AsRef<str>>(&self, cell_data: T) -> bool {
         let cell_data = cell_data.as_ref().to_lowercase();
         let content = &self.content.to_lowercase();
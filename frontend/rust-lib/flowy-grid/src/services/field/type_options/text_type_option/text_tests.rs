@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::entities::FieldType;
-    use crate::services::cell::stringify_cell_data;
+    use crate::services::cell::{stringify_cell_data, CellDataChangeset};
 
     use crate::services::field::FieldBuilder;
     use crate::services::field::*;
@@ -34,6 +34,27 @@ mod tests {
             done_option.name,
         );
     }
+    // Test showing the cell data of the URL field in a text field with the normalized URL and
+    // with the content that was typed
+    #[test]
+    fn url_to_text_type() {
+        let url_type_option = URLTypeOptionPB::default();
+        let (cell_str, _) = url_type_option.apply_changeset("appflowy.io".to_owned(), None).unwrap();
+
+        let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+        assert_eq!(
+            stringify_cell_data(cell_str.clone(), &FieldType::URL, &FieldType::RichText, &field_rev),
+            "https://appflowy.io"
+        );
+
+        let text = RichTextTypeOptionBuilder::default().keep_raw_url(true);
+        let field_rev = FieldBuilder::new(text).build();
+        assert_eq!(
+            stringify_cell_data(cell_str, &FieldType::URL, &FieldType::RichText, &field_rev),
+            "appflowy.io"
+        );
+    }
+
     /*
     - [Unit Test] Testing the switching from Multi-selection type to Text type
     - Tracking : https://github.com/AppFlowy-IO/AppFlowy/issues/1183
@@ -5,8 +5,9 @@ use crate::services::cell::{
     TypeCellData,
 };
 use crate::services::field::{
-    BoxTypeOptionBuilder, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
-    TypeOptionCellDataFilter, TypeOptionTransform,
+    BoxTypeOptionBuilder, CheckboxCellData, CheckboxTypeOptionPB, DateTypeOptionPB, SingleSelectTypeOptionPB,
+    TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    TypeOptionTransform, URLCellData,
 };
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
@@ -21,6 +22,18 @@ pub struct RichTextTypeOptionBuilder(RichTextTypeOptionPB);
 impl_into_box_type_option_builder!(RichTextTypeOptionBuilder);
 impl_builder_from_json_str_and_from_bytes!(RichTextTypeOptionBuilder, RichTextTypeOptionPB);
 
+impl RichTextTypeOptionBuilder {
+    pub fn keep_raw_url(mut self, keep_raw_url: bool) -> Self {
+        self.0.keep_raw_url = keep_raw_url;
+        self
+    }
+
+    pub fn keep_empty_string(mut self, keep_empty_string: bool) -> Self {
+        self.0.keep_empty_string = keep_empty_string;
+        self
+    }
+}
+
 impl TypeOptionBuilder for RichTextTypeOptionBuilder {
     fn field_type(&self) -> FieldType {
         FieldType::RichText
@@ -31,13 +44,26 @@ impl TypeOptionBuilder for RichTextTypeOptionBuilder {
     }
 }
 
-/// For the moment, the `RichTextTypeOptionPB` is empty. The `data` property is not
-/// used yet.
+/// The `data` property is not used yet.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, ProtoBuf)]
 pub struct RichTextTypeOptionPB {
     #[pb(index = 1)]
     #[serde(default)]
     data: String,
+
+    /// Decides how the cells of a URL field are shown after switching the field to text. The
+    /// content is shown as it was typed if it's true, otherwise the normalized URL is shown, e.g.
+    /// "https://appflowy.io" for "appflowy.io".
+    #[pb(index = 2)]
+    #[serde(default)]
+    pub keep_raw_url: bool,
+
+    /// Decides whether the empty string written to a cell is kept as a value. By default, a cell
+    /// with the empty string is an empty cell, the same as the row without the cell, so it matches
+    /// the `TextIsEmpty` filter. If it's true, only the rows without the cell are empty.
+    #[pb(index = 3)]
+    #[serde(default)]
+    pub keep_empty_string: bool,
 }
 impl_type_option!(RichTextTypeOptionPB, FieldType::RichText);
 
@@ -61,13 +87,39 @@ impl TypeOptionTransform for RichTextTypeOptionPB {
         decoded_field_type: &FieldType,
         field_rev: &FieldRevision,
     ) -> Option<<Self as TypeOption>::CellData> {
-        if decoded_field_type.is_date()
-            || decoded_field_type.is_single_select()
-            || decoded_field_type.is_multi_select()
-            || decoded_field_type.is_number()
-            || decoded_field_type.is_url()
-        {
+        if decoded_field_type.is_url() {
+            let url_cell_data = URLCellData::from_cell_str(cell_str).ok()?;
+            // The url is empty if the content doesn't contain a URL
+            if self.keep_raw_url || url_cell_data.url.is_empty() {
+                Some(url_cell_data.content.into())
+            } else {
+                Some(url_cell_data.url.into())
+            }
+        } else if decoded_field_type.is_single_select() {
+            let type_option = field_rev
+                .get_type_option::<SingleSelectTypeOptionPB>(decoded_field_type.into())
+                .unwrap_or_default();
+            if type_option.include_color_in_text {
+                let cell_data = type_option.decode_type_option_cell_str(cell_str.to_owned()).ok()?;
+                Some(type_option.text_with_colors(cell_data).into())
+            } else {
+                Some(stringify_cell_data(cell_str.to_owned(), decoded_field_type, decoded_field_type, field_rev).into())
+            }
+        } else if decoded_field_type.is_date() {
+            let type_option = field_rev
+                .get_type_option::<DateTypeOptionPB>(decoded_field_type.into())
+                .unwrap_or_default();
+            let cell_data = type_option.decode_type_option_cell_str(cell_str.to_owned()).ok()?;
+            Some(type_option.text_with_time(cell_data).into())
+        } else if decoded_field_type.is_multi_select() || decoded_field_type.is_number() {
             Some(stringify_cell_data(cell_str.to_owned(), decoded_field_type, decoded_field_type, field_rev).into())
+        } else if decoded_field_type.is_checkbox() {
+            // The checkbox may have custom labels, e.g. "Done" and "Pending"
+            let cell_data = CheckboxCellData::from_cell_str(cell_str).ok()?;
+            let type_option = field_rev
+                .get_type_option::<CheckboxTypeOptionPB>(decoded_field_type.into())
+                .unwrap_or_default();
+            Some(type_option.label(&cell_data).into())
         } else {
             StrCellData::from_cell_str(cell_str).ok()
         }
@@ -75,6 +75,16 @@ pub trait TypeOptionTransform: TypeOption {
     ///
     fn transform_type_option(&mut self, _old_type_option_field_type: FieldType, _old_type_option_data: String) {}
 
+    /// Transform the TypeOption with the cells of the field before switching its type. For example,
+    /// when switching from `text` to `single-select`, an option is added for each distinct text.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_type_option_field_type`: the FieldType of the passed-in cells
+    /// * `old_cell_strs`: the cell strings of the old field type
+    ///
+    fn transform_type_option_with_cells(&mut self, _old_type_option_field_type: FieldType, _old_cell_strs: &[String]) {}
+
     /// Transform the cell data from one field type to another
     ///
     /// # Arguments
@@ -4,9 +4,10 @@ use crate::services::cell::{
     FromCellChangesetString, FromCellString, TypeCellData,
 };
 use crate::services::field::{
-    default_order, CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB, MultiSelectTypeOptionPB,
-    NumberTypeOptionPB, RichTextTypeOptionPB, SingleSelectTypeOptionPB, TypeOption, TypeOptionCellData,
-    TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, URLTypeOptionPB,
+    default_order, CheckboxTypeOptionPB, ChecklistTypeOptionPB, CreatedByTypeOptionPB, DateTypeOptionPB,
+    FormulaTypeOptionPB, LastEditedByTypeOptionPB, MultiSelectTypeOptionPB, NumberTypeOptionPB, RichTextTypeOptionPB,
+    SingleSelectTypeOptionPB, TypeOption, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    TypeOptionTransform, URLTypeOptionPB,
 };
 use crate::services::filter::FilterType;
 use flowy_error::FlowyResult;
@@ -371,6 +372,36 @@ impl<'a> TypeOptionCellExt<'a> {
                         self.cell_data_cache.clone(),
                     )
                 }),
+            FieldType::Formula => self
+                .field_rev
+                .get_type_option::<FormulaTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::CreatedBy => self
+                .field_rev
+                .get_type_option::<CreatedByTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::LastEditedBy => self
+                .field_rev
+                .get_type_option::<LastEditedByTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
         }
     }
 }
@@ -380,11 +411,13 @@ pub fn transform_type_option(
     new_field_type: &FieldType,
     old_type_option_data: Option<String>,
     old_field_type: FieldType,
+    old_cell_strs: &[String],
 ) -> String {
     let mut transform_handler = get_type_option_transform_handler(type_option_data, new_field_type);
     if let Some(old_type_option_data) = old_type_option_data {
-        transform_handler.transform(old_field_type, old_type_option_data);
+        transform_handler.transform(old_field_type.clone(), old_type_option_data);
     }
+    transform_handler.transform_with_cells(old_field_type, old_cell_strs);
     transform_handler.json_str()
 }
 
@@ -392,6 +425,8 @@ pub fn transform_type_option(
 pub trait TypeOptionTransformHandler {
     fn transform(&mut self, old_type_option_field_type: FieldType, old_type_option_data: String);
 
+    fn transform_with_cells(&mut self, old_type_option_field_type: FieldType, old_cell_strs: &[String]);
+
     fn json_str(&self) -> String;
 }
 
@@ -405,6 +440,12 @@ where
         }
     }
 
+    fn transform_with_cells(&mut self, old_type_option_field_type: FieldType, old_cell_strs: &[String]) {
+        if self.transformable() {
+            self.transform_type_option_with_cells(old_type_option_field_type, old_cell_strs)
+        }
+    }
+
     fn json_str(&self) -> String {
         self.json_str()
     }
@@ -438,5 +479,14 @@ fn get_type_option_transform_handler(
         FieldType::Checklist => {
             Box::new(ChecklistTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
         }
+        FieldType::Formula => {
+            Box::new(FormulaTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::CreatedBy => {
+            Box::new(CreatedByTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::LastEditedBy => {
+            Box::new(LastEditedByTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
     }
 }
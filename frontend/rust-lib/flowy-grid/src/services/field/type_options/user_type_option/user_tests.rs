@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+    use crate::services::field::{CreatedByTypeOptionPB, FieldBuilder, LastEditedByTypeOptionPB, UserCellData};
+    use grid_rev_model::FieldRevision;
+
+    #[test]
+    fn user_type_option_display_name_test() {
+        let type_option = CreatedByTypeOptionPB::default();
+        let field_type = FieldType::CreatedBy;
+        let field_rev = FieldBuilder::from_field_type(&field_type).build();
+
+        // The cell stores the user id only, the name is resolved by the formatter of the editor
+        let cell_str = UserCellData::new("1").to_json().unwrap();
+        assert_eq!(cell_str, r#"{"user_id":"1"}"#);
+        assert_user(&type_option, cell_str, &field_type, &field_rev, "1");
+
+        // The names that were stored by the older versions are ignored
+        let cell_str = r#"{"user_id":"1","name":"nathan"}"#.to_owned();
+        assert_user(&type_option, cell_str, &field_type, &field_rev, "1");
+
+        assert_user(&type_option, "".to_owned(), &field_type, &field_rev, "");
+    }
+
+    #[test]
+    fn user_type_option_decode_other_field_type_test() {
+        let type_option = LastEditedByTypeOptionPB::default();
+        let field_rev = FieldBuilder::from_field_type(&FieldType::LastEditedBy).build();
+        let cell_str = UserCellData::new("1").to_json().unwrap();
+        assert_user(&type_option, cell_str, &FieldType::CreatedBy, &field_rev, "");
+    }
+
+    #[test]
+    fn user_type_option_read_only_test() {
+        let type_option = LastEditedByTypeOptionPB::default();
+        assert!(type_option.apply_changeset("1".to_owned(), None).is_err());
+    }
+
+    fn assert_user<T>(
+        type_option: &T,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+        expected: &str,
+    ) where
+        T: CellDataDecoder,
+    {
+        let cell_data = type_option
+            .decode_cell_str(cell_str, decoded_field_type, field_rev)
+            .unwrap();
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), expected);
+    }
+}
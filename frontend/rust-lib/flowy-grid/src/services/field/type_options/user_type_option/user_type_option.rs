@@ -0,0 +1,175 @@
+use crate::entities::{FieldType, TextFilterPB};
+use crate::impl_type_option;
+use crate::manager::GridUser;
+use crate::services::cell::{
+    CellDataChangeset, CellDataDecoder, CellFormatter, DecodedCellData, FromCellString, TypeCellData,
+};
+use crate::services::field::{
+    BoxTypeOptionBuilder, StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter, TypeOptionTransform, UserCellData,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{FlowyError, FlowyResult};
+use grid_rev_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct CreatedByTypeOptionBuilder(CreatedByTypeOptionPB);
+impl_into_box_type_option_builder!(CreatedByTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(CreatedByTypeOptionBuilder, CreatedByTypeOptionPB);
+
+impl TypeOptionBuilder for CreatedByTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::CreatedBy
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
+
+/// The cells of the created by field show the user that created the row.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct CreatedByTypeOptionPB {
+    #[pb(index = 1)]
+    data: String, //It's not used yet.
+}
+impl_type_option!(CreatedByTypeOptionPB, FieldType::CreatedBy);
+
+#[derive(Default)]
+pub struct LastEditedByTypeOptionBuilder(LastEditedByTypeOptionPB);
+impl_into_box_type_option_builder!(LastEditedByTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(LastEditedByTypeOptionBuilder, LastEditedByTypeOptionPB);
+
+impl TypeOptionBuilder for LastEditedByTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::LastEditedBy
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
+
+/// The cells of the last edited by field show the user that edited the row most recently.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct LastEditedByTypeOptionPB {
+    #[pb(index = 1)]
+    data: String, //It's not used yet.
+}
+impl_type_option!(LastEditedByTypeOptionPB, FieldType::LastEditedBy);
+
+/// Both fields only differ in which author of the row they show, so they share the cell
+/// implementations.
+macro_rules! impl_user_type_option {
+    ($target: ident, $field_type: expr) => {
+        impl TypeOption for $target {
+            type CellData = UserCellData;
+            type CellChangeset = String;
+            type CellProtobufType = StrCellData;
+            type CellFilter = TextFilterPB;
+        }
+
+        impl TypeOptionTransform for $target {}
+
+        impl TypeOptionCellData for $target {
+            fn convert_to_protobuf(
+                &self,
+                cell_data: <Self as TypeOption>::CellData,
+            ) -> <Self as TypeOption>::CellProtobufType {
+                StrCellData(cell_data.to_string())
+            }
+
+            fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+                UserCellData::from_cell_str(&cell_str)
+            }
+        }
+
+        impl CellDataDecoder for $target {
+            fn decode_cell_str(
+                &self,
+                cell_str: String,
+                decoded_field_type: &FieldType,
+                _field_rev: &FieldRevision,
+            ) -> FlowyResult<<Self as TypeOption>::CellData> {
+                // The cells that were written before switching to this field are stale until
+                // they are filled with the author of the row.
+                if decoded_field_type != &$field_type {
+                    return Ok(UserCellData::default());
+                }
+                UserCellData::from_cell_str(&cell_str)
+            }
+
+            fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+                cell_data.to_string()
+            }
+        }
+
+        impl CellDataChangeset for $target {
+            fn apply_changeset(
+                &self,
+                _changeset: <Self as TypeOption>::CellChangeset,
+                _type_cell_data: Option<TypeCellData>,
+            ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+                Err(FlowyError::invalid_data().context("The author of the row can't be edited"))
+            }
+        }
+
+        impl TypeOptionCellDataFilter for $target {
+            fn apply_filter(
+                &self,
+                filter: &<Self as TypeOption>::CellFilter,
+                field_type: &FieldType,
+                cell_data: &<Self as TypeOption>::CellData,
+            ) -> bool {
+                if field_type != &$field_type {
+                    return false;
+                }
+
+                filter.is_visible(cell_data)
+            }
+        }
+
+        impl TypeOptionCellDataCompare for $target {
+            fn apply_cmp(
+                &self,
+                cell_data: &<Self as TypeOption>::CellData,
+                other_cell_data: &<Self as TypeOption>::CellData,
+            ) -> Ordering {
+                cell_data.user_id.cmp(&other_cell_data.user_id)
+            }
+        }
+    };
+}
+
+impl_user_type_option!(CreatedByTypeOptionPB, FieldType::CreatedBy);
+impl_user_type_option!(LastEditedByTypeOptionPB, FieldType::LastEditedBy);
+
+/// Displays the name of the user in the cells of the `CreatedBy` and `LastEditedBy` fields. The
+/// user id is displayed if the user is unknown.
+pub struct UserCellFormatter {
+    user: Arc<dyn GridUser>,
+}
+
+impl UserCellFormatter {
+    pub fn new(user: Arc<dyn GridUser>) -> Self {
+        Self { user }
+    }
+}
+
+impl CellFormatter for UserCellFormatter {
+    fn format(&self, cell_str: String, decoded_field_type: &FieldType, field_rev: &FieldRevision) -> String {
+        let field_type: FieldType = field_rev.ty.into();
+        if decoded_field_type != &field_type {
+            return "".to_owned();
+        }
+        let cell_data = UserCellData::from_cell_str(&cell_str).unwrap_or_default();
+        if cell_data.is_empty() {
+            return "".to_owned();
+        }
+        self.user.user_name(&cell_data.user_id).unwrap_or(cell_data.user_id)
+    }
+}
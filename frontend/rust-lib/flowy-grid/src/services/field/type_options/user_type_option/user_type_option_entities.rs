@@ -0,0 +1,52 @@
+use crate::services::cell::{DecodedCellData, FromCellString};
+use flowy_error::{internal_error, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// The cell data of the `CreatedBy` and `LastEditedBy` fields. Only the id of the user is
+/// stored, the name is resolved when the cell is displayed, so a renamed user is displayed with
+/// the new name. Check out [UserCellFormatter](crate::services::field::UserCellFormatter).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UserCellData {
+    pub user_id: String,
+}
+
+impl UserCellData {
+    pub fn new(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+        }
+    }
+
+    pub fn to_json(&self) -> FlowyResult<String> {
+        serde_json::to_string(self).map_err(internal_error)
+    }
+}
+
+impl AsRef<str> for UserCellData {
+    fn as_ref(&self) -> &str {
+        &self.user_id
+    }
+}
+
+impl DecodedCellData for UserCellData {
+    type Object = UserCellData;
+
+    fn is_empty(&self) -> bool {
+        self.user_id.is_empty()
+    }
+}
+
+impl FromCellString for UserCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self> {
+        if s.is_empty() {
+            return Ok(UserCellData::default());
+        }
+        serde_json::from_str::<UserCellData>(s).map_err(internal_error)
+    }
+}
+
+impl ToString for UserCellData {
+    fn to_string(&self) -> String {
+        self.user_id.clone()
+    }
+}
@@ -1,13 +1,15 @@
 use crate::entities::filter_entities::*;
 use crate::entities::{FieldType, InsertedRowPB, RowPB};
-use crate::services::cell::{AnyTypeCache, AtomicCellDataCache, AtomicCellFilterCache, TypeCellData};
+use crate::services::cell::{AnyTypeCache, AtomicCellDataCache, AtomicCellFilterCache, FromCellString, TypeCellData};
 use crate::services::field::*;
-use crate::services::filter::{FilterChangeset, FilterResult, FilterResultNotification, FilterType};
+use crate::services::filter::{
+    FilterChangeset, FilterResult, FilterResultNotification, FilterType, RowVisibilityPredicate,
+};
 use crate::services::row::GridBlockRowRevision;
 use crate::services::view_editor::{GridViewChanged, GridViewChangedNotifier};
 use flowy_error::FlowyResult;
 use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
-use grid_rev_model::{CellRevision, FieldId, FieldRevision, FilterRevision, RowRevision};
+use grid_rev_model::{CellRevision, FieldId, FieldRevision, FilterConjunctionRevision, FilterRevision, RowRevision};
 use lib_infra::future::Fut;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -22,6 +24,7 @@ pub trait FilterDelegate: Send + Sync + 'static {
     fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<FieldRevision>>>;
     fn get_blocks(&self) -> Fut<Vec<GridBlockRowRevision>>;
     fn get_row_rev(&self, rows_id: &str) -> Fut<Option<(usize, Arc<RowRevision>)>>;
+    fn get_filter_conjunction(&self) -> Fut<FilterConjunctionRevision>;
 }
 
 pub trait FromFilterString {
@@ -35,10 +38,14 @@ pub struct FilterController {
     handler_id: String,
     delegate: Box<dyn FilterDelegate>,
     result_by_row_id: HashMap<RowId, FilterResult>,
+    conjunction: FilterConjunctionRevision,
     cell_data_cache: AtomicCellDataCache,
     cell_filter_cache: AtomicCellFilterCache,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     notifier: GridViewChangedNotifier,
+    row_predicate: Option<RowVisibilityPredicate>,
+    /// The id of the field that each filter compares with, see [DateFilterPB::other_field_id].
+    dependent_field_id_by_filter_type: HashMap<FilterType, String>,
 }
 
 impl FilterController {
@@ -54,15 +61,19 @@ impl FilterController {
     where
         T: FilterDelegate + 'static,
     {
+        let conjunction = delegate.get_filter_conjunction().await;
         let mut this = Self {
             view_id: view_id.to_string(),
             handler_id: handler_id.to_string(),
             delegate: Box::new(delegate),
             result_by_row_id: HashMap::default(),
+            conjunction,
             cell_data_cache,
             cell_filter_cache: AnyTypeCache::<FilterType>::new(),
             task_scheduler,
             notifier,
+            row_predicate: None,
+            dependent_field_id_by_filter_type: HashMap::default(),
         };
         this.refresh_filters(filter_revs).await;
         this
@@ -84,7 +95,7 @@ impl FilterController {
     }
 
     pub async fn filter_row_revs(&mut self, row_revs: &mut Vec<Arc<RowRevision>>) {
-        if self.cell_filter_cache.read().is_empty() {
+        if self.cell_filter_cache.read().is_empty() && self.row_predicate.is_none() {
             return;
         }
         let field_rev_by_field_id = self.get_filter_revs_map().await;
@@ -95,13 +106,15 @@ impl FilterController {
                 &field_rev_by_field_id,
                 &self.cell_data_cache,
                 &self.cell_filter_cache,
+                &self.conjunction,
+                self.row_predicate.as_ref(),
             );
         });
 
         row_revs.retain(|row_rev| {
             self.result_by_row_id
                 .get(&row_rev.id)
-                .map(|result| result.is_visible())
+                .map(|result| result.is_visible(&self.conjunction))
                 .unwrap_or(false)
         });
     }
@@ -135,6 +148,8 @@ impl FilterController {
                 &field_rev_by_field_id,
                 &self.cell_data_cache,
                 &self.cell_filter_cache,
+                &self.conjunction,
+                self.row_predicate.as_ref(),
             ) {
                 if is_visible {
                     if let Some((index, row_rev)) = self.delegate.get_row_rev(&row_id).await {
@@ -167,6 +182,8 @@ impl FilterController {
                     &field_rev_by_field_id,
                     &self.cell_data_cache,
                     &self.cell_filter_cache,
+                    &self.conjunction,
+                    self.row_predicate.as_ref(),
                 ) {
                     if is_visible {
                         let row_pb = RowPB::from(row_rev.as_ref());
@@ -189,6 +206,61 @@ impl FilterController {
         Ok(())
     }
 
+    /// Updates the way the filters are combined and notifies the rows whose visibility changed.
+    pub async fn did_update_conjunction(&mut self, conjunction: FilterConjunctionRevision) {
+        if self.conjunction == conjunction {
+            return;
+        }
+        let old_conjunction = std::mem::replace(&mut self.conjunction, conjunction);
+        for block in self.delegate.get_blocks().await.into_iter() {
+            let mut visible_rows = vec![];
+            let mut invisible_rows = vec![];
+            for (index, row_rev) in block.row_revs.iter().enumerate() {
+                if let Some(filter_result) = self.result_by_row_id.get(&row_rev.id) {
+                    let is_visible = filter_result.is_visible(&self.conjunction);
+                    if filter_result.is_visible(&old_conjunction) == is_visible {
+                        continue;
+                    }
+                    if is_visible {
+                        let row_pb = RowPB::from(row_rev.as_ref());
+                        visible_rows.push(InsertedRowPB::with_index(row_pb, index as i32))
+                    } else {
+                        invisible_rows.push(row_rev.id.clone());
+                    }
+                }
+            }
+
+            let notification = FilterResultNotification {
+                view_id: self.view_id.clone(),
+                block_id: block.block_id,
+                invisible_rows,
+                visible_rows,
+            };
+            let _ = self.notifier.send(GridViewChanged::FilterNotification(notification));
+        }
+    }
+
+    /// Replaces the [RowVisibilityPredicate] of the view and notifies the rows whose visibility
+    /// changed. Passing None removes the predicate.
+    pub async fn set_row_predicate(&mut self, row_predicate: Option<RowVisibilityPredicate>) {
+        self.row_predicate = row_predicate;
+        self.gen_task(FilterEvent::FilterDidChanged, QualityOfService::UserInteractive)
+            .await;
+    }
+
+    /// Re-filters all the rows if a filter compares with the field. The cells of the field decide
+    /// the visibility of the rows even though the filter belongs to another field.
+    pub async fn did_receive_field_changed(&self, field_id: &str) {
+        let is_dependent = self
+            .dependent_field_id_by_filter_type
+            .values()
+            .any(|dependent_field_id| dependent_field_id == field_id);
+        if is_dependent {
+            self.gen_task(FilterEvent::FilterDidChanged, QualityOfService::UserInteractive)
+                .await;
+        }
+    }
+
     pub async fn did_receive_row_changed(&self, row_id: &str) {
         self.gen_task(
             FilterEvent::RowDidChanged(row_id.to_string()),
@@ -242,6 +314,7 @@ impl FilterController {
                 notification = Some(FilterChangesetNotificationPB::from_delete(&self.view_id, vec![filter]));
             }
             self.cell_filter_cache.write().remove(filter_type);
+            self.dependent_field_id_by_filter_type.remove(filter_type);
         }
 
         let _ = self
@@ -276,9 +349,18 @@ impl FilterController {
                             .insert(&filter_type, NumberFilterPB::from_filter_rev(filter_rev.as_ref()));
                     }
                     FieldType::DateTime => {
-                        self.cell_filter_cache
-                            .write()
-                            .insert(&filter_type, DateFilterPB::from_filter_rev(filter_rev.as_ref()));
+                        let date_filter = DateFilterPB::from_filter_rev(filter_rev.as_ref());
+                        match date_filter
+                            .other_field_id
+                            .clone()
+                            .filter(|_| date_filter.is_field_comparison())
+                        {
+                            None => self.dependent_field_id_by_filter_type.remove(&filter_type),
+                            Some(other_field_id) => self
+                                .dependent_field_id_by_filter_type
+                                .insert(filter_type.clone(), other_field_id),
+                        };
+                        self.cell_filter_cache.write().insert(&filter_type, date_filter);
                     }
                     FieldType::SingleSelect | FieldType::MultiSelect => {
                         self.cell_filter_cache
@@ -290,7 +372,7 @@ impl FilterController {
                             .write()
                             .insert(&filter_type, CheckboxFilterPB::from_filter_rev(filter_rev.as_ref()));
                     }
-                    FieldType::URL => {
+                    FieldType::URL | FieldType::Formula | FieldType::CreatedBy | FieldType::LastEditedBy => {
                         self.cell_filter_cache
                             .write()
                             .insert(&filter_type, TextFilterPB::from_filter_rev(filter_rev.as_ref()));
@@ -314,12 +396,14 @@ fn filter_row(
     field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>,
     cell_data_cache: &AtomicCellDataCache,
     cell_filter_cache: &AtomicCellFilterCache,
+    conjunction: &FilterConjunctionRevision,
+    row_predicate: Option<&RowVisibilityPredicate>,
 ) -> Option<(String, bool)> {
     // Create a filter result cache if it's not exist
     let filter_result = result_by_row_id
         .entry(row_rev.id.clone())
         .or_insert_with(FilterResult::default);
-    let old_is_visible = filter_result.is_visible();
+    let old_is_visible = filter_result.is_visible(conjunction);
 
     // Iterate each cell of the row to check its visibility
     for (field_id, field_rev) in field_rev_by_field_id {
@@ -330,6 +414,38 @@ fn filter_row(
         }
 
         let cell_rev = row_rev.cells.get(field_id);
+        // The date filter that compares with another field needs the other cell of the row, so
+        // it can't be handled by the cell's type option.
+        let is_visible_with_other_field = cell_filter_cache
+            .read()
+            .get::<DateFilterPB>(&filter_type)
+            .filter(|filter| filter.is_field_comparison())
+            .map(|filter| {
+                let other_cell_rev = filter
+                    .other_field_id
+                    .as_ref()
+                    .and_then(|other_field_id| row_rev.cells.get(other_field_id));
+                filter.is_visible_with_other_field(date_timestamp_from(cell_rev), date_timestamp_from(other_cell_rev))
+            });
+
+        if let Some(is_visible) = is_visible_with_other_field {
+            filter_result.visible_by_filter_id.insert(filter_type, is_visible);
+            continue;
+        }
+
+        // The empty string that the text field keeps is a value, so the cell is not empty even
+        // though its content is.
+        let is_visible_with_kept_empty_string = cell_filter_cache
+            .read()
+            .get::<TextFilterPB>(&filter_type)
+            .filter(|filter| filter.is_emptiness_check() && is_kept_empty_string(field_rev, cell_rev))
+            .map(|filter| filter.condition == TextFilterConditionPB::TextIsNotEmpty);
+
+        if let Some(is_visible) = is_visible_with_kept_empty_string {
+            filter_result.visible_by_filter_id.insert(filter_type, is_visible);
+            continue;
+        }
+
         // if the visibility of the cell_rew is changed, which means the visibility of the
         // row is changed too.
         if let Some(is_visible) = filter_cell(&filter_type, field_rev, cell_rev, cell_data_cache, cell_filter_cache) {
@@ -337,7 +453,8 @@ fn filter_row(
         }
     }
 
-    let is_visible = filter_result.is_visible();
+    filter_result.visible_by_predicate = row_predicate.map(|predicate| predicate(row_rev));
+    let is_visible = filter_result.is_visible(conjunction);
     if old_is_visible != is_visible {
         Some((row_rev.id.clone(), is_visible))
     } else {
@@ -378,6 +495,34 @@ fn filter_cell(
     Some(is_visible)
 }
 
+/// Returns true if the cell holds the empty string and its text field keeps the empty string as
+/// a value, see [RichTextTypeOptionPB::keep_empty_string].
+fn is_kept_empty_string(field_rev: &FieldRevision, cell_rev: Option<&CellRevision>) -> bool {
+    if FieldType::try_from_type_rev(field_rev.ty) != Some(FieldType::RichText) {
+        return false;
+    }
+    let keep_empty_string = field_rev
+        .get_type_option::<RichTextTypeOptionPB>(field_rev.ty)
+        .map(|type_option| type_option.keep_empty_string)
+        .unwrap_or(false);
+    if !keep_empty_string {
+        return false;
+    }
+    match cell_rev.and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok()) {
+        None => false,
+        Some(type_cell_data) => type_cell_data.is_text() && type_cell_data.cell_str.is_empty(),
+    }
+}
+
+/// Returns the timestamp of the cell if the cell's data is date
+fn date_timestamp_from(cell_rev: Option<&CellRevision>) -> Option<i64> {
+    let type_cell_data = TypeCellData::try_from(cell_rev?).ok()?;
+    if !type_cell_data.field_type.is_date() {
+        return None;
+    }
+    DateCellData::from_cell_str(&type_cell_data.cell_str).ok()?.0
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 enum FilterEvent {
     FilterDidChanged,
@@ -1,7 +1,12 @@
 use crate::entities::{AlterFilterParams, DeleteFilterParams, FieldType, GridSettingChangesetParams, InsertedRowPB};
-use grid_rev_model::{FieldRevision, FieldTypeRevision};
+use grid_rev_model::{FieldRevision, FieldTypeRevision, RowRevision};
 use std::sync::Arc;
 
+/// Decides whether the row is visible, for the filtering that the filter conditions can't
+/// express. It's combined with the filters of the view by AND, whatever the conjunction of the
+/// filters is.
+pub type RowVisibilityPredicate = Arc<dyn Fn(&RowRevision) -> bool + Send + Sync>;
+
 #[derive(Debug)]
 pub struct FilterChangeset {
     pub(crate) insert_filter: Option<FilterType>,
@@ -1,5 +1,6 @@
 use crate::services::filter::{FilterController, FilterType};
 use flowy_task::{TaskContent, TaskHandler};
+use grid_rev_model::FilterConjunctionRevision;
 use lib_infra::future::BoxResultFuture;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -47,17 +48,21 @@ impl TaskHandler for FilterTaskHandler {
 #[derive(Default)]
 pub(crate) struct FilterResult {
     pub(crate) visible_by_filter_id: HashMap<FilterType, bool>,
+    /// The result of the row predicate of the view, None if there is no predicate.
+    pub(crate) visible_by_predicate: Option<bool>,
 }
 
 impl FilterResult {
-    pub(crate) fn is_visible(&self) -> bool {
-        let mut is_visible = true;
-        for visible in self.visible_by_filter_id.values() {
-            if !is_visible {
-                break;
-            }
-            is_visible = *visible;
+    pub(crate) fn is_visible(&self, conjunction: &FilterConjunctionRevision) -> bool {
+        if self.visible_by_predicate == Some(false) {
+            return false;
+        }
+        if self.visible_by_filter_id.is_empty() {
+            return true;
+        }
+        match conjunction {
+            FilterConjunctionRevision::And => self.visible_by_filter_id.values().all(|visible| *visible),
+            FilterConjunctionRevision::Or => self.visible_by_filter_id.values().any(|visible| *visible),
         }
-        is_visible
     }
 }
@@ -4,24 +4,39 @@ use crate::entities::*;
 use crate::manager::GridUser;
 use crate::services::block_manager::GridBlockManager;
 use crate::services::cell::{
-    apply_cell_data_changeset, decode_type_cell_data, stringify_cell_data, AnyTypeCache, AtomicCellDataCache,
-    CellProtobufBlob, ToCellChangesetString, TypeCellData,
+    apply_cell_data_changeset, cell_changeset_from_display_str, decode_type_cell_data, stringify_cell_data,
+    AnyTypeCache, AtomicCellDataCache, AtomicCellFormatterRegistry, CellFormatter, CellFormatterRegistry,
+    CellProtobufBlob, FromCellString, ToCellChangesetString, TypeCellData, UNKNOWN_FIELD_TYPE_PLACEHOLDER,
 };
+use crate::services::errors::GridError;
 use crate::services::field::{
-    default_type_option_builder_from_type, transform_type_option, type_option_builder_from_bytes, FieldBuilder,
+    default_type_option_builder_from_type, is_hex_color, migrate_field_type_options, select_type_option_from_field_rev,
+    transform_type_option, type_option_builder_from_bytes, type_option_field_dependencies, AtomicSelectOptionRecency,
+    CheckboxAggregation, CheckboxCellData, CheckboxCount, ChecklistTypeOptionPB, DateTypeOptionPB, FieldBuilder,
+    FieldDependencyGraph, FieldSwitchWarning, FieldTransformWarning, FormulaTypeOptionPB, MultiSelectTypeOptionPB,
+    NumberTypeOptionPB, SelectOptionCellChangeset, SelectOptionColorPB, SelectOptionIds, SelectOptionPB,
+    SelectOptionRecency, SelectOptionRenamePB, SelectTypeOptionSharedAction, UserCellData, UserCellFormatter, CHECK,
+    UNCHECK,
 };
 
-use crate::services::filter::FilterType;
+use crate::services::export::{parse_tsv, CSVBuilder, ExportOptions};
+use crate::services::filter::{FilterType, RowVisibilityPredicate};
 use crate::services::grid_editor_trait_impl::GridViewEditorDelegateImpl;
+use crate::services::grid_event::{GridChangeEvent, GridChangeEventNotifier};
 use crate::services::persistence::block_index::BlockIndexCache;
-use crate::services::row::{GridBlockRow, GridBlockRowRevision, RowRevisionBuilder};
+use crate::services::persistence::kv::GridKVPersistence;
+use crate::services::row::{
+    normalize_row_rev, GridBlockRow, GridBlockRowRevision, NormalizeGridSummary, RowRevisionBuilder,
+};
 use crate::services::view_editor::{GridViewChanged, GridViewManager};
 use bytes::Bytes;
 use flowy_database::ConnectionPool;
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use flowy_http_model::revision::Revision;
+use flowy_http_model::util::md5;
 use flowy_revision::{
     RevisionCloudService, RevisionManager, RevisionMergeable, RevisionObjectDeserializer, RevisionObjectSerializer,
+    RECENT_REVISIONS_AFTER_COMPACTION,
 };
 use flowy_sync::client_grid::{GridRevisionChangeset, GridRevisionPad, JsonDeserializer};
 use flowy_sync::errors::{CollaborateError, CollaborateResult};
@@ -30,20 +45,22 @@ use flowy_task::TaskDispatcher;
 use grid_rev_model::*;
 use lib_infra::future::{to_fut, FutureResult};
 use lib_ot::core::EmptyAttributes;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 
 pub struct GridRevisionEditor {
     pub grid_id: String,
-    #[allow(dead_code)]
     user: Arc<dyn GridUser>,
     grid_pad: Arc<RwLock<GridRevisionPad>>,
     view_manager: Arc<GridViewManager>,
     rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
     block_manager: Arc<GridBlockManager>,
     cell_data_cache: AtomicCellDataCache,
+    cell_formatters: AtomicCellFormatterRegistry,
+    select_option_recency: AtomicSelectOptionRecency,
+    event_notifier: GridChangeEventNotifier,
 }
 
 impl Drop for GridRevisionEditor {
@@ -58,6 +75,7 @@ impl GridRevisionEditor {
         user: Arc<dyn GridUser>,
         mut rev_manager: RevisionManager<Arc<ConnectionPool>>,
         persistence: Arc<BlockIndexCache>,
+        kv_persistence: Arc<GridKVPersistence>,
         task_scheduler: Arc<RwLock<TaskDispatcher>>,
     ) -> FlowyResult<Arc<Self>> {
         let token = user.token()?;
@@ -89,6 +107,13 @@ impl GridRevisionEditor {
             .await?,
         );
 
+        // The names of the authors of the rows are resolved when the cells are displayed
+        let cell_formatters = CellFormatterRegistry::new();
+        for field_type in [FieldType::CreatedBy, FieldType::LastEditedBy] {
+            let formatter = Arc::new(UserCellFormatter::new(user.clone()));
+            cell_formatters.write().register(field_type, formatter);
+        }
+
         let editor = Arc::new(Self {
             grid_id: grid_id.to_owned(),
             user,
@@ -97,11 +122,42 @@ impl GridRevisionEditor {
             block_manager,
             view_manager,
             cell_data_cache,
+            cell_formatters,
+            select_option_recency: SelectOptionRecency::new(kv_persistence),
+            event_notifier: broadcast::channel(100).0,
         });
 
+        if let Err(e) = editor.migrate_type_options().await {
+            tracing::error!("Migrate the type options of the grid:{} failed: {:?}", grid_id, e);
+        }
         Ok(editor)
     }
 
+    /// Upgrades the type-option data of the fields that were saved by an older version, which
+    /// lets the grid open with the default values of the properties that were added since then.
+    /// Only the fields that need the migration are saved.
+    pub async fn migrate_type_options(&self) -> FlowyResult<()> {
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        for field_rev in field_revs {
+            if !migrate_field_type_options(&mut field_rev.as_ref().clone()) {
+                continue;
+            }
+            let _ = self
+                .modify(|grid| {
+                    let changeset = grid.modify_field(&field_rev.id, |field_rev| {
+                        if migrate_field_type_options(field_rev) {
+                            Ok(Some(()))
+                        } else {
+                            Ok(None)
+                        }
+                    })?;
+                    Ok(changeset)
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(name = "close grid editor", level = "trace", skip_all)]
     pub async fn close(&self) {
         self.block_manager.close().await;
@@ -134,6 +190,18 @@ impl GridRevisionEditor {
             return Ok(());
         }
         let field_rev = result.unwrap();
+        let deserializer = TypeOptionJsonDeserializer(field_rev.ty.into());
+        if let Ok(json_str) = deserializer.deserialize(type_option_data.clone()) {
+            let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+            let mut graph = FieldDependencyGraph::from_field_revs(&field_revs);
+            let field_type: FieldType = field_rev.ty.into();
+            graph.insert(
+                field_id,
+                type_option_field_dependencies(&field_type, &json_str, &field_revs),
+            );
+            let _ = graph.validate()?;
+        }
+
         let _ = self
             .modify(|grid| {
                 let changeset = grid.modify_field(field_id, |field| {
@@ -158,6 +226,11 @@ impl GridRevisionEditor {
             .did_update_view_field_type_option(field_id, old_field_rev)
             .await?;
         let _ = self.notify_did_update_grid_field(field_id).await?;
+
+        let field_type: FieldType = field_rev.ty.into();
+        if field_type.is_formula() {
+            let _ = self.recompute_formula_field(field_id).await?;
+        }
         Ok(())
     }
 
@@ -171,6 +244,7 @@ impl GridRevisionEditor {
         let field_id = field_rev.id.clone();
         let _ = self.modify(|grid| Ok(grid.create_field_rev(field_rev, None)?)).await?;
         let _ = self.notify_did_insert_grid_field(&field_id).await?;
+        let _ = self.fill_user_field(&field_id).await?;
 
         Ok(())
     }
@@ -189,6 +263,7 @@ impl GridRevisionEditor {
             .modify(|grid| Ok(grid.create_field_rev(field_rev.clone(), None)?))
             .await?;
         let _ = self.notify_did_insert_grid_field(&field_rev.id).await?;
+        let _ = self.fill_user_field(&field_rev.id).await?;
 
         Ok(field_rev)
     }
@@ -197,8 +272,24 @@ impl GridRevisionEditor {
         self.grid_pad.read().await.contain_field(field_id)
     }
 
+    /// Returns the id of the latest revision that changed the field or moved it, which grows with
+    /// each change of the grid, so the client can tell whether its cached copy of the field is
+    /// stale. The id is saved with the field, so it's kept after reopening the grid. The fields
+    /// that are shifted by moving another field aren't modified.
+    pub async fn field_last_modified(&self, field_id: &str) -> FlowyResult<i64> {
+        match self.grid_pad.read().await.get_field_rev(field_id) {
+            None => Err(GridError::field_not_found(field_id).into()),
+            Some((_, field_rev)) => Ok(field_rev.last_modified_rev_id),
+        }
+    }
+
     pub async fn update_field(&self, params: FieldChangesetParams) -> FlowyResult<()> {
         let field_id = params.field_id.clone();
+        let old_name = self
+            .get_field_rev(&field_id)
+            .await
+            .map(|field_rev| field_rev.name.clone());
+        let new_name = params.name.clone();
         let _ = self
             .modify(|grid| {
                 let changeset = grid.modify_field(&params.field_id, |field| {
@@ -218,7 +309,10 @@ impl GridRevisionEditor {
                         field.visibility = visibility;
                     }
                     if let Some(width) = params.width {
-                        field.width = width;
+                        field.width = width.clamp(MIN_FIELD_WIDTH, MAX_FIELD_WIDTH);
+                    }
+                    if let Some(read_only) = params.read_only {
+                        field.read_only = read_only;
                     }
                     Ok(Some(()))
                 })?;
@@ -226,9 +320,69 @@ impl GridRevisionEditor {
             })
             .await?;
         let _ = self.notify_did_update_grid_field(&field_id).await?;
+        if let (Some(old_name), Some(new_name)) = (old_name, new_name) {
+            let _ = self.did_rename_field(&field_id, &old_name, &new_name).await?;
+        }
+        Ok(())
+    }
+
+    /// Updates the name of the field only. The name is trimmed before saving, and an empty name is
+    /// rejected.
+    pub async fn rename_field(&self, field_id: &str, name: &str) -> FlowyResult<()> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ErrorCode::FieldNameIsEmpty.into());
+        }
+        let old_name = match self.get_field_rev(field_id).await {
+            None => return Err(GridError::field_not_found(field_id).into()),
+            Some(field_rev) => field_rev.name.clone(),
+        };
+
+        let _ = self
+            .modify(|grid| {
+                let changeset = grid.modify_field(field_id, |field| {
+                    if field.name == name {
+                        return Ok(None);
+                    }
+                    field.name = name.to_owned();
+                    Ok(Some(()))
+                })?;
+                Ok(changeset)
+            })
+            .await?;
+        let _ = self.notify_did_update_grid_field(field_id).await?;
+        let _ = self.did_rename_field(field_id, &old_name, name).await?;
         Ok(())
     }
 
+    /// Rewrites the formulas that reference the field by its old name, then recomputes the
+    /// formulas that reference the field by its new name.
+    async fn did_rename_field(&self, field_id: &str, old_name: &str, new_name: &str) -> FlowyResult<()> {
+        if old_name == new_name {
+            return Ok(());
+        }
+        let formula_field_ids = self
+            .get_field_revs(None)
+            .await?
+            .into_iter()
+            .filter(|field_rev| FieldType::from(field_rev.ty).is_formula())
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        for formula_field_id in formula_field_ids {
+            let _ = self
+                .modify_field_rev(&formula_field_id, |field_rev| {
+                    let mut type_option = FormulaTypeOptionPB::from(&*field_rev);
+                    if !type_option.rename_field(old_name, new_name) {
+                        return Ok(None);
+                    }
+                    field_rev.insert_type_option(&type_option);
+                    Ok(Some(()))
+                })
+                .await?;
+        }
+        self.recompute_formula_field(field_id).await
+    }
+
     pub async fn modify_field_rev<F>(&self, field_id: &str, f: F) -> FlowyResult<()>
     where
         F: for<'a> FnOnce(&'a mut FieldRevision) -> FlowyResult<Option<()>>,
@@ -259,12 +413,96 @@ impl GridRevisionEditor {
         Ok(())
     }
 
+    /// Sets the color of a select option without touching the other options of the field. Nothing
+    /// is saved if the option already has the color.
+    pub async fn set_option_color(
+        &self,
+        field_id: &str,
+        option_id: &str,
+        color: SelectOptionColorPB,
+    ) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => return Err(GridError::field_not_found(field_id).into()),
+            Some(field_rev) => field_rev,
+        };
+        let type_option = select_type_option_from_field_rev(&field_rev)?;
+        match type_option.options().iter().find(|option| option.id == option_id) {
+            None => return Err(GridError::option_not_found(option_id).into()),
+            Some(option) if option.color == color && option.custom_color.is_none() => return Ok(()),
+            Some(_) => {}
+        }
+
+        self.modify_field_rev(field_id, |field_rev| {
+            let mut type_option = select_type_option_from_field_rev(field_rev)?;
+            type_option.set_option_color(option_id, color)?;
+            field_rev.insert_type_option(&*type_option);
+            Ok(Some(()))
+        })
+        .await
+    }
+
+    /// Duplicates the select option and returns the copy. The copy is inserted after the option
+    /// with the " (copy)" suffix added to its name, and no cell selects it.
+    pub async fn duplicate_select_option(&self, field_id: &str, option_id: &str) -> FlowyResult<SelectOptionPB> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => return Err(GridError::field_not_found(field_id).into()),
+            Some(field_rev) => field_rev,
+        };
+        let type_option = select_type_option_from_field_rev(&field_rev)?;
+        if !type_option.options().iter().any(|option| option.id == option_id) {
+            return Err(GridError::option_not_found(option_id).into());
+        }
+
+        let mut copy = None;
+        let _ = self
+            .modify_field_rev(field_id, |field_rev| {
+                let mut type_option = select_type_option_from_field_rev(field_rev)?;
+                copy = Some(type_option.duplicate_option(option_id)?);
+                field_rev.insert_type_option(&*type_option);
+                Ok(Some(()))
+            })
+            .await?;
+        match copy {
+            None => Err(GridError::option_not_found(option_id).into()),
+            Some(copy) => Ok(copy),
+        }
+    }
+
+    /// Renames the options of the select field in one go. The ids of the options are unchanged,
+    /// so the cells keep selecting the same options. Nothing is renamed if any of the options
+    /// doesn't exist or the names of the options aren't unique afterwards.
+    pub async fn rename_select_options(&self, field_id: &str, renames: Vec<SelectOptionRenamePB>) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => return Err(GridError::field_not_found(field_id).into()),
+            Some(field_rev) => field_rev,
+        };
+        let mut type_option = select_type_option_from_field_rev(&field_rev)?;
+        let _ = type_option.rename_options(renames.clone())?;
+
+        self.modify_field_rev(field_id, |field_rev| {
+            let mut type_option = select_type_option_from_field_rev(field_rev)?;
+            let _ = type_option.rename_options(renames)?;
+            field_rev.insert_type_option(&*type_option);
+            Ok(Some(()))
+        })
+        .await
+    }
+
     pub async fn delete_field(&self, field_id: &str) -> FlowyResult<()> {
+        // The formulas that reference the field show the reference error after it's deleted
+        let dependents = {
+            let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+            FieldDependencyGraph::from_field_revs(&field_revs).dependents(field_id)
+        };
         let _ = self.modify(|grid_pad| Ok(grid_pad.delete_field_rev(field_id)?)).await?;
         let field_order = FieldIdPB::from(field_id);
         let notified_changeset = GridFieldChangesetPB::delete(&self.grid_id, vec![field_order]);
         let _ = self.notify_did_update_grid(notified_changeset).await?;
-        Ok(())
+        self.view_manager.did_delete_field(field_id).await;
+        self.send_grid_event(GridChangeEvent::FieldDeleted {
+            field_id: field_id.to_owned(),
+        });
+        self.recompute_formula_fields(&dependents).await
     }
 
     pub async fn group_by_field(&self, field_id: &str) -> FlowyResult<()> {
@@ -283,8 +521,174 @@ impl GridRevisionEditor {
     /// * `field_id`: the id of the field
     /// * `new_field_type`: the new field type of the field
     ///
-    pub async fn switch_to_field_type(&self, field_id: &str, new_field_type: &FieldType) -> FlowyResult<()> {
-        //
+    /// Switching the field to its own type does nothing, so neither the type-option data nor the
+    /// cells are transformed.
+    ///
+    /// Returns a warning for each row whose cell loses some of its content in the new field type,
+    /// e.g. the options that are dropped when switching from multi-select to single-select.
+    pub async fn switch_to_field_type(
+        &self,
+        field_id: &str,
+        new_field_type: &FieldType,
+    ) -> FlowyResult<Vec<FieldTransformWarning>> {
+        let mut warnings = vec![];
+        if let Some(field_rev) = self.get_field_rev(field_id).await {
+            let field_type: FieldType = field_rev.ty.into();
+            if &field_type == new_field_type {
+                return Ok(warnings);
+            }
+            warnings = self.get_field_transform_warnings(&field_rev, new_field_type).await?;
+        }
+
+        let old_cell_strs = self.get_field_cell_strs(field_id).await?;
+        let _ = self
+            .switch_to_field_type_with_cell_strs(field_id, new_field_type, &old_cell_strs)
+            .await?;
+        Ok(warnings)
+    }
+
+    /// Compares the display string of each cell before and after switching the field to the
+    /// `new_field_type`. The cells that were written with another field type are skipped.
+    async fn get_field_transform_warnings(
+        &self,
+        field_rev: &FieldRevision,
+        new_field_type: &FieldType,
+    ) -> FlowyResult<Vec<FieldTransformWarning>> {
+        let old_field_type: FieldType = field_rev.ty.into();
+        let preview = self.preview_switch_field(&field_rev.id, new_field_type).await?;
+        let row_revs = self.get_all_block_row_revs().await?;
+        let warnings = row_revs
+            .iter()
+            .zip(preview.into_iter())
+            .flat_map(|(row_rev, (_, new_display_str))| {
+                let type_cell_data = TypeCellData::try_from(row_rev.cells.get(&field_rev.id)?).ok()?;
+                if type_cell_data.field_type != old_field_type {
+                    return None;
+                }
+
+                let message = match (&old_field_type, new_field_type) {
+                    (FieldType::MultiSelect | FieldType::Checklist, FieldType::SingleSelect) => {
+                        let number_of_options = SelectOptionIds::from_cell_str(&type_cell_data.cell_str).ok()?.len();
+                        if number_of_options <= 1 {
+                            return None;
+                        }
+                        format!(
+                            "dropped {} of {} selected options",
+                            number_of_options - 1,
+                            number_of_options
+                        )
+                    }
+                    _ => {
+                        let old_display_str =
+                            stringify_cell_data(type_cell_data.cell_str, &old_field_type, &old_field_type, field_rev);
+                        if old_display_str.is_empty() || !new_display_str.is_empty() {
+                            return None;
+                        }
+                        format!("dropped the content \"{}\"", old_display_str)
+                    }
+                };
+                Some(FieldTransformWarning {
+                    row_id: row_rev.id.clone(),
+                    message,
+                })
+            })
+            .collect();
+        Ok(warnings)
+    }
+
+    /// Switches the text field to a select field. The cells whose text is a key of the
+    /// `option_mapping` select the mapped option, the other cells are transformed as in
+    /// [Self::switch_to_field_type], which creates an option for each distinct text.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_id`: the id of the text field
+    /// * `new_field_type`: the single-select or multi-select field type
+    /// * `option_mapping`: maps the text of the cells to the ids of the options that already exist
+    /// in the type-option data of the `new_field_type`
+    ///
+    pub async fn switch_to_select_field_with_mapping(
+        &self,
+        field_id: &str,
+        new_field_type: &FieldType,
+        option_mapping: HashMap<String, String>,
+    ) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_text() {
+            return Err(GridError::type_mismatch(field_id, "text").into());
+        }
+        let _ = ensure_writable(&field_rev)?;
+        if !new_field_type.is_single_select() && !new_field_type.is_multi_select() {
+            // The option mapping only applies to the select fields
+            return Err(GridError::unsupported_field_type(new_field_type.into()).into());
+        }
+
+        // The mapped options must exist before switching, otherwise the cells would select
+        // nothing.
+        let mut select_field_rev = field_rev.as_ref().clone();
+        select_field_rev.ty = new_field_type.into();
+        let type_option = select_type_option_from_field_rev(&select_field_rev)?;
+        for option_id in option_mapping.values() {
+            if !type_option.options().iter().any(|option| &option.id == option_id) {
+                return Err(GridError::option_not_found(option_id).into());
+            }
+        }
+
+        let mut mapped_cells = vec![];
+        let mut unmapped_cell_strs = vec![];
+        for row_rev in self.get_all_block_row_revs().await? {
+            let type_cell_data = match row_rev.cells.get(field_id).map(TypeCellData::try_from) {
+                Some(Ok(type_cell_data)) if type_cell_data.is_text() => type_cell_data,
+                _ => continue,
+            };
+            match option_mapping.get(type_cell_data.cell_str.trim()) {
+                None => unmapped_cell_strs.push(type_cell_data.cell_str),
+                Some(option_id) => mapped_cells.push((row_rev.id.clone(), option_id.clone())),
+            }
+        }
+
+        let _ = self
+            .switch_to_field_type_with_cell_strs(field_id, new_field_type, &unmapped_cell_strs)
+            .await?;
+
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        for (row_id, option_id) in mapped_cells {
+            // The text of the cell is replaced instead of being merged with the selected option.
+            let changeset = SelectOptionCellChangeset::from_insert_option_id(&option_id);
+            let type_cell_data = apply_cell_data_changeset(changeset, None, field_rev.clone(), None)?;
+            let cell_changeset = CellChangesetPB {
+                grid_id: self.grid_id.clone(),
+                row_id: row_id.clone(),
+                field_id: field_id.to_owned(),
+                type_cell_data,
+            };
+            let _ = self.block_manager.update_cell(cell_changeset).await?;
+            self.view_manager.did_update_cell(&row_id).await;
+            self.send_grid_event(GridChangeEvent::CellChanged {
+                row_id,
+                field_id: field_id.to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn switch_to_field_type_with_cell_strs(
+        &self,
+        field_id: &str,
+        new_field_type: &FieldType,
+        old_cell_strs: &[String],
+    ) -> FlowyResult<()> {
         let make_default_type_option = || -> String {
             return default_type_option_builder_from_type(new_field_type)
                 .serializer()
@@ -294,7 +698,13 @@ impl GridRevisionEditor {
         let type_option_transform =
             |old_field_type: FieldTypeRevision, old_type_option: Option<String>, new_type_option: String| {
                 let old_field_type: FieldType = old_field_type.into();
-                transform_type_option(&new_type_option, new_field_type, old_type_option, old_field_type)
+                transform_type_option(
+                    &new_type_option,
+                    new_field_type,
+                    old_type_option,
+                    old_field_type,
+                    old_cell_strs,
+                )
             };
 
         let _ = self
@@ -309,10 +719,133 @@ impl GridRevisionEditor {
             .await?;
 
         let _ = self.notify_did_update_grid_field(field_id).await?;
+        self.send_grid_event(GridChangeEvent::FieldTypeChanged {
+            field_id: field_id.to_owned(),
+            field_type: new_field_type.clone(),
+        });
+        let _ = self.fill_user_field(field_id).await?;
 
         Ok(())
     }
 
+    /// Returns the display strings of the field's cells after switching the field to the
+    /// `new_field_type`, without saving anything. Each item is a pair of the row id and the
+    /// display string of the cell in that row.
+    pub async fn preview_switch_field(
+        &self,
+        field_id: &str,
+        new_field_type: &FieldType,
+    ) -> FlowyResult<Vec<(String, String)>> {
+        let field_rev = self.switched_field_rev(field_id, new_field_type).await?;
+        let preview = self
+            .get_all_block_row_revs()
+            .await?
+            .iter()
+            .map(|row_rev| {
+                let display_str = row_rev
+                    .cells
+                    .get(field_id)
+                    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+                    .map(|type_cell_data| {
+                        stringify_cell_data(
+                            type_cell_data.cell_str,
+                            &type_cell_data.field_type,
+                            new_field_type,
+                            &field_rev,
+                        )
+                    })
+                    .unwrap_or_default();
+                (row_rev.id.clone(), display_str)
+            })
+            .collect();
+        Ok(preview)
+    }
+
+    /// Checks the type-option data of the field after switching it to the `new_field_type`,
+    /// without saving anything. Returns the warnings about the data that the `new_field_type`
+    /// requires but the field misses, e.g. a select field without any options. It's empty if
+    /// the field is ready to be switched.
+    pub async fn validate_switch_field(
+        &self,
+        field_id: &str,
+        new_field_type: &FieldType,
+    ) -> FlowyResult<Vec<FieldSwitchWarning>> {
+        let field_rev = self.switched_field_rev(field_id, new_field_type).await?;
+        let mut warnings = vec![];
+        match new_field_type {
+            FieldType::SingleSelect | FieldType::MultiSelect | FieldType::Checklist => {
+                if select_type_option_from_field_rev(&field_rev)?.options().is_empty() {
+                    warnings.push(FieldSwitchWarning::NoSelectOptions);
+                }
+            }
+            FieldType::Formula => {
+                let type_option = field_rev
+                    .get_type_option::<FormulaTypeOptionPB>(field_rev.ty)
+                    .unwrap_or_default();
+                if type_option.expression.trim().is_empty() {
+                    warnings.push(FieldSwitchWarning::EmptyFormula);
+                }
+            }
+            _ => {}
+        }
+        Ok(warnings)
+    }
+
+    /// Returns a copy of the field that is switched to the `new_field_type`, whose type-option
+    /// data is transformed as [Self::switch_to_field_type] does.
+    async fn switched_field_rev(&self, field_id: &str, new_field_type: &FieldType) -> FlowyResult<FieldRevision> {
+        let mut field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev.as_ref().clone(),
+        };
+
+        let old_field_type: FieldType = field_rev.ty.into();
+        let new_field_type_rev: FieldTypeRevision = new_field_type.into();
+        let old_type_option = field_rev.get_type_option_str(field_rev.ty).map(|s| s.to_owned());
+        let new_type_option = match field_rev.get_type_option_str(new_field_type_rev) {
+            Some(type_option) => type_option.to_owned(),
+            None => default_type_option_builder_from_type(new_field_type)
+                .serializer()
+                .json_str(),
+        };
+        let old_cell_strs = self.get_field_cell_strs(field_id).await?;
+        let transformed_type_option = transform_type_option(
+            &new_type_option,
+            new_field_type,
+            old_type_option,
+            old_field_type,
+            &old_cell_strs,
+        );
+        field_rev.insert_type_option_str(&new_field_type_rev, transformed_type_option);
+        field_rev.ty = new_field_type_rev;
+        Ok(field_rev)
+    }
+
+    /// Returns the cell strings of the field in the order of the rows. The cells that were written
+    /// with another field type are skipped.
+    async fn get_field_cell_strs(&self, field_id: &str) -> FlowyResult<Vec<String>> {
+        let field_type: FieldType = match self.get_field_rev(field_id).await {
+            None => return Ok(vec![]),
+            Some(field_rev) => field_rev.ty.into(),
+        };
+        let cell_strs = self
+            .get_all_block_row_revs()
+            .await?
+            .iter()
+            .flat_map(|row_rev| {
+                let type_cell_data = TypeCellData::try_from(row_rev.cells.get(field_id)?).ok()?;
+                if type_cell_data.field_type == field_type {
+                    Some(type_cell_data.cell_str)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        Ok(cell_strs)
+    }
+
     pub async fn duplicate_field(&self, field_id: &str) -> FlowyResult<()> {
         let duplicated_field_id = gen_field_id();
         let _ = self
@@ -323,11 +856,33 @@ impl GridRevisionEditor {
         Ok(())
     }
 
+    /// Returns the ids of the fields that the computed field with `field_id` reads.
+    pub async fn field_dependencies(&self, field_id: &str) -> FlowyResult<Vec<String>> {
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        if !field_revs.iter().any(|field_rev| field_rev.id == field_id) {
+            return Err(GridError::field_not_found(field_id).into());
+        }
+        Ok(FieldDependencyGraph::from_field_revs(&field_revs).dependencies(field_id))
+    }
+
     pub async fn get_field_rev(&self, field_id: &str) -> Option<Arc<FieldRevision>> {
         let field_rev = self.grid_pad.read().await.get_field_rev(field_id)?.1.clone();
         Some(field_rev)
     }
 
+    /// Returns the field whose name matches the `name`, ignoring case. If multiple fields have the
+    /// same name, the first one in the grid's field order is returned.
+    pub async fn field_by_name(&self, name: &str) -> Option<Arc<FieldRevision>> {
+        let name = name.to_lowercase();
+        self.grid_pad
+            .read()
+            .await
+            .get_fields()
+            .iter()
+            .find(|field_rev| field_rev.name.to_lowercase() == name)
+            .cloned()
+    }
+
     pub async fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> FlowyResult<Vec<Arc<FieldRevision>>> {
         if field_ids.is_none() {
             let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
@@ -347,6 +902,72 @@ impl GridRevisionEditor {
         Ok(field_revs)
     }
 
+    /// Returns the fields in the order of the view. It falls back to the grid's field order if
+    /// the view doesn't have its own one.
+    pub async fn get_view_field_revs(&self, view_id: &str) -> FlowyResult<Vec<Arc<FieldRevision>>> {
+        self.view_manager.get_view_field_revs(view_id).await
+    }
+
+    /// Returns a hash of the fields of the view, which covers the id, the type and the type-option
+    /// data of each field in the view's order. It only changes when the schema changes, so the
+    /// client can compare it with the previous one instead of fetching all the fields.
+    pub async fn schema_fingerprint(&self, view_id: &str) -> FlowyResult<String> {
+        let field_revs = self.get_view_field_revs(view_id).await?;
+        let mut schema = String::new();
+        for field_rev in field_revs {
+            let type_option_str = field_rev.get_type_option_str(field_rev.ty).unwrap_or_default();
+            schema.push_str(&format!("{}:{}:{}\n", field_rev.id, field_rev.ty, type_option_str));
+        }
+        Ok(md5(schema))
+    }
+
+    /// Reorders the fields of the view without affecting the grid's field order or the other views.
+    pub async fn update_view_field_order(&self, view_id: &str, field_ids: Vec<String>) -> FlowyResult<()> {
+        self.view_manager.update_view_field_order(view_id, field_ids).await
+    }
+
+    /// Moves the field to `to_index` in the view. Unlike [Self::move_field], the grid's field order
+    /// and the other views are unaffected.
+    pub async fn move_field_in_view(&self, view_id: &str, field_id: &str, to_index: usize) -> FlowyResult<()> {
+        self.view_manager.move_view_field(view_id, field_id, to_index).await
+    }
+
+    /// Shows or hides the archived rows in the view.
+    pub async fn update_view_show_archived_rows(&self, view_id: &str, show_archived_rows: bool) -> FlowyResult<()> {
+        self.view_manager
+            .update_view_show_archived_rows(view_id, show_archived_rows)
+            .await
+    }
+
+    /// Sets the height of the rows in the view. The other views keep their own row height.
+    pub async fn update_view_row_height(&self, view_id: &str, row_height: RowHeightPB) -> FlowyResult<()> {
+        self.view_manager
+            .update_view_row_height(view_id, row_height.into())
+            .await
+    }
+
+    /// Decides whether the rows of the view must match all of its filters or any of them.
+    pub async fn update_view_filter_conjunction(
+        &self,
+        view_id: &str,
+        filter_conjunction: FilterConjunctionPB,
+    ) -> FlowyResult<()> {
+        self.view_manager
+            .update_view_filter_conjunction(view_id, filter_conjunction.into())
+            .await
+    }
+
+    /// Registers a predicate that hides the rows of the view for which it returns false, on top of
+    /// the filters of the view. Passing None removes the predicate. The predicate isn't saved, so
+    /// it's gone once the grid is closed.
+    pub async fn set_row_visibility_predicate(
+        &self,
+        view_id: &str,
+        predicate: Option<RowVisibilityPredicate>,
+    ) -> FlowyResult<()> {
+        self.view_manager.set_row_visibility_predicate(view_id, predicate).await
+    }
+
     pub async fn create_block(&self, block_meta_rev: GridBlockMetaRevision) -> FlowyResult<()> {
         let _ = self
             .modify(|grid_pad| Ok(grid_pad.create_block_meta_rev(block_meta_rev)?))
@@ -361,166 +982,1361 @@ impl GridRevisionEditor {
         Ok(())
     }
 
-    pub async fn create_row(&self, params: CreateRowParams) -> FlowyResult<RowPB> {
-        let mut row_rev = self.create_row_rev().await?;
+    pub async fn create_row(&self, params: CreateRowParams) -> FlowyResult<RowPB> {
+        let mut row_rev = self.create_row_rev().await?;
+
+        self.view_manager.will_create_row(&mut row_rev, &params).await;
+
+        let row_pb = self.create_row_pb(row_rev, params.start_row_id.clone()).await?;
+
+        self.view_manager.did_create_row(&row_pb, &params).await;
+        Ok(row_pb)
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn move_group(&self, params: MoveGroupParams) -> FlowyResult<()> {
+        let _ = self.view_manager.move_group(params).await?;
+        Ok(())
+    }
+
+    /// Hide or show the group that contains the rows without a value, e.g. the `No status` group.
+    pub async fn update_group_hide_empty(&self, hide_empty: bool) -> FlowyResult<()> {
+        let _ = self.view_manager.update_group_hide_empty(hide_empty).await?;
+        Ok(())
+    }
+
+    /// Groups the rows by the day, week, month or year of the date field.
+    pub async fn update_date_group_condition(&self, field_id: &str, condition: DateCondition) -> FlowyResult<()> {
+        let _ = self
+            .view_manager
+            .update_date_group_condition(field_id, condition)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn insert_rows(&self, row_revs: Vec<RowRevision>) -> FlowyResult<Vec<RowPB>> {
+        let block_id = self.block_id().await?;
+        let user_id = self.user.user_id()?;
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        let mut rows_by_block_id: HashMap<String, Vec<RowRevision>> = HashMap::new();
+        let mut row_orders = vec![];
+        for mut row_rev in row_revs {
+            // The rows that are imported or duplicated keep their authors
+            if row_rev.created_by.is_none() {
+                row_rev.created_by = Some(user_id.clone());
+            }
+            if row_rev.last_edited_by.is_none() {
+                row_rev.last_edited_by = Some(user_id.clone());
+            }
+            self.insert_user_cells(&mut row_rev, &field_revs);
+            row_orders.push(RowPB::from(&row_rev));
+            rows_by_block_id
+                .entry(block_id.clone())
+                .or_insert_with(Vec::new)
+                .push(row_rev);
+        }
+        let changesets = self.block_manager.insert_row(rows_by_block_id).await?;
+        for changeset in changesets {
+            let _ = self.update_block(changeset).await?;
+        }
+        for row_pb in row_orders.iter() {
+            let _ = self.recompute_row_formulas(&row_pb.id).await?;
+        }
+        for row_pb in row_orders.iter() {
+            self.send_grid_event(GridChangeEvent::RowInserted {
+                row_id: row_pb.id.clone(),
+            });
+        }
+        Ok(row_orders)
+    }
+
+    /// Updates the row, which is recorded as edited by the current user.
+    pub async fn update_row(&self, mut changeset: RowChangeset) -> FlowyResult<()> {
+        let user_id = self.user.user_id()?;
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        for field_id in changeset.cell_by_field_id.keys() {
+            match field_revs.iter().find(|field_rev| &field_rev.id == field_id) {
+                None => return Err(GridError::field_not_found(field_id).into()),
+                Some(field_rev) => {
+                    let _ = ensure_writable(field_rev)?;
+                }
+            }
+        }
+        self.set_last_edited_by(&mut changeset, &user_id, &field_revs);
+        self.save_row(changeset).await
+    }
+
+    async fn save_row(&self, changeset: RowChangeset) -> FlowyResult<()> {
+        let row_id = changeset.row_id.clone();
+        let field_ids = changeset.cell_by_field_id.keys().cloned().collect::<Vec<String>>();
+        let _ = self.block_manager.update_row(changeset).await?;
+        self.view_manager.did_update_cell(&row_id).await;
+        let _ = self.recompute_dependent_cells(&row_id, &field_ids).await?;
+        self.send_grid_event(GridChangeEvent::RowUpdated { row_id });
+        Ok(())
+    }
+
+    /// Fixes the rows of the grid that are inconsistent with the fields: the ids of the options
+    /// that don't exist are pruned from the select cells, the cells of the deleted fields are
+    /// removed and the number cells that can't be parsed are cleared. All the rows are fixed,
+    /// including the ones that the filters of the views hide and the archived ones.
+    ///
+    /// The number fields don't have a range and the grid has no rating field, so there are no
+    /// out-of-range values to clamp.
+    pub async fn normalize_grid(&self) -> FlowyResult<NormalizeGridSummary> {
+        let field_revs = self.get_field_revs(None).await?;
+        let row_revs = self.get_all_block_row_revs().await?;
+        let mut summary = NormalizeGridSummary::default();
+        for row_rev in row_revs.iter() {
+            // Fixing the row is not an edit of the user, so the last editor is kept
+            if let Some(changeset) = normalize_row_rev(row_rev, &field_revs, &mut summary) {
+                let _ = self.save_row(changeset).await?;
+            }
+        }
+        tracing::trace!("Normalize the grid: {}, {:?}", self.grid_id, summary);
+        Ok(summary)
+    }
+
+    /// Archives the row. The archived row is kept in the grid, but it's excluded from the views
+    /// and the calculations unless the view shows the archived rows.
+    pub async fn archive_row(&self, row_id: &str) -> FlowyResult<()> {
+        self.update_row_archived(row_id, true).await
+    }
+
+    pub async fn unarchive_row(&self, row_id: &str) -> FlowyResult<()> {
+        self.update_row_archived(row_id, false).await
+    }
+
+    async fn update_row_archived(&self, row_id: &str, archived: bool) -> FlowyResult<()> {
+        match self.block_manager.get_row_rev(row_id).await? {
+            None => Err(GridError::row_not_found(row_id).into()),
+            Some((_, row_rev)) => {
+                if row_rev.archived == archived {
+                    return Ok(());
+                }
+                let mut changeset = RowChangeset::new(row_id.to_owned());
+                changeset.archived = Some(archived);
+                self.update_row(changeset).await
+            }
+        }
+    }
+
+    /// Returns all the rows in this block.
+    pub async fn get_row_pbs(&self, view_id: &str, block_id: &str) -> FlowyResult<Vec<RowPB>> {
+        let rows = self.view_manager.get_row_revs(view_id, block_id).await?;
+        let rows = rows.into_iter().map(|row_rev| RowPB::from(&row_rev)).collect();
+        Ok(rows)
+    }
+
+    pub async fn get_all_row_revs(&self, view_id: &str) -> FlowyResult<Vec<Arc<RowRevision>>> {
+        let mut all_rows = vec![];
+        let blocks = self.block_manager.get_blocks(None).await?;
+        for block in blocks {
+            let rows = self.view_manager.get_row_revs(view_id, &block.block_id).await?;
+            all_rows.extend(rows);
+        }
+        Ok(all_rows)
+    }
+
+    /// Returns all the rows of the grid, including the ones that the filters of the views hide and
+    /// the archived ones.
+    async fn get_all_block_row_revs(&self) -> FlowyResult<Vec<Arc<RowRevision>>> {
+        let row_revs = self
+            .block_manager
+            .get_blocks(None)
+            .await?
+            .into_iter()
+            .flat_map(|block| block.row_revs)
+            .collect();
+        Ok(row_revs)
+    }
+
+    /// Returns the [CheckboxAggregation] of the checkbox field over the rows of the view. The row
+    /// without a checkbox cell is treated as unchecked.
+    pub async fn get_checkbox_aggregation(&self, view_id: &str, field_id: &str) -> FlowyResult<CheckboxAggregation> {
+        let count = self.count_checkbox_cells(view_id, field_id).await?;
+        Ok(CheckboxAggregation::from_count(count))
+    }
+
+    /// Counts the checked and the unchecked cells of the checkbox field over the rows of the view.
+    pub async fn count_checkbox_cells(&self, view_id: &str, field_id: &str) -> FlowyResult<CheckboxCount> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_checkbox() {
+            return Err(GridError::type_mismatch(field_id, "checkbox").into());
+        }
+
+        let row_revs = self.get_all_row_revs(view_id).await?;
+        let cell_data = row_revs.iter().map(|row_rev| {
+            row_rev
+                .cells
+                .get(field_id)
+                .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+                .filter(|type_cell_data| type_cell_data.is_checkbox())
+                .and_then(|type_cell_data| CheckboxCellData::from_cell_str(&type_cell_data.cell_str).ok())
+                .unwrap_or_default()
+        });
+        Ok(CheckboxCount::from_cell_data(cell_data))
+    }
+
+    /// Returns the options of the select field ordered by how many rows of the view select them.
+    /// The stored order of the options is only replaced with this order if `persist` is true.
+    pub async fn select_options_by_usage(
+        &self,
+        view_id: &str,
+        field_id: &str,
+        persist: bool,
+    ) -> FlowyResult<Vec<SelectOptionPB>> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_select_option() {
+            return Err(GridError::type_mismatch(field_id, "select option").into());
+        }
+
+        let row_revs = self.get_all_row_revs(view_id).await?;
+        let cell_data = row_revs
+            .iter()
+            .flat_map(|row_rev| {
+                let type_cell_data = TypeCellData::try_from(row_rev.cells.get(field_id)?).ok()?;
+                if !type_cell_data.is_select_option() {
+                    return None;
+                }
+                SelectOptionIds::from_cell_str(&type_cell_data.cell_str).ok()
+            })
+            .collect::<Vec<SelectOptionIds>>();
+        let options = select_type_option_from_field_rev(&field_rev)?.options_by_usage(&cell_data);
+
+        if persist {
+            let _ = self
+                .modify_field_rev(field_id, |field_rev| {
+                    let mut type_option = select_type_option_from_field_rev(field_rev)?;
+                    if type_option.options() == &options {
+                        return Ok(None);
+                    }
+                    *type_option.mut_options() = options.clone();
+                    field_rev.insert_type_option(&*type_option);
+                    Ok(Some(()))
+                })
+                .await?;
+        }
+        Ok(options)
+    }
+
+    /// Returns the sum of the number field over the rows of the view. The sum is formatted with the
+    /// field's format, for example, the currency symbol for the currency formats.
+    pub async fn calculate_sum(&self, view_id: &str, field_id: &str) -> FlowyResult<CalculationPB> {
+        self.calculate(view_id, field_id, CalculationTypePB::Sum, CalculationOptions::default())
+            .await
+    }
+
+    /// Calculates the number field over the rows of the view, see [CalculationOptions] for how the
+    /// empty cells and the filtered rows are treated. The date field is calculated by
+    /// [CalculationTypePB::Earliest] and [CalculationTypePB::Latest], which always skip the empty
+    /// cells.
+    pub async fn calculate(
+        &self,
+        view_id: &str,
+        field_id: &str,
+        calculation_type: CalculationTypePB,
+        options: CalculationOptions,
+    ) -> FlowyResult<CalculationPB> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        if calculation_type.is_date_calculation() {
+            if !field_type.is_date() {
+                return Err(GridError::type_mismatch(field_id, "date").into());
+            }
+        } else if !field_type.is_number() {
+            return Err(GridError::type_mismatch(field_id, "number").into());
+        }
+
+        let mut cells = self.cells_for_field(field_id).await?;
+        if options.respect_filters {
+            let row_ids = self
+                .get_all_row_revs(view_id)
+                .await?
+                .iter()
+                .map(|row_rev| row_rev.id.clone())
+                .collect::<HashSet<String>>();
+            cells.retain(|(row_id, _)| row_ids.contains(row_id));
+        }
+        let cell_strs = cells
+            .into_iter()
+            .map(|(_, type_cell_data)| {
+                if type_cell_data.field_type == field_type {
+                    type_cell_data.cell_str
+                } else {
+                    "".to_owned()
+                }
+            })
+            .collect::<Vec<String>>();
+        let value = if field_type.is_date() {
+            let type_option = field_rev
+                .get_type_option::<DateTypeOptionPB>(field_rev.ty)
+                .unwrap_or_default();
+            type_option.calculate_cell_strs(&cell_strs, &calculation_type)?
+        } else {
+            let type_option = field_rev
+                .get_type_option::<NumberTypeOptionPB>(field_rev.ty)
+                .unwrap_or_default();
+            type_option
+                .calculate_cell_strs(&cell_strs, &calculation_type, options.empty_as_zero)?
+                .to_string()
+        };
+        Ok(CalculationPB {
+            field_id: field_id.to_owned(),
+            value,
+        })
+    }
+
+    /// Returns true if the row exists in any block of the grid. An unknown row id is not an error
+    /// here, it just returns false.
+    pub async fn row_exists(&self, row_id: &str) -> bool {
+        matches!(self.block_manager.get_row_rev(row_id).await, Ok(Some(_)))
+    }
+
+    pub async fn get_row_rev(&self, row_id: &str) -> FlowyResult<Option<Arc<RowRevision>>> {
+        match self.block_manager.get_row_rev(row_id).await? {
+            None => Ok(None),
+            Some((_, row_rev)) => Ok(Some(row_rev)),
+        }
+    }
+
+    /// Returns the title of the row, which is the display value of its primary cell. It falls back
+    /// to the first non-empty cell in the field order of the view if the primary cell is empty.
+    pub async fn row_display_title(&self, view_id: &str, row_id: &str) -> FlowyResult<String> {
+        let row_rev = match self.get_row_rev(row_id).await? {
+            None => {
+                return Err(GridError::row_not_found(row_id).into());
+            }
+            Some(row_rev) => row_rev,
+        };
+
+        let mut field_revs = self.get_view_field_revs(view_id).await?;
+        // The sort is stable, so the other fields keep their order after the primary field.
+        field_revs.sort_by_key(|field_rev| !field_rev.is_primary);
+        let title = field_revs
+            .iter()
+            .flat_map(|field_rev| {
+                let cell_rev = row_rev.cells.get(&field_rev.id)?;
+                self.format_cell_rev(cell_rev, field_rev)
+            })
+            .find(|display_str| !display_str.trim().is_empty())
+            .unwrap_or_default();
+        Ok(title)
+    }
+
+    pub async fn delete_row(&self, row_id: &str) -> FlowyResult<()> {
+        let row_rev = self.block_manager.delete_row(row_id).await?;
+        tracing::trace!("Did delete row:{:?}", row_rev);
+        if let Some((row_rev, changeset)) = row_rev {
+            let _ = self.update_block(changeset).await?;
+            self.view_manager.did_delete_row(row_rev).await;
+            self.send_grid_event(GridChangeEvent::RowDeleted {
+                row_id: row_id.to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the number of rows of the grid. The count is read from the blocks, which keep
+    /// their row count up to date when rows are inserted or deleted, so the rows are not iterated.
+    pub async fn row_count(&self) -> usize {
+        self.grid_pad
+            .read()
+            .await
+            .get_block_meta_revs()
+            .iter()
+            .map(|block_meta_rev| block_meta_rev.row_count as usize)
+            .sum()
+    }
+
+    pub async fn subscribe_view_changed(&self, view_id: &str) -> FlowyResult<broadcast::Receiver<GridViewChanged>> {
+        self.view_manager.subscribe_view_changed(view_id).await
+    }
+
+    /// Subscribes to all the mutations of the grid, which lets the integrations react to the
+    /// fields, rows, cells, filters and sorts in one place. Archiving a row sends the
+    /// [GridChangeEvent::RowUpdated] of the row.
+    pub fn subscribe_grid_event(&self) -> broadcast::Receiver<GridChangeEvent> {
+        self.event_notifier.subscribe()
+    }
+
+    fn send_grid_event(&self, event: GridChangeEvent) {
+        // Sending fails only if nobody subscribed, which is fine.
+        let _ = self.event_notifier.send(event);
+    }
+
+    /// Inserts a copy of the row below it. The copy keeps the cells and the authors of the row.
+    pub async fn duplicate_row(&self, row_id: &str) -> FlowyResult<RowPB> {
+        let row_rev = match self.get_row_rev(row_id).await? {
+            None => return Err(GridError::row_not_found(row_id).into()),
+            Some(row_rev) => row_rev,
+        };
+        let mut new_row_rev = row_rev.as_ref().clone();
+        new_row_rev.id = gen_row_id();
+
+        let new_row_id = new_row_rev.id.clone();
+        let row_pb = self.create_row_pb(new_row_rev, Some(row_id.to_owned())).await?;
+        // The groups pick up the copy the same way they pick up a row whose cells were changed.
+        self.view_manager.did_update_cell(&new_row_id).await;
+        Ok(row_pb)
+    }
+
+    /// Inserts a copy of the row of the `source` grid at the end of this grid. The cells are
+    /// mapped to the fields by the names of the fields. A cell is copied as it is if both fields
+    /// are of the same type, otherwise it's parsed from its display string, e.g. the select options
+    /// are mapped by their names. The cells that can't be parsed and the fields that this grid
+    /// doesn't have are dropped, and the read-only or computed fields are skipped.
+    pub async fn clone_row_from(&self, source: &GridRevisionEditor, row_id: &str) -> FlowyResult<RowPB> {
+        let source_row_rev = match source.get_row_rev(row_id).await? {
+            None => return Err(GridError::row_not_found(row_id).into()),
+            Some(row_rev) => row_rev,
+        };
+        let source_field_revs = source.get_field_revs(None).await?;
+        let mut new_row_rev = self.create_row_rev().await?;
+        for source_field_rev in source_field_revs.iter() {
+            let source_cell_rev = match source_row_rev.cells.get(&source_field_rev.id) {
+                None => continue,
+                Some(cell_rev) => cell_rev,
+            };
+            let field_rev = match self.field_by_name(&source_field_rev.name).await {
+                None => continue,
+                Some(field_rev) => field_rev,
+            };
+            let field_type = match FieldType::try_from_type_rev(field_rev.ty) {
+                None => continue,
+                Some(field_type) => field_type,
+            };
+            if ensure_writable(&field_rev).is_err() {
+                continue;
+            }
+
+            // The options of the select fields have different ids in each grid
+            let cell_rev = if field_rev.ty == source_field_rev.ty && !field_type.is_select_option() {
+                source_cell_rev.clone()
+            } else {
+                let cell_rev = source
+                    .format_cell_rev(source_cell_rev, source_field_rev)
+                    .and_then(|display_str| cell_changeset_from_display_str(&display_str, &field_rev).ok())
+                    .and_then(|changeset| apply_cell_data_changeset(changeset, None, field_rev.as_ref(), None).ok())
+                    .map(CellRevision::new);
+                match cell_rev {
+                    None => {
+                        tracing::debug!(
+                            "Drop the cell of the field:{} that can't be transformed",
+                            field_rev.name
+                        );
+                        continue;
+                    }
+                    Some(cell_rev) => cell_rev,
+                }
+            };
+            new_row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+        }
+
+        let new_row_id = new_row_rev.id.clone();
+        let row_pb = self.create_row_pb(new_row_rev, None).await?;
+        self.view_manager.did_update_cell(&new_row_id).await;
+        Ok(row_pb)
+    }
+
+    pub async fn get_cell(&self, params: &CellPathParams) -> Option<CellPB> {
+        let (field_type, cell_bytes) = self.decode_cell_data_from(params).await?;
+        Some(CellPB::new(&params.field_id, field_type, cell_bytes.to_vec()))
+    }
+
+    pub async fn get_cell_display_str(&self, params: &CellPathParams) -> String {
+        let display_str = || async {
+            let field_rev = self.get_field_rev(&params.field_id).await?;
+            let cell_rev = self.get_cell_rev(&params.row_id, &params.field_id).await.ok()??;
+            self.format_cell_rev(&cell_rev, &field_rev)
+        };
+
+        display_str().await.unwrap_or_else(|| "".to_string())
+    }
+
+    /// Returns the value of the cell as it's stored, e.g. the option ids of the select cell or the
+    /// timestamp of the date cell. Returns `None` if the row has no cell for the field.
+    pub async fn cell_raw_value(&self, params: &CellPathParams) -> Option<String> {
+        let cell_rev = self.get_cell_rev(&params.row_id, &params.field_id).await.ok()??;
+        let type_cell_data = TypeCellData::try_from(&cell_rev).ok()?;
+        Some(type_cell_data.cell_str)
+    }
+
+    /// Sets the background color of the text cell, which must be in the `#RRGGBB` format. The
+    /// color is removed if it's None. The content of the cell is kept as it is.
+    pub async fn set_cell_color(&self, row_id: &str, field_id: &str, color: Option<String>) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let _ = ensure_writable(&field_rev)?;
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_text() {
+            return Err(GridError::type_mismatch(field_id, "text").into());
+        }
+        if let Some(color) = color.as_ref().filter(|color| !is_hex_color(color)) {
+            let msg = format!("Invalid color: {}, expected the #RRGGBB format", color);
+            return Err(GridError::invalid_cell_data(msg).into());
+        }
+
+        let mut type_cell_data = match self.get_cell_rev(row_id, field_id).await? {
+            None => TypeCellData::from_field_type(&field_type),
+            Some(cell_rev) => TypeCellData::try_from(&cell_rev)?,
+        };
+        type_cell_data.color = color;
+        let user_id = self.user.user_id()?;
+        self.save_cell(&user_id, row_id, field_id, type_cell_data.to_json())
+            .await
+    }
+
+    /// Returns the background color of the cell, or None if the cell has no color.
+    pub async fn cell_color(&self, row_id: &str, field_id: &str) -> FlowyResult<Option<String>> {
+        match self.get_cell_rev(row_id, field_id).await? {
+            None => Ok(None),
+            Some(cell_rev) => Ok(TypeCellData::try_from(&cell_rev)?.color),
+        }
+    }
+
+    /// Returns the cells of the field with the ids of their rows, one for each row of the grid in
+    /// the order of the rows. The rows hidden by the filters are included, and the row without a
+    /// cell for the field gets the empty cell of the field's type.
+    pub async fn cells_for_field(&self, field_id: &str) -> FlowyResult<Vec<(String, TypeCellData)>> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        let cells = self
+            .get_all_block_row_revs()
+            .await?
+            .into_iter()
+            .map(|row_rev| {
+                let type_cell_data = row_rev
+                    .cells
+                    .get(field_id)
+                    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+                    .unwrap_or_else(|| TypeCellData::from_field_type(&field_type));
+                (row_rev.id.clone(), type_cell_data)
+            })
+            .collect();
+        Ok(cells)
+    }
+
+    /// Returns the value of the cell as it's rendered, using the registered cell formatters.
+    pub async fn cell_display_value(&self, params: &CellPathParams) -> String {
+        self.get_cell_display_str(params).await
+    }
+
+    fn format_cell_rev(&self, cell_rev: &CellRevision, field_rev: &FieldRevision) -> Option<String> {
+        if FieldType::try_from_type_rev(field_rev.ty).is_none() {
+            return Some(UNKNOWN_FIELD_TYPE_PLACEHOLDER.to_owned());
+        }
+        let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+        Some(self.format_type_cell_data(type_cell_data, field_rev))
+    }
+
+    fn format_type_cell_data(&self, type_cell_data: TypeCellData, field_rev: &FieldRevision) -> String {
+        if FieldType::try_from_type_rev(field_rev.ty).is_none() {
+            return UNKNOWN_FIELD_TYPE_PLACEHOLDER.to_owned();
+        }
+        // The cell keeps the field type it was written with until it's edited again, e.g. after
+        // switching the field type, so it's decoded with that type.
+        let field_type: FieldType = field_rev.ty.into();
+        self.cell_formatters.read().format(
+            type_cell_data.cell_str,
+            &type_cell_data.field_type,
+            &field_type,
+            field_rev,
+        )
+    }
+
+    /// Returns the display strings of the cells of the view. Each line of the matrix is a row,
+    /// in the order of the filtered and sorted rows, and holds the cells in the order of the
+    /// view's fields. The cell is rendered as [Self::get_cell_display_str] does.
+    pub async fn grid_as_matrix(&self, view_id: &str) -> FlowyResult<Vec<Vec<String>>> {
+        let field_revs = self.get_view_field_revs(view_id).await?;
+        let row_revs = self.get_all_row_revs(view_id).await?;
+        Ok(self.make_display_matrix(&field_revs, &row_revs))
+    }
+
+    fn make_display_matrix(
+        &self,
+        field_revs: &[Arc<FieldRevision>],
+        row_revs: &[Arc<RowRevision>],
+    ) -> Vec<Vec<String>> {
+        row_revs
+            .iter()
+            .map(|row_rev| {
+                field_revs
+                    .iter()
+                    .map(|field_rev| {
+                        row_rev
+                            .cells
+                            .get(&field_rev.id)
+                            .and_then(|cell_rev| self.format_cell_rev(cell_rev, field_rev))
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Exports the rows of the view as CSV. The header line is the names of the fields in the
+    /// order of the view, and each cell is rendered as [Self::get_cell_display_str] does.
+    pub async fn export_csv(&self, view_id: &str, options: ExportOptions) -> FlowyResult<String> {
+        let field_revs = self.get_view_field_revs(view_id).await?;
+        let row_revs = self.get_all_row_revs(view_id).await?;
+
+        let mut builder = CSVBuilder::default();
+        let header = field_revs
+            .iter()
+            .map(|field_rev| field_rev.name.clone())
+            .collect::<Vec<String>>();
+        builder.push_line(&header);
+        for cells in self.make_display_matrix(&field_revs, &row_revs) {
+            builder.push_line(&cells);
+        }
+
+        if options.include_aggregation_footer {
+            let mut footer = Vec::with_capacity(field_revs.len());
+            for field_rev in field_revs.iter() {
+                footer.push(self.get_aggregation_str(view_id, field_rev).await?);
+            }
+            builder.push_line(&footer);
+        }
+        Ok(builder.build())
+    }
+
+    async fn get_aggregation_str(&self, view_id: &str, field_rev: &FieldRevision) -> FlowyResult<String> {
+        let field_type: FieldType = field_rev.ty.into();
+        match field_type {
+            FieldType::Number => Ok(self.calculate_sum(view_id, &field_rev.id).await?.value),
+            FieldType::Checkbox => {
+                let count = self.count_checkbox_cells(view_id, &field_rev.id).await?;
+                Ok(format!("{} checked / {} unchecked", count.checked, count.unchecked))
+            }
+            _ => Ok("".to_owned()),
+        }
+    }
+
+    /// Overrides how the cells of the `field_type` are rendered by [Self::get_cell_display_str].
+    pub fn register_cell_formatter(&self, field_type: FieldType, formatter: Arc<dyn CellFormatter>) {
+        self.cell_formatters.write().register(field_type, formatter);
+    }
+
+    pub async fn get_cell_bytes(&self, params: &CellPathParams) -> Option<CellProtobufBlob> {
+        let (_, cell_data) = self.decode_cell_data_from(params).await?;
+        Some(cell_data)
+    }
+
+    async fn decode_cell_data_from(&self, params: &CellPathParams) -> Option<(FieldType, CellProtobufBlob)> {
+        let field_rev = self.get_field_rev(&params.field_id).await?;
+        let (_, row_rev) = self.block_manager.get_row_rev(&params.row_id).await.ok()??;
+        let cell_rev = row_rev.cells.get(&params.field_id)?.clone();
+        let field_type = FieldType::try_from_type_rev(field_rev.ty);
+        if let Some(field_type) = field_type.filter(|field_type| field_type.is_user()) {
+            // The cell stores the id of the user, the name is displayed instead
+            let display_str = self.format_cell_rev(&cell_rev, &field_rev).unwrap_or_default();
+            return Some((field_type, CellProtobufBlob::new(display_str)));
+        }
+        Some(decode_type_cell_data(
+            cell_rev.type_cell_data,
+            &field_rev,
+            Some(self.cell_data_cache.clone()),
+        ))
+    }
+
+    pub async fn get_cell_rev(&self, row_id: &str, field_id: &str) -> FlowyResult<Option<CellRevision>> {
+        match self.block_manager.get_row_rev(row_id).await? {
+            None => Ok(None),
+            Some((_, row_rev)) => {
+                let cell_rev = row_rev.cells.get(field_id).cloned();
+                Ok(cell_rev)
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn update_cell_with_changeset<T: ToCellChangesetString>(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        cell_changeset: T,
+    ) -> FlowyResult<()> {
+        let user_id = self.user.user_id()?;
+        self.update_cell_as_user(&user_id, row_id, field_id, cell_changeset)
+            .await
+    }
+
+    /// Sets the cell from its display string, e.g. "Mar 14, 2022" for a date cell, "$1,200" for a
+    /// number cell or the names of the options for a select cell.
+    pub async fn set_cell_from_str(&self, row_id: &str, field_id: &str, display_str: &str) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let cell_changeset = cell_changeset_from_display_str(display_str, &field_rev)?;
+        self.update_cell_with_changeset(row_id, field_id, cell_changeset).await
+    }
+
+    /// Pastes the tab-separated text copied from a table into the view. The first cell of the text
+    /// goes into the cell of the anchor row and field, the others follow the view's order of the
+    /// rows and fields. Rows are created when the text has more lines than the rows below the
+    /// anchor, while the cells beyond the last field are dropped. The read-only fields are skipped.
+    ///
+    /// Each cell is parsed as [Self::set_cell_from_str] does, and nothing is written, nor any row
+    /// created, if any of the cells is invalid for its field, e.g. a multi-select cell that selects
+    /// more options than the field allows.
+    pub async fn paste_cells(
+        &self,
+        view_id: &str,
+        anchor_row_id: &str,
+        anchor_field_id: &str,
+        tsv: &str,
+    ) -> FlowyResult<()> {
+        let field_revs = self.get_view_field_revs(view_id).await?;
+        let field_index = match field_revs.iter().position(|field_rev| field_rev.id == anchor_field_id) {
+            None => return Err(GridError::field_not_found(anchor_field_id).into()),
+            Some(index) => index,
+        };
+        let row_revs = self.get_all_row_revs(view_id).await?;
+        let mut row_ids = row_revs
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+        let row_index = match row_ids.iter().position(|row_id| row_id == anchor_row_id) {
+            None => return Err(GridError::row_not_found(anchor_row_id).into()),
+            Some(index) => index,
+        };
+
+        let lines = parse_tsv(tsv);
+        let mut cell_changesets = vec![];
+        for (line_index, line) in lines.iter().enumerate() {
+            for (field_rev, display_str) in field_revs[field_index..].iter().zip(line.iter()) {
+                if ensure_writable(field_rev).is_err() {
+                    continue;
+                }
+                let cell_changeset = cell_changeset_from_display_str(display_str, field_rev)?;
+                // Applies the changeset to the current cell without saving it, so the cells that the
+                // field rejects fail before anything is written
+                let cell_rev = row_revs
+                    .get(row_index + line_index)
+                    .and_then(|row_rev| row_rev.cells.get(&field_rev.id).cloned());
+                let _ = apply_cell_data_changeset(cell_changeset.clone(), cell_rev, field_rev, None)?;
+                cell_changesets.push((row_index + line_index, field_rev.id.clone(), cell_changeset));
+            }
+        }
+
+        while row_ids.len() < row_index + lines.len() {
+            let params = CreateRowParams {
+                grid_id: self.grid_id.clone(),
+                start_row_id: row_ids.last().cloned(),
+                group_id: None,
+                layout: GridLayout::Table,
+            };
+            let row_pb = self.create_row(params).await?;
+            row_ids.push(row_pb.id);
+        }
+
+        for (index, field_id, cell_changeset) in cell_changesets {
+            let _ = self
+                .update_cell_with_changeset(&row_ids[index], &field_id, cell_changeset)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Same as `update_cell_with_changeset` but the edit is recorded as made by the user with
+    /// `user_id`, which is shown in the `LastEditedBy` cells of the row.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    async fn update_cell_as_user<T: ToCellChangesetString>(
+        &self,
+        user_id: &str,
+        row_id: &str,
+        field_id: &str,
+        cell_changeset: T,
+    ) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let _ = ensure_writable(&field_rev)?;
+
+        tracing::trace!("Cell changeset: id:{} / value:{:?}", &field_id, cell_changeset);
+        let cell_rev = self.get_cell_rev(row_id, field_id).await?;
+        let field_type: FieldType = field_rev.ty.into();
+        let old_option_ids = if field_type.is_select_option() {
+            let type_cell_data = cell_rev.as_ref().map(|cell_rev| cell_rev.type_cell_data.as_str());
+            Some(select_option_ids_from_type_cell_data(
+                type_cell_data.unwrap_or_default(),
+            ))
+        } else {
+            None
+        };
+        // Update the changeset.data property with the return value.
+        let type_cell_data =
+            apply_cell_data_changeset(cell_changeset, cell_rev, field_rev, Some(self.cell_data_cache.clone()))?;
+
+        // The options that were added to the cell become the most recently used ones
+        let added_option_ids = old_option_ids.map(|old_option_ids| {
+            select_option_ids_from_type_cell_data(&type_cell_data)
+                .into_inner()
+                .into_iter()
+                .filter(|option_id| !old_option_ids.contains(option_id))
+                .collect::<Vec<String>>()
+        });
+        self.save_cell(user_id, row_id, field_id, type_cell_data).await?;
+        if let Some(added_option_ids) = added_option_ids {
+            self.select_option_recency.write().record(field_id, &added_option_ids);
+        }
+        Ok(())
+    }
+
+    /// Returns up to `n` options of the select field that were most recently written to its
+    /// cells, most recent first. The options that were deleted since then are skipped.
+    pub async fn recent_options(&self, field_id: &str, n: usize) -> FlowyResult<Vec<SelectOptionPB>> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_select_option() {
+            return Err(GridError::type_mismatch(field_id, "select option").into());
+        }
+
+        let type_option = select_type_option_from_field_rev(&field_rev)?;
+        let recent_option_ids = self.select_option_recency.write().recent_option_ids(field_id);
+        let options = recent_option_ids
+            .iter()
+            .flat_map(|option_id| {
+                type_option
+                    .options()
+                    .iter()
+                    .find(|option| &option.id == option_id)
+                    .cloned()
+            })
+            .take(n)
+            .collect();
+        Ok(options)
+    }
+
+    /// Copies the cell of the field from one row to another. Both cells belong to the same field,
+    /// so the data is copied as it is, e.g. the select cells share the option ids. The select
+    /// cells are applied as a changeset first, so the copy is rejected if it selects more options
+    /// than the field allows. An empty source cell clears the destination cell.
+    pub async fn copy_cell(&self, from_row_id: &str, to_row_id: &str, field_id: &str) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let _ = ensure_writable(&field_rev)?;
+        let field_type: FieldType = field_rev.ty.into();
+        if self.get_row_rev(to_row_id).await?.is_none() {
+            return Err(GridError::row_not_found(to_row_id).into());
+        }
+
+        let type_cell_data = match self.get_row_rev(from_row_id).await? {
+            None => {
+                return Err(GridError::row_not_found(from_row_id).into());
+            }
+            Some(row_rev) => match row_rev.cells.get(field_id) {
+                None => TypeCellData::new("".to_owned(), field_type).to_json(),
+                Some(cell_rev) => cell_rev.type_cell_data.clone(),
+            },
+        };
+        let type_cell_data = if field_type.is_select_option() {
+            let option_ids = select_option_ids_from_type_cell_data(&type_cell_data).into_inner();
+            let changeset = SelectOptionCellChangeset::from_insert_options(option_ids);
+            apply_cell_data_changeset(changeset, None, field_rev, None)?
+        } else {
+            type_cell_data
+        };
+        let user_id = self.user.user_id()?;
+        self.save_cell(&user_id, to_row_id, field_id, type_cell_data).await
+    }
+
+    /// Copies the cells of every row from one field to another. The cells are copied as they are
+    /// if both fields have the same type, except the select cells whose options are mapped to the
+    /// options of the destination field with the same names. Otherwise, the destination field must
+    /// be a text or a URL field, which gets the display strings of the cells, e.g. "$1" for a USD
+    /// number cell. The empty cells clear the destination cells.
+    pub async fn copy_column(&self, from_field_id: &str, to_field_id: &str) -> FlowyResult<()> {
+        let from_field_rev = match self.get_field_rev(from_field_id).await {
+            None => return Err(GridError::field_not_found(from_field_id).into()),
+            Some(field_rev) => field_rev,
+        };
+        let to_field_rev = match self.get_field_rev(to_field_id).await {
+            None => return Err(GridError::field_not_found(to_field_id).into()),
+            Some(field_rev) => field_rev,
+        };
+        let to_field_type: FieldType = to_field_rev.ty.into();
+        let _ = ensure_writable(&to_field_rev)?;
+        let is_same_type = from_field_rev.ty == to_field_rev.ty;
+        if !is_same_type && !to_field_type.is_text() && !to_field_type.is_url() {
+            return Err(GridError::type_mismatch(to_field_id, "text").into());
+        }
+
+        let user_id = self.user.user_id()?;
+        for (row_id, type_cell_data) in self.cells_for_field(from_field_id).await? {
+            if is_same_type && to_field_type.is_select_option() {
+                // The options of the select fields have different ids, so the options are mapped
+                // by their names.
+                let display_str = self.format_type_cell_data(type_cell_data, &from_field_rev);
+                let changeset = cell_changeset_from_display_str(&display_str, &to_field_rev)?;
+                let type_cell_data = apply_cell_data_changeset(changeset, None, to_field_rev.as_ref(), None)?;
+                let _ = self.save_cell(&user_id, &row_id, to_field_id, type_cell_data).await?;
+            } else if is_same_type {
+                let _ = self
+                    .save_cell(&user_id, &row_id, to_field_id, type_cell_data.to_json())
+                    .await?;
+            } else {
+                // The empty cells stay empty instead of getting the display string of the
+                // default value
+                let display_str = if type_cell_data.cell_str.is_empty() {
+                    "".to_owned()
+                } else {
+                    self.format_type_cell_data(type_cell_data, &from_field_rev)
+                };
+                let _ = self
+                    .update_cell_as_user(&user_id, &row_id, to_field_id, display_str)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn save_cell(&self, user_id: &str, row_id: &str, field_id: &str, type_cell_data: String) -> FlowyResult<()> {
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        let mut changeset = RowChangeset::new(row_id.to_owned());
+        changeset
+            .cell_by_field_id
+            .insert(field_id.to_owned(), CellRevision::new(type_cell_data));
+        self.set_last_edited_by(&mut changeset, user_id, &field_revs);
+        let _ = self.block_manager.update_rows(vec![changeset]).await?;
+        self.view_manager.did_update_cell(row_id).await;
+        self.send_grid_event(GridChangeEvent::CellChanged {
+            row_id: row_id.to_owned(),
+            field_id: field_id.to_owned(),
+        });
+        self.recompute_dependent_cells(row_id, &[field_id.to_owned()]).await
+    }
 
-        self.view_manager.will_create_row(&mut row_rev, &params).await;
+    /// Records the user as the last editor of the row in the changeset. The `LastEditedBy` cells
+    /// are refreshed along with the other changes of the row, so the edit is saved in one revision.
+    fn set_last_edited_by(&self, changeset: &mut RowChangeset, user_id: &str, field_revs: &[Arc<FieldRevision>]) {
+        changeset.last_edited_by = Some(user_id.to_owned());
+        for field_rev in field_revs.iter() {
+            let field_type: FieldType = field_rev.ty.into();
+            if field_type.is_last_edited_by() {
+                let cell_rev = self.make_user_cell_rev(user_id, field_type);
+                changeset.cell_by_field_id.insert(field_rev.id.clone(), cell_rev);
+            }
+        }
+    }
 
-        let row_pb = self.create_row_pb(row_rev, params.start_row_id.clone()).await?;
+    /// Fills the cells of the user field with the authors of all the rows. It does nothing if the
+    /// field is not a `CreatedBy` or `LastEditedBy` field.
+    async fn fill_user_field(&self, field_id: &str) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => return Ok(()),
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_user() {
+            return Ok(());
+        }
 
-        self.view_manager.did_create_row(&row_pb, &params).await;
-        Ok(row_pb)
-    }
+        let row_revs = self.get_all_block_row_revs().await?;
+        let mut changesets = vec![];
+        for row_rev in row_revs.iter() {
+            if let Some(user_id) = row_author(row_rev, &field_type) {
+                changesets.push(CellChangesetPB {
+                    grid_id: self.grid_id.clone(),
+                    row_id: row_rev.id.clone(),
+                    field_id: field_id.to_owned(),
+                    type_cell_data: self.make_user_cell_rev(user_id, field_type.clone()).type_cell_data,
+                });
+            }
+        }
 
-    #[tracing::instrument(level = "trace", skip_all, err)]
-    pub async fn move_group(&self, params: MoveGroupParams) -> FlowyResult<()> {
-        let _ = self.view_manager.move_group(params).await?;
+        let _ = self.block_manager.update_cells(changesets).await?;
+        for row_rev in row_revs.iter() {
+            self.view_manager.did_update_cell(&row_rev.id).await;
+        }
         Ok(())
     }
 
-    pub async fn insert_rows(&self, row_revs: Vec<RowRevision>) -> FlowyResult<Vec<RowPB>> {
-        let block_id = self.block_id().await?;
-        let mut rows_by_block_id: HashMap<String, Vec<RowRevision>> = HashMap::new();
-        let mut row_orders = vec![];
-        for row_rev in row_revs {
-            row_orders.push(RowPB::from(&row_rev));
-            rows_by_block_id
-                .entry(block_id.clone())
-                .or_insert_with(Vec::new)
-                .push(row_rev);
-        }
-        let changesets = self.block_manager.insert_row(rows_by_block_id).await?;
-        for changeset in changesets {
-            let _ = self.update_block(changeset).await?;
+    /// Fills the `CreatedBy` and `LastEditedBy` cells of the row that is going to be inserted.
+    fn insert_user_cells(&self, row_rev: &mut RowRevision, field_revs: &[Arc<FieldRevision>]) {
+        for field_rev in field_revs {
+            let field_type: FieldType = field_rev.ty.into();
+            if let Some(user_id) = row_author(row_rev, &field_type) {
+                let cell_rev = self.make_user_cell_rev(user_id, field_type);
+                row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+            }
         }
-        Ok(row_orders)
     }
 
-    pub async fn update_row(&self, changeset: RowChangeset) -> FlowyResult<()> {
-        let row_id = changeset.row_id.clone();
-        let _ = self.block_manager.update_row(changeset).await?;
-        self.view_manager.did_update_cell(&row_id).await;
-        Ok(())
+    fn make_user_cell_rev(&self, user_id: &str, field_type: FieldType) -> CellRevision {
+        let cell_data = UserCellData::new(user_id);
+        let cell_str = cell_data.to_json().unwrap_or_default();
+        CellRevision::new(TypeCellData::new(cell_str, field_type).to_json())
     }
 
-    /// Returns all the rows in this block.
-    pub async fn get_row_pbs(&self, view_id: &str, block_id: &str) -> FlowyResult<Vec<RowPB>> {
-        let rows = self.view_manager.get_row_revs(view_id, block_id).await?;
-        let rows = rows.into_iter().map(|row_rev| RowPB::from(&row_rev)).collect();
-        Ok(rows)
-    }
+    /// Adds the option to the multi-select or checklist cell without replacing the options that
+    /// are already selected. The `value` is the id or the name of the option. The option is
+    /// merged with the stored cell, so the client doesn't need to read the cell first.
+    pub async fn append_to_cell(&self, row_id: &str, field_id: &str, value: &str) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        let options = match field_type {
+            FieldType::MultiSelect => MultiSelectTypeOptionPB::from(&field_rev).options,
+            FieldType::Checklist => ChecklistTypeOptionPB::from(&field_rev).options,
+            _ => {
+                return Err(GridError::type_mismatch(field_id, "multi-select or checklist").into());
+            }
+        };
 
-    pub async fn get_all_row_revs(&self, view_id: &str) -> FlowyResult<Vec<Arc<RowRevision>>> {
-        let mut all_rows = vec![];
-        let blocks = self.block_manager.get_blocks(None).await?;
-        for block in blocks {
-            let rows = self.view_manager.get_row_revs(view_id, &block.block_id).await?;
-            all_rows.extend(rows);
+        let option = options
+            .iter()
+            .find(|option| option.id == value)
+            .or_else(|| options.iter().find(|option| option.name == value));
+        match option {
+            None => Err(GridError::option_not_found(value).into()),
+            Some(option) => {
+                let changeset = SelectOptionCellChangeset::from_insert_option_id(&option.id);
+                self.update_cell_with_changeset(row_id, field_id, changeset).await
+            }
         }
-        Ok(all_rows)
     }
 
-    pub async fn get_row_rev(&self, row_id: &str) -> FlowyResult<Option<Arc<RowRevision>>> {
-        match self.block_manager.get_row_rev(row_id).await? {
-            None => Ok(None),
-            Some((_, row_rev)) => Ok(Some(row_rev)),
+    /// Appends an item named `name` to the checklist field and returns it. The names of the items
+    /// are unique, and no cell has the new item completed.
+    pub async fn add_checklist_item(&self, field_id: &str, name: &str) -> FlowyResult<SelectOptionPB> {
+        let field_rev = self.get_checklist_field_rev(field_id).await?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(GridError::OptionNameIsEmpty.into());
+        }
+        let type_option = ChecklistTypeOptionPB::from(&field_rev);
+        if type_option.option_id_for_name(name).is_some() {
+            return Err(GridError::duplicate_option_name(name).into());
         }
-    }
 
-    pub async fn delete_row(&self, row_id: &str) -> FlowyResult<()> {
-        let row_rev = self.block_manager.delete_row(row_id).await?;
-        tracing::trace!("Did delete row:{:?}", row_rev);
-        if let Some(row_rev) = row_rev {
-            self.view_manager.did_delete_row(row_rev).await;
+        let item = type_option.create_option(name);
+        let new_item = item.clone();
+        let _ = self
+            .modify_field_rev(field_id, |field_rev| {
+                let mut type_option = ChecklistTypeOptionPB::from(&*field_rev);
+                type_option.options.push(new_item);
+                field_rev.insert_type_option(&type_option);
+                Ok(Some(()))
+            })
+            .await?;
+        Ok(item)
+    }
+
+    /// Removes the item from the checklist field, and from the cells that have it completed. The
+    /// cells that don't have it completed are untouched.
+    pub async fn remove_checklist_item(&self, field_id: &str, item_id: &str) -> FlowyResult<()> {
+        let field_rev = self.get_checklist_field_rev(field_id).await?;
+        let _ = ensure_writable(&field_rev)?;
+        if !ChecklistTypeOptionPB::from(&field_rev)
+            .options
+            .iter()
+            .any(|option| option.id == item_id)
+        {
+            return Err(GridError::option_not_found(item_id).into());
+        }
+        let _ = self
+            .modify_field_rev(field_id, |field_rev| {
+                let mut type_option = ChecklistTypeOptionPB::from(&*field_rev);
+                type_option.options.retain(|option| option.id != item_id);
+                field_rev.insert_type_option(&type_option);
+                Ok(Some(()))
+            })
+            .await?;
+
+        let mut changesets = vec![];
+        for row_rev in self.get_all_block_row_revs().await? {
+            let cell_rev = match row_rev.cells.get(field_id) {
+                None => continue,
+                Some(cell_rev) => cell_rev,
+            };
+            let mut type_cell_data = match TypeCellData::try_from(cell_rev) {
+                Ok(type_cell_data) if type_cell_data.is_select_option() => type_cell_data,
+                _ => continue,
+            };
+            let mut item_ids = SelectOptionIds::from_cell_str(&type_cell_data.cell_str).unwrap_or_default();
+            if !item_ids.contains(&item_id.to_owned()) {
+                continue;
+            }
+            item_ids.retain(|id| id != item_id);
+            type_cell_data.cell_str = item_ids.to_string();
+            changesets.push(CellChangesetPB {
+                grid_id: self.grid_id.clone(),
+                row_id: row_rev.id.clone(),
+                field_id: field_id.to_owned(),
+                type_cell_data: type_cell_data.to_json(),
+            });
+        }
+
+        let row_ids = changesets
+            .iter()
+            .map(|changeset| changeset.row_id.clone())
+            .collect::<Vec<String>>();
+        let _ = self.block_manager.update_cells(changesets).await?;
+        for row_id in row_ids.iter() {
+            self.view_manager.did_update_cell(row_id).await;
+            self.send_grid_event(GridChangeEvent::CellChanged {
+                row_id: row_id.clone(),
+                field_id: field_id.to_owned(),
+            });
+            let _ = self.recompute_dependent_cells(row_id, &[field_id.to_owned()]).await?;
         }
         Ok(())
     }
 
-    pub async fn subscribe_view_changed(&self, view_id: &str) -> FlowyResult<broadcast::Receiver<GridViewChanged>> {
-        self.view_manager.subscribe_view_changed(view_id).await
+    /// Moves the item of the checklist field to the `to_index` of the items. The index past the
+    /// last item moves it to the end. Nothing is saved if the item is already at the index.
+    pub async fn move_checklist_item(&self, field_id: &str, item_id: &str, to_index: usize) -> FlowyResult<()> {
+        let field_rev = self.get_checklist_field_rev(field_id).await?;
+        let options = ChecklistTypeOptionPB::from(&field_rev).options;
+        let from_index = match options.iter().position(|option| option.id == item_id) {
+            None => return Err(GridError::option_not_found(item_id).into()),
+            Some(from_index) => from_index,
+        };
+        let to_index = to_index.min(options.len() - 1);
+        if from_index == to_index {
+            return Ok(());
+        }
+
+        self.modify_field_rev(field_id, |field_rev| {
+            let mut type_option = ChecklistTypeOptionPB::from(&*field_rev);
+            let item = type_option.options.remove(from_index);
+            type_option.options.insert(to_index, item);
+            field_rev.insert_type_option(&type_option);
+            Ok(Some(()))
+        })
+        .await
     }
 
-    pub async fn duplicate_row(&self, _row_id: &str) -> FlowyResult<()> {
-        Ok(())
+    /// Completes the item in the checklist cell if it's not completed, otherwise uncompletes it.
+    /// Returns whether the item is completed after the toggle.
+    pub async fn toggle_checklist_item(&self, row_id: &str, field_id: &str, item_id: &str) -> FlowyResult<bool> {
+        let field_rev = self.get_checklist_field_rev(field_id).await?;
+        if !ChecklistTypeOptionPB::from(&field_rev)
+            .options
+            .iter()
+            .any(|option| option.id == item_id)
+        {
+            return Err(GridError::option_not_found(item_id).into());
+        }
+
+        let cell_rev = self.get_cell_rev(row_id, field_id).await?;
+        let type_cell_data = cell_rev.as_ref().map(|cell_rev| cell_rev.type_cell_data.as_str());
+        let is_completed =
+            select_option_ids_from_type_cell_data(type_cell_data.unwrap_or_default()).contains(&item_id.to_owned());
+        let changeset = if is_completed {
+            SelectOptionCellChangeset::from_delete_option_id(item_id)
+        } else {
+            SelectOptionCellChangeset::from_insert_option_id(item_id)
+        };
+        let _ = self.update_cell_with_changeset(row_id, field_id, changeset).await?;
+        Ok(!is_completed)
     }
 
-    pub async fn get_cell(&self, params: &CellPathParams) -> Option<CellPB> {
-        let (field_type, cell_bytes) = self.decode_cell_data_from(params).await?;
-        Some(CellPB::new(&params.field_id, field_type, cell_bytes.to_vec()))
+    async fn get_checklist_field_rev(&self, field_id: &str) -> FlowyResult<Arc<FieldRevision>> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_check_list() {
+            return Err(GridError::type_mismatch(field_id, "checklist").into());
+        }
+        Ok(field_rev)
     }
 
-    pub async fn get_cell_display_str(&self, params: &CellPathParams) -> String {
-        let display_str = || async {
-            let field_rev = self.get_field_rev(&params.field_id).await?;
-            let field_type: FieldType = field_rev.ty.into();
-            let cell_rev = self.get_cell_rev(&params.row_id, &params.field_id).await.ok()??;
-            let type_cell_data: TypeCellData = cell_rev.try_into().ok()?;
-            Some(stringify_cell_data(
-                type_cell_data.cell_str,
-                &field_type,
-                &field_type,
-                &field_rev,
-            ))
+    /// Checks or unchecks all the cells of the checkbox field in the rows that are visible in the
+    /// view. The rows hidden by the filters are untouched. Returns the number of the updated rows.
+    pub async fn set_all_checkbox_cells(&self, view_id: &str, field_id: &str, checked: bool) -> FlowyResult<usize> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            None => {
+                return Err(GridError::field_not_found(field_id).into());
+            }
+            Some(field_rev) => field_rev,
         };
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_checkbox() {
+            return Err(GridError::type_mismatch(field_id, "checkbox").into());
+        }
+        let _ = ensure_writable(&field_rev)?;
 
-        display_str().await.unwrap_or_else(|| "".to_string())
+        let cell_str = if checked { CHECK } else { UNCHECK };
+        let row_revs = self.get_all_row_revs(view_id).await?;
+        let user_id = self.user.user_id()?;
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        let mut changesets = Vec::with_capacity(row_revs.len());
+        for row_rev in row_revs.iter() {
+            let cell_rev = row_rev.cells.get(field_id).cloned();
+            let type_cell_data = apply_cell_data_changeset(
+                cell_str.to_owned(),
+                cell_rev,
+                field_rev.clone(),
+                Some(self.cell_data_cache.clone()),
+            )?;
+            let mut changeset = RowChangeset::new(row_rev.id.clone());
+            changeset
+                .cell_by_field_id
+                .insert(field_id.to_owned(), CellRevision::new(type_cell_data));
+            self.set_last_edited_by(&mut changeset, &user_id, &field_revs);
+            changesets.push(changeset);
+        }
+
+        let _ = self.block_manager.update_rows(changesets).await?;
+        for row_rev in row_revs.iter() {
+            self.view_manager.did_update_cell(&row_rev.id).await;
+            self.send_grid_event(GridChangeEvent::CellChanged {
+                row_id: row_rev.id.clone(),
+                field_id: field_id.to_owned(),
+            });
+            let _ = self
+                .recompute_dependent_cells(&row_rev.id, &[field_id.to_owned()])
+                .await?;
+        }
+        Ok(row_revs.len())
     }
 
-    pub async fn get_cell_bytes(&self, params: &CellPathParams) -> Option<CellProtobufBlob> {
-        let (_, cell_data) = self.decode_cell_data_from(params).await?;
-        Some(cell_data)
+    /// Recomputes the formula cells of the row that depend on any of the fields with `field_ids`,
+    /// directly or through other formulas. The other formula cells of the row are kept.
+    async fn recompute_dependent_cells(&self, row_id: &str, field_ids: &[String]) -> FlowyResult<()> {
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        let dependents = FieldDependencyGraph::from_field_revs(&field_revs).dependents_of_fields(field_ids);
+        for dependent in dependents {
+            let _ = self.compute_formula_cell(row_id, &dependent, &field_revs).await?;
+        }
+        Ok(())
     }
 
-    async fn decode_cell_data_from(&self, params: &CellPathParams) -> Option<(FieldType, CellProtobufBlob)> {
-        let field_rev = self.get_field_rev(&params.field_id).await?;
-        let (_, row_rev) = self.block_manager.get_row_rev(&params.row_id).await.ok()??;
-        let cell_rev = row_rev.cells.get(&params.field_id)?.clone();
-        Some(decode_type_cell_data(
-            cell_rev.type_cell_data,
-            &field_rev,
-            Some(self.cell_data_cache.clone()),
-        ))
+    /// Computes all the formula cells of the row, e.g. after the row is inserted.
+    async fn recompute_row_formulas(&self, row_id: &str) -> FlowyResult<()> {
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        let computed_fields = FieldDependencyGraph::from_field_revs(&field_revs).computed_fields();
+        // The formulas that don't reference any existing field go first
+        let mut field_ids = field_revs
+            .iter()
+            .filter(|field_rev| FieldType::from(field_rev.ty).is_formula() && !computed_fields.contains(&field_rev.id))
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        field_ids.extend(computed_fields);
+        for field_id in field_ids.iter() {
+            let _ = self.compute_formula_cell(row_id, field_id, &field_revs).await?;
+        }
+        Ok(())
     }
 
-    pub async fn get_cell_rev(&self, row_id: &str, field_id: &str) -> FlowyResult<Option<CellRevision>> {
-        match self.block_manager.get_row_rev(row_id).await? {
-            None => Ok(None),
-            Some((_, row_rev)) => {
-                let cell_rev = row_rev.cells.get(field_id).cloned();
-                Ok(cell_rev)
+    /// Recomputes the formula field, and the formulas that depend on it, for all the rows.
+    async fn recompute_formula_field(&self, field_id: &str) -> FlowyResult<()> {
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        let mut field_ids = vec![field_id.to_owned()];
+        field_ids.extend(FieldDependencyGraph::from_field_revs(&field_revs).dependents(field_id));
+        self.recompute_formula_fields(&field_ids).await
+    }
+
+    /// Recomputes the formula fields, in the given order, for all the rows.
+    async fn recompute_formula_fields(&self, field_ids: &[String]) -> FlowyResult<()> {
+        if field_ids.is_empty() {
+            return Ok(());
+        }
+        let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+        let row_ids = self
+            .get_all_block_row_revs()
+            .await?
+            .into_iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+        for row_id in row_ids {
+            for field_id in field_ids.iter() {
+                let _ = self.compute_formula_cell(&row_id, field_id, &field_revs).await?;
             }
         }
+        Ok(())
     }
 
-    #[tracing::instrument(level = "trace", skip_all, err)]
-    pub async fn update_cell_with_changeset<T: ToCellChangesetString>(
+    async fn compute_formula_cell(
         &self,
         row_id: &str,
         field_id: &str,
-        cell_changeset: T,
+        field_revs: &[Arc<FieldRevision>],
     ) -> FlowyResult<()> {
-        match self.grid_pad.read().await.get_field_rev(field_id) {
-            None => {
-                let msg = format!("Field:{} not found", &field_id);
-                Err(FlowyError::internal().context(msg))
-            }
-            Some((_, field_rev)) => {
-                tracing::trace!("Cell changeset: id:{} / value:{:?}", &field_id, cell_changeset);
-                let cell_rev = self.get_cell_rev(row_id, field_id).await?;
-                // Update the changeset.data property with the return value.
-                let type_cell_data =
-                    apply_cell_data_changeset(cell_changeset, cell_rev, field_rev, Some(self.cell_data_cache.clone()))?;
-                let cell_changeset = CellChangesetPB {
-                    grid_id: self.grid_id.clone(),
-                    row_id: row_id.to_owned(),
-                    field_id: field_id.to_owned(),
-                    type_cell_data,
-                };
-                let _ = self.block_manager.update_cell(cell_changeset).await?;
-                self.view_manager.did_update_cell(row_id).await;
-                Ok(())
-            }
+        let field_rev = match field_revs.iter().find(|field_rev| field_rev.id == field_id) {
+            Some(field_rev) if FieldType::from(field_rev.ty).is_formula() => field_rev,
+            _ => return Ok(()),
+        };
+        let row_rev = match self.block_manager.get_row_rev(row_id).await? {
+            None => return Ok(()),
+            Some((_, row_rev)) => row_rev,
+        };
+
+        let cell_str = FormulaTypeOptionPB::from(field_rev).compute_cell_str(&row_rev, field_revs);
+        let type_cell_data = TypeCellData::new(cell_str, FieldType::Formula).to_json();
+        let is_changed = row_rev
+            .cells
+            .get(field_id)
+            .map(|cell_rev| cell_rev.type_cell_data != type_cell_data)
+            .unwrap_or(true);
+        if is_changed {
+            let cell_changeset = CellChangesetPB {
+                grid_id: self.grid_id.clone(),
+                row_id: row_id.to_owned(),
+                field_id: field_id.to_owned(),
+                type_cell_data,
+            };
+            let _ = self.block_manager.update_cell(cell_changeset).await?;
+            self.view_manager.did_update_cell(row_id).await;
+            self.send_grid_event(GridChangeEvent::CellChanged {
+                row_id: row_id.to_owned(),
+                field_id: field_id.to_owned(),
+            });
         }
+        Ok(())
     }
 
     #[tracing::instrument(level = "trace", skip_all, err)]
@@ -534,6 +2350,40 @@ impl GridRevisionEditor {
             .await
     }
 
+    /// Runs the field's parser against the `input` without writing the cell. It returns the
+    /// parser's error if the `input` is invalid for the field.
+    pub async fn validate_cell_input<T: ToCellChangesetString>(&self, field_id: &str, input: T) -> FlowyResult<()> {
+        match self.grid_pad.read().await.get_field_rev(field_id) {
+            None => Err(GridError::field_not_found(field_id).into()),
+            Some((_, field_rev)) => {
+                // Don't pass the cell data cache, the parsed value should not be cached.
+                let type_cell_data = apply_cell_data_changeset(input, None, field_rev.clone(), None)?;
+
+                // The number field with the `Num` format saves the input that is not a number as
+                // an empty cell, which is still an invalid input.
+                let field_type: FieldType = field_rev.ty.into();
+                if field_type.is_number() {
+                    let cell_str = TypeCellData::from_json_str(&type_cell_data)?.cell_str;
+                    let cell_data = NumberTypeOptionPB::from(field_rev.as_ref()).format_cell_data(&cell_str)?;
+                    if !cell_str.is_empty() && cell_data.is_empty() {
+                        return Err(GridError::invalid_cell_data("Should only contain numbers").into());
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns whether the `cell_bytes` parse cleanly as a cell of the field, which lets an importer
+    /// flag the cells before writing them. The bytes are read as a UTF-8 string, the same input as
+    /// [Self::validate_cell_input].
+    pub async fn cell_is_valid(&self, field_id: &str, cell_bytes: Bytes) -> bool {
+        match String::from_utf8(cell_bytes.to_vec()) {
+            Ok(input) => self.validate_cell_input(field_id, input).await.is_ok(),
+            Err(_) => false,
+        }
+    }
+
     pub async fn get_block_meta_revs(&self) -> FlowyResult<Vec<Arc<GridBlockMetaRevision>>> {
         let block_meta_revs = self.grid_pad.read().await.get_block_meta_revs();
         Ok(block_meta_revs)
@@ -556,16 +2406,29 @@ impl GridRevisionEditor {
     }
 
     pub async fn delete_rows(&self, block_rows: Vec<GridBlockRow>) -> FlowyResult<()> {
+        let row_ids = block_rows
+            .iter()
+            .flat_map(|block_row| block_row.row_ids.clone())
+            .collect::<Vec<String>>();
         let changesets = self.block_manager.delete_rows(block_rows).await?;
         for changeset in changesets {
             let _ = self.update_block(changeset).await?;
         }
+        for row_id in row_ids {
+            self.send_grid_event(GridChangeEvent::RowDeleted { row_id });
+        }
         Ok(())
     }
 
     pub async fn get_grid(&self, view_id: &str) -> FlowyResult<GridPB> {
+        let fields = self
+            .view_manager
+            .get_view_field_revs(view_id)
+            .await?
+            .iter()
+            .map(FieldIdPB::from)
+            .collect();
         let pad = self.grid_pad.read().await;
-        let fields = pad.get_field_revs(None)?.iter().map(FieldIdPB::from).collect();
         let mut all_rows = vec![];
         for block_rev in pad.get_block_meta_revs() {
             if let Ok(rows) = self.get_row_pbs(view_id, &block_rev.block_id).await {
@@ -584,6 +2447,30 @@ impl GridRevisionEditor {
         self.view_manager.get_setting().await
     }
 
+    pub async fn get_view_setting(&self, view_id: &str) -> FlowyResult<GridSettingPB> {
+        self.view_manager.get_view_setting(view_id).await
+    }
+
+    /// Squashes the revisions of the grid and of the view into a base revision each, followed by
+    /// the [RECENT_REVISIONS_AFTER_COMPACTION] most recent revisions. It keeps the current state
+    /// but makes the grid faster to open. The blocks keep their revisions.
+    pub async fn compact_revisions(&self, view_id: &str) -> FlowyResult<()> {
+        {
+            // Holding the write lock keeps the grid from being edited while it's compacted.
+            let _grid_pad = self.grid_pad.write().await;
+            let _ = self
+                .rev_manager
+                .compact_revisions(RECENT_REVISIONS_AFTER_COMPACTION)
+                .await?;
+        }
+        self.view_manager.compact_revisions(view_id).await
+    }
+
+    /// Duplicates the view with its settings. Returns the id of the new view.
+    pub async fn duplicate_view(&self, view_id: &str) -> FlowyResult<String> {
+        self.view_manager.duplicate_view(view_id).await
+    }
+
     pub async fn get_all_filters(&self) -> FlowyResult<Vec<FilterPB>> {
         Ok(self
             .view_manager
@@ -607,22 +2494,36 @@ impl GridRevisionEditor {
     }
 
     pub async fn create_or_update_filter(&self, params: AlterFilterParams) -> FlowyResult<()> {
+        let view_id = params.view_id.clone();
         let _ = self.view_manager.create_or_update_filter(params).await?;
+        self.send_grid_event(GridChangeEvent::FilterChanged { view_id });
         Ok(())
     }
 
     pub async fn delete_filter(&self, params: DeleteFilterParams) -> FlowyResult<()> {
+        let view_id = params.view_id.clone();
         let _ = self.view_manager.delete_filter(params).await?;
+        self.send_grid_event(GridChangeEvent::FilterChanged { view_id });
         Ok(())
     }
 
     pub async fn delete_sort(&self, params: DeleteSortParams) -> FlowyResult<()> {
+        let view_id = params.view_id.clone();
         let _ = self.view_manager.delete_sort(params).await?;
+        self.send_grid_event(GridChangeEvent::SortChanged { view_id });
+        Ok(())
+    }
+
+    /// Removes all the filters, sorts and the grouping of the view
+    pub async fn reset_view_settings(&self, view_id: &str) -> FlowyResult<()> {
+        let _ = self.view_manager.reset_view_settings(view_id).await?;
         Ok(())
     }
 
     pub async fn create_or_update_sort(&self, params: AlterSortParams) -> FlowyResult<SortRevision> {
+        let view_id = params.view_id.clone();
         let sort_rev = self.view_manager.create_or_update_sort(params).await?;
+        self.send_grid_event(GridChangeEvent::SortChanged { view_id });
         Ok(sort_rev)
     }
 
@@ -646,6 +2547,9 @@ impl GridRevisionEditor {
                             .block_manager
                             .move_row(row_rev.clone(), from_index, to_index)
                             .await?;
+                        self.send_grid_event(GridChangeEvent::RowMoved {
+                            row_id: from_row_id.clone(),
+                        });
                     }
                     (_, None) => tracing::warn!("Can not find the from row id: {}", from_row_id),
                     (None, _) => tracing::warn!("Can not find the to row id: {}", to_row_id),
@@ -663,30 +2567,44 @@ impl GridRevisionEditor {
             to_row_id,
         } = params;
 
+        // Moving the row to another group writes the cell of the grouped field
+        let groups = self.view_manager.load_groups().await?.items;
+        if let Some(group) = groups.iter().find(|group| group.group_id == to_group_id) {
+            if !group.rows.iter().any(|row| row.id == from_row_id) {
+                if let Some(field_rev) = self.get_field_rev(&group.field_id).await {
+                    let _ = ensure_writable(&field_rev)?;
+                }
+            }
+        }
+
         match self.block_manager.get_row_rev(&from_row_id).await? {
             None => tracing::warn!("Move row failed, can not find the row:{}", from_row_id),
             Some((_, row_rev)) => {
                 let block_manager = self.block_manager.clone();
+                // Moving the row to another group changes its cells, which is an edit of the user
+                let user_id = self.user.user_id()?;
+                let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
+                let mut changeset = RowChangeset::new(from_row_id.clone());
+                self.set_last_edited_by(&mut changeset, &user_id, &field_revs);
+                let event_notifier = self.event_notifier.clone();
                 self.view_manager
                     .move_group_row(row_rev, to_group_id, to_row_id.clone(), |row_changeset| {
                         to_fut(async move {
-                            tracing::trace!("Row data changed: {:?}", row_changeset);
-                            let cell_changesets = row_changeset
+                            tracing::trace!("Row data changed in view {}: {:?}", view_id, row_changeset);
+                            let events = row_changeset
                                 .cell_by_field_id
-                                .into_iter()
-                                .map(|(field_id, cell_rev)| CellChangesetPB {
-                                    grid_id: view_id.clone(),
+                                .keys()
+                                .map(|field_id| GridChangeEvent::CellChanged {
                                     row_id: row_changeset.row_id.clone(),
-                                    field_id,
-                                    type_cell_data: cell_rev.type_cell_data,
+                                    field_id: field_id.clone(),
                                 })
-                                .collect::<Vec<CellChangesetPB>>();
-
-                            for cell_changeset in cell_changesets {
-                                match block_manager.update_cell(cell_changeset).await {
-                                    Ok(_) => {}
-                                    Err(e) => tracing::error!("Apply cell changeset error:{:?}", e),
-                                }
+                                .collect::<Vec<GridChangeEvent>>();
+                            changeset.cell_by_field_id.extend(row_changeset.cell_by_field_id);
+                            match block_manager.update_rows(vec![changeset]).await {
+                                Ok(_) => events.into_iter().for_each(|event| {
+                                    let _ = event_notifier.send(event);
+                                }),
+                                Err(e) => tracing::error!("Apply row changeset error:{:?}", e),
                             }
                         })
                     })
@@ -696,6 +2614,27 @@ impl GridRevisionEditor {
         Ok(())
     }
 
+    /// Moves the row to the end of the group with `to_group_id`. The cell of the grouped field is
+    /// updated to reflect the new group, e.g. it's set to the option id of the single-select group.
+    pub async fn move_row_to_group(&self, row_id: &str, to_group_id: &str) -> FlowyResult<()> {
+        let groups = self.view_manager.load_groups().await?.items;
+        if !groups.iter().any(|group| group.group_id == to_group_id) {
+            return Err(GridError::group_not_found(to_group_id).into());
+        }
+
+        if self.block_manager.get_row_rev(row_id).await?.is_none() {
+            return Err(GridError::row_not_found(row_id).into());
+        }
+
+        let params = MoveGroupRowParams {
+            view_id: self.grid_id.clone(),
+            from_row_id: row_id.to_owned(),
+            to_group_id: to_group_id.to_owned(),
+            to_row_id: None,
+        };
+        self.move_group_row(params).await
+    }
+
     pub async fn move_field(&self, params: MoveFieldParams) -> FlowyResult<()> {
         let MoveFieldParams {
             grid_id: _,
@@ -708,7 +2647,7 @@ impl GridRevisionEditor {
             .modify(|grid_pad| Ok(grid_pad.move_field(&field_id, from_index as usize, to_index as usize)?))
             .await?;
         if let Some((index, field_rev)) = self.grid_pad.read().await.get_field_rev(&field_id) {
-            let delete_field_order = FieldIdPB::from(field_id);
+            let delete_field_order = FieldIdPB::from(field_id.clone());
             let insert_field = IndexFieldPB::from_field_rev(field_rev, index);
             let notified_changeset = GridFieldChangesetPB {
                 grid_id: self.grid_id.clone(),
@@ -718,6 +2657,39 @@ impl GridRevisionEditor {
             };
 
             let _ = self.notify_did_update_grid(notified_changeset).await?;
+            self.send_grid_event(GridChangeEvent::FieldMoved { field_id });
+        }
+        Ok(())
+    }
+
+    /// Exchanges the positions of the two fields within a single revision.
+    pub async fn swap_fields(&self, field_id_a: &str, field_id_b: &str) -> FlowyResult<()> {
+        let _ = self
+            .modify(|grid_pad| Ok(grid_pad.swap_fields(field_id_a, field_id_b)?))
+            .await?;
+
+        let grid_pad = self.grid_pad.read().await;
+        let mut notified_changeset = GridFieldChangesetPB {
+            grid_id: self.grid_id.clone(),
+            inserted_fields: vec![],
+            deleted_fields: vec![],
+            updated_fields: vec![],
+        };
+        for field_id in [field_id_a, field_id_b] {
+            if let Some((index, field_rev)) = grid_pad.get_field_rev(field_id) {
+                notified_changeset.deleted_fields.push(FieldIdPB::from(field_id));
+                notified_changeset
+                    .inserted_fields
+                    .push(IndexFieldPB::from_field_rev(field_rev, index));
+            }
+        }
+        drop(grid_pad);
+
+        let _ = self.notify_did_update_grid(notified_changeset).await?;
+        for field_id in [field_id_a, field_id_b] {
+            self.send_grid_event(GridChangeEvent::FieldMoved {
+                field_id: field_id.to_owned(),
+            });
         }
         Ok(())
     }
@@ -759,12 +2731,73 @@ impl GridRevisionEditor {
         self.view_manager.load_groups().await
     }
 
+    /// Returns the summary of each group of the board view: the number of its rows and, if the
+    /// `sum_field_id` is given, the sum of that number field over its rows.
+    pub async fn group_summaries(&self, view_id: &str, sum_field_id: Option<&str>) -> FlowyResult<Vec<GroupSummaryPB>> {
+        let sum_field_rev = match sum_field_id {
+            None => None,
+            Some(field_id) => {
+                let field_rev = match self.get_field_rev(field_id).await {
+                    None => {
+                        return Err(GridError::field_not_found(field_id).into());
+                    }
+                    Some(field_rev) => field_rev,
+                };
+                let field_type: FieldType = field_rev.ty.into();
+                if !field_type.is_number() {
+                    return Err(GridError::type_mismatch(field_id, "number").into());
+                }
+                Some(field_rev)
+            }
+        };
+
+        let cell_str_by_row_id = match &sum_field_rev {
+            None => HashMap::new(),
+            Some(field_rev) => self
+                .cells_for_field(&field_rev.id)
+                .await?
+                .into_iter()
+                .filter(|(_, type_cell_data)| type_cell_data.is_number())
+                .map(|(row_id, type_cell_data)| (row_id, type_cell_data.cell_str))
+                .collect::<HashMap<String, String>>(),
+        };
+
+        let mut summaries = vec![];
+        for group in self.view_manager.load_groups_of_view(view_id).await?.items {
+            let sum = match &sum_field_rev {
+                None => None,
+                Some(field_rev) => {
+                    let cell_strs = group
+                        .rows
+                        .iter()
+                        .flat_map(|row| cell_str_by_row_id.get(&row.id))
+                        .collect::<Vec<&String>>();
+                    let type_option = field_rev
+                        .get_type_option::<NumberTypeOptionPB>(field_rev.ty)
+                        .unwrap_or_default();
+                    let sum = type_option.calculate_cell_strs(&cell_strs, &CalculationTypePB::Sum, false)?;
+                    Some(sum.to_string())
+                }
+            };
+            summaries.push(GroupSummaryPB {
+                group_id: group.group_id,
+                count: group.rows.len() as i32,
+                sum,
+            });
+        }
+        Ok(summaries)
+    }
+
     async fn create_row_rev(&self) -> FlowyResult<RowRevision> {
         let field_revs = self.grid_pad.read().await.get_field_revs(None)?;
         let block_id = self.block_id().await?;
 
         // insert empty row below the row whose id is upper_row_id
-        let row_rev = RowRevisionBuilder::new(&block_id, &field_revs).build();
+        let mut row_rev = RowRevisionBuilder::new(&block_id, &field_revs).build();
+        let user_id = self.user.user_id()?;
+        row_rev.created_by = Some(user_id.clone());
+        row_rev.last_edited_by = Some(user_id);
+        self.insert_user_cells(&mut row_rev, &field_revs);
         Ok(row_rev)
     }
 
@@ -778,6 +2811,10 @@ impl GridRevisionEditor {
         // update block row count
         let changeset = GridBlockMetaRevisionChangeset::from_row_count(block_id, row_count);
         let _ = self.update_block(changeset).await?;
+        let _ = self.recompute_row_formulas(&row_pb.id).await?;
+        self.send_grid_event(GridChangeEvent::RowInserted {
+            row_id: row_pb.id.clone(),
+        });
         Ok(row_pb)
     }
 
@@ -786,6 +2823,8 @@ impl GridRevisionEditor {
         F: for<'a> FnOnce(&'a mut GridRevisionPad) -> FlowyResult<Option<GridRevisionChangeset>>,
     {
         let mut write_guard = self.grid_pad.write().await;
+        // The change is saved in the next revision, which is recorded on the modified fields
+        write_guard.set_next_rev_id(self.rev_manager.rev_id() + 1);
         if let Some(changeset) = f(&mut *write_guard)? {
             let _ = self.apply_change(changeset).await?;
         }
@@ -812,6 +2851,9 @@ impl GridRevisionEditor {
             let index_field = IndexFieldPB::from_field_rev(field_rev, index);
             let notified_changeset = GridFieldChangesetPB::insert(&self.grid_id, vec![index_field]);
             let _ = self.notify_did_update_grid(notified_changeset).await?;
+            self.send_grid_event(GridChangeEvent::FieldCreated {
+                field_id: field_id.to_owned(),
+            });
         }
         Ok(())
     }
@@ -832,6 +2874,9 @@ impl GridRevisionEditor {
             send_dart_notification(field_id, GridDartNotification::DidUpdateField)
                 .payload(updated_field)
                 .send();
+            self.send_grid_event(GridChangeEvent::FieldUpdated {
+                field_id: field_id.to_owned(),
+            });
         }
 
         Ok(())
@@ -901,3 +2946,34 @@ impl JsonDeserializer for TypeOptionJsonDeserializer {
         Ok(json)
     }
 }
+
+/// Returns the option ids of the select cell, or no ids if the cell is empty or was not written
+/// by a select field.
+fn select_option_ids_from_type_cell_data(type_cell_data: &str) -> SelectOptionIds {
+    match TypeCellData::from_json_str(type_cell_data) {
+        Ok(type_cell_data) if type_cell_data.is_select_option() => {
+            SelectOptionIds::from_cell_str(&type_cell_data.cell_str).unwrap_or_default()
+        }
+        _ => SelectOptionIds::default(),
+    }
+}
+
+/// Returns an error if the cells of the field can't be written: the field is read-only or its
+/// cells are computed, e.g. the formula and the user fields.
+fn ensure_writable(field_rev: &FieldRevision) -> FlowyResult<()> {
+    let field_type: FieldType = field_rev.ty.into();
+    if field_rev.read_only || field_type.is_computed() {
+        return Err(GridError::read_only_field(&field_rev.id).into());
+    }
+    Ok(())
+}
+
+/// Returns the user that the cell of the user field shows, or None if the field is not a user
+/// field. The rows that were never edited show their creator as the last editor.
+fn row_author<'a>(row_rev: &'a RowRevision, field_type: &FieldType) -> Option<&'a str> {
+    match field_type {
+        FieldType::CreatedBy => row_rev.created_by.as_deref(),
+        FieldType::LastEditedBy => row_rev.last_edited_by.as_deref().or(row_rev.created_by.as_deref()),
+        _ => None,
+    }
+}
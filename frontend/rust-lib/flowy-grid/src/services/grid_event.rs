@@ -0,0 +1,52 @@
+use crate::entities::FieldType;
+use tokio::sync::broadcast;
+
+/// The mutations of a grid. An event is sent after its mutation is saved, so the subscribers
+/// receive the events in the order in which the mutations were made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridChangeEvent {
+    FieldCreated {
+        field_id: String,
+    },
+    FieldUpdated {
+        field_id: String,
+    },
+    FieldDeleted {
+        field_id: String,
+    },
+    /// The field is moved in the grid's field order.
+    FieldMoved {
+        field_id: String,
+    },
+    /// Sent after the [GridChangeEvent::FieldUpdated] of the switched field.
+    FieldTypeChanged {
+        field_id: String,
+        field_type: FieldType,
+    },
+    RowInserted {
+        row_id: String,
+    },
+    RowUpdated {
+        row_id: String,
+    },
+    RowDeleted {
+        row_id: String,
+    },
+    /// The row is moved in the grid's row order. Moving the row to another group sends the
+    /// [GridChangeEvent::CellChanged] of the grouped field instead.
+    RowMoved {
+        row_id: String,
+    },
+    CellChanged {
+        row_id: String,
+        field_id: String,
+    },
+    FilterChanged {
+        view_id: String,
+    },
+    SortChanged {
+        view_id: String,
+    },
+}
+
+pub type GridChangeEventNotifier = broadcast::Sender<GridChangeEvent>;
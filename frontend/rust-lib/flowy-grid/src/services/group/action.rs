@@ -3,7 +3,7 @@ use crate::services::cell::DecodedCellData;
 use crate::services::group::controller::MoveGroupRowContext;
 use crate::services::group::Group;
 use flowy_error::FlowyResult;
-use grid_rev_model::{CellRevision, FieldRevision, RowRevision};
+use grid_rev_model::{CellRevision, FieldRevision, GroupConfigurationRevision, RowRevision};
 use std::sync::Arc;
 
 /// Using polymorphism to provides the customs action for different group controller.
@@ -52,6 +52,10 @@ pub trait GroupControllerSharedActions: Send + Sync {
     /// Returns number of groups the current field has
     fn groups(&self) -> Vec<&Group>;
 
+    /// Returns the group configuration that the controller keeps in memory. Returns None if the
+    /// rows are not grouped by a field.
+    fn configuration(&self) -> Option<GroupConfigurationRevision>;
+
     /// Returns the index and the group data with group_id
     fn get_group(&self, group_id: &str) -> Option<(usize, Group)>;
 
@@ -78,6 +82,18 @@ pub trait GroupControllerSharedActions: Send + Sync {
     /// Move the row from one group to another group
     fn move_group_row(&mut self, context: MoveGroupRowContext) -> FlowyResult<Vec<GroupRowsNotificationPB>>;
 
-    /// Update the group if the corresponding field is changed
-    fn did_update_group_field(&mut self, field_rev: &FieldRevision) -> FlowyResult<Option<GroupViewChangesetPB>>;
+    /// Regenerates the groups from the updated type-option data of the grouping field, e.g. after an
+    /// option is renamed, and separates the rows into the groups again.
+    fn did_update_group_field(
+        &mut self,
+        field_rev: &Arc<FieldRevision>,
+        row_revs: &[Arc<RowRevision>],
+    ) -> FlowyResult<Option<GroupViewChangesetPB>>;
+
+    /// Hide or show the `No status` group. Hiding the group doesn't remove its rows.
+    fn set_no_status_group_hidden(&mut self, hidden: bool) -> FlowyResult<Option<GroupViewChangesetPB>>;
+
+    /// Returns the groups that were inserted while updating the rows, for example, a new date
+    /// group. Returns None if no group was inserted since the last call.
+    fn take_inserted_groups(&mut self) -> Option<GroupViewChangesetPB>;
 }
@@ -1,6 +1,7 @@
-use crate::entities::{GroupPB, GroupViewChangesetPB};
+use crate::entities::{GroupPB, GroupViewChangesetPB, InsertedGroupPB};
+use crate::services::errors::GridError;
 use crate::services::group::{default_group_configuration, GeneratedGroupContext, Group};
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::FlowyResult;
 use grid_rev_model::{
     FieldRevision, FieldTypeRevision, GroupConfigurationContentSerde, GroupConfigurationRevision, GroupRevision,
 };
@@ -57,6 +58,9 @@ pub struct GroupContext<C> {
     /// configuration to disk  
     ///
     writer: Arc<dyn GroupConfigurationWriter>,
+
+    /// The groups that were inserted after initializing the groups, waiting to be notified.
+    inserted_groups: Vec<InsertedGroupPB>,
 }
 
 impl<C> GroupContext<C>
@@ -88,6 +92,7 @@ where
             writer,
             configuration,
             configuration_phantom: PhantomData,
+            inserted_groups: vec![],
         })
     }
 
@@ -102,6 +107,10 @@ where
         self.groups_map.get_mut(&self.field_rev.id)
     }
 
+    pub(crate) fn configuration(&self) -> &GroupConfigurationRevision {
+        &self.configuration
+    }
+
     pub(crate) fn groups(&self) -> Vec<&Group> {
         self.groups_map.values().collect()
     }
@@ -162,7 +171,8 @@ where
                 })?;
                 Ok(())
             }
-            _ => Err(FlowyError::record_not_found().context("Moving group failed. Groups are not exist")),
+            (None, _) => Err(GridError::group_not_found(from_id).into()),
+            (_, None) => Err(GridError::group_not_found(to_id).into()),
         }
     }
 
@@ -192,8 +202,12 @@ where
 
         let mut new_groups = vec![];
         let mut filter_content_map = HashMap::new();
+        let mut color_map = HashMap::new();
         group_configs.into_iter().for_each(|generate_group| {
             filter_content_map.insert(generate_group.group_rev.id.clone(), generate_group.filter_content);
+            if let Some(color) = generate_group.color {
+                color_map.insert(generate_group.group_rev.id.clone(), color);
+            }
             new_groups.push(generate_group.group_rev);
         });
 
@@ -212,7 +226,7 @@ where
         let MergeGroupResult {
             mut all_group_revs,
             new_group_revs,
-            updated_group_revs: _,
+            updated_group_revs,
             deleted_group_revs,
         } = merge_groups(old_groups, new_groups);
 
@@ -221,6 +235,24 @@ where
             .map(|group_rev| group_rev.id)
             .collect::<Vec<String>>();
 
+        // The groups that existed before, but whose name or color is changed, e.g. the option of
+        // the group is renamed.
+        let update_group_ids = all_group_revs
+            .iter()
+            .filter(|group_rev| {
+                let is_renamed = updated_group_revs
+                    .iter()
+                    .any(|updated_group_rev| updated_group_rev.id == group_rev.id);
+                let is_recolored = self
+                    .groups_map
+                    .get(&group_rev.id)
+                    .map(|group| group.color != color_map.get(&group_rev.id).cloned())
+                    .unwrap_or(false);
+                is_renamed || is_recolored
+            })
+            .map(|group_rev| group_rev.id.clone())
+            .collect::<Vec<String>>();
+
         self.mut_configuration(|configuration| {
             let mut is_changed = !deleted_group_ids.is_empty();
 
@@ -259,12 +291,15 @@ where
         })?;
 
         // Update the memory cache of the groups
+        self.groups_map
+            .retain(|group_id, _| !deleted_group_ids.contains(group_id));
         all_group_revs.into_iter().for_each(|group_rev| {
             let filter_content = filter_content_map
                 .get(&group_rev.id)
                 .cloned()
                 .unwrap_or_else(|| "".to_owned());
-            let group = Group::new(group_rev.id, self.field_rev.id.clone(), group_rev.name, filter_content);
+            let mut group = Group::new(group_rev.id, self.field_rev.id.clone(), group_rev.name, filter_content);
+            group.color = color_map.get(&group.id).cloned();
             self.groups_map.insert(group.id.clone(), group);
         });
 
@@ -272,21 +307,27 @@ where
             .into_iter()
             .flat_map(|group_rev| {
                 let filter_content = filter_content_map.get(&group_rev.id)?;
-                let group = Group::new(
+                let mut group = Group::new(
                     group_rev.id,
                     self.field_rev.id.clone(),
                     group_rev.name,
                     filter_content.clone(),
                 );
+                group.color = color_map.get(&group.id).cloned();
                 Some(GroupPB::from(group))
             })
             .collect();
+        let update_groups = update_group_ids
+            .iter()
+            .flat_map(|group_id| self.groups_map.get(group_id))
+            .map(|group| GroupPB::from(group.clone()))
+            .collect();
 
         let changeset = GroupViewChangesetPB {
             view_id: self.view_id.clone(),
             new_groups,
             deleted_groups: deleted_group_ids,
-            update_groups: vec![],
+            update_groups,
             inserted_groups: vec![],
         };
         tracing::trace!("Group changeset: {:?}", changeset);
@@ -313,6 +354,83 @@ where
         Ok(())
     }
 
+    /// Returns the content of the group configuration. For example, the date groups read the period
+    /// that the rows are grouped by from it.
+    pub(crate) fn get_configuration_content(&self) -> Option<C> {
+        C::from_json(&self.configuration.content).ok()
+    }
+
+    /// Inserts a group that was not generated while initializing the groups at the index and saves
+    /// it to the configuration. The inserted group is kept until [Self::take_inserted_groups] is
+    /// called.
+    pub(crate) fn insert_new_group(
+        &mut self,
+        index: usize,
+        group_rev: GroupRevision,
+        filter_content: String,
+    ) -> FlowyResult<()> {
+        let group = Group::new(
+            group_rev.id.clone(),
+            self.field_rev.id.clone(),
+            group_rev.name.clone(),
+            filter_content,
+        );
+        let index = index.min(self.groups_map.len());
+        // The group that the new group is inserted before
+        let next_group_id = self.groups_map.get_index(index).map(|(id, _)| id.clone());
+        self.groups_map.insert(group.id.clone(), group.clone());
+        self.groups_map.move_index(self.groups_map.len() - 1, index);
+        self.inserted_groups.push(InsertedGroupPB {
+            group: GroupPB::from(group),
+            index: index as i32,
+        });
+
+        self.mut_configuration(|configuration| {
+            if configuration.groups.iter().any(|group| group.id == group_rev.id) {
+                return false;
+            }
+            let pos = next_group_id
+                .and_then(|next_group_id| configuration.groups.iter().position(|group| group.id == next_group_id));
+            match pos {
+                None => configuration.groups.push(group_rev),
+                Some(pos) => configuration.groups.insert(pos, group_rev),
+            }
+            true
+        })
+    }
+
+    /// Returns the groups inserted by [Self::insert_new_group] since the last call.
+    pub(crate) fn take_inserted_groups(&mut self) -> Vec<InsertedGroupPB> {
+        std::mem::take(&mut self.inserted_groups)
+    }
+
+    /// Returns true if the `No status` group is omitted from the output. Its rows are still
+    /// kept in the memory cache.
+    pub(crate) fn is_no_status_group_hidden(&self) -> bool {
+        C::from_json(&self.configuration.content)
+            .map(|content| content.hide_empty())
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn set_no_status_group_hidden(&mut self, hidden: bool) -> FlowyResult<()> {
+        self.mut_configuration(|configuration| match C::from_json(&configuration.content) {
+            Ok(mut content) if content.hide_empty() != hidden => {
+                content.set_hide_empty(hidden);
+                match content.to_json() {
+                    Ok(json) => {
+                        configuration.content = json;
+                        true
+                    }
+                    Err(e) => {
+                        tracing::error!("Serialize group configuration content failed: {}", e);
+                        false
+                    }
+                }
+            }
+            _ => false,
+        })
+    }
+
     fn mut_configuration(
         &mut self,
         mut_configuration_fn: impl FnOnce(&mut GroupConfigurationRevision) -> bool,
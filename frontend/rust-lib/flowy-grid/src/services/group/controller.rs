@@ -1,11 +1,13 @@
-use crate::entities::{GroupRowsNotificationPB, GroupViewChangesetPB, InsertedRowPB, RowPB};
+use crate::entities::{GroupPB, GroupRowsNotificationPB, GroupViewChangesetPB, InsertedGroupPB, InsertedRowPB, RowPB};
 use crate::services::cell::{decode_type_cell_data, CellProtobufBlobParser, DecodedCellData};
+use crate::services::field::SelectOptionColorPB;
 use crate::services::group::action::{GroupControllerCustomActions, GroupControllerSharedActions};
 use crate::services::group::configuration::GroupContext;
 use crate::services::group::entities::Group;
 use flowy_error::FlowyResult;
 use grid_rev_model::{
-    FieldRevision, GroupConfigurationContentSerde, GroupRevision, RowChangeset, RowRevision, TypeOptionDataDeserializer,
+    FieldRevision, GroupConfigurationContentSerde, GroupConfigurationRevision, GroupRevision, RowChangeset,
+    RowRevision, TypeOptionDataDeserializer,
 };
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -28,10 +30,14 @@ pub trait GroupGenerator {
     type Context;
     type TypeOptionType;
 
+    /// The `row_revs` are the rows that are going to be grouped. Most of the groups are generated
+    /// from the type option, e.g. the options of the select field, but the date groups are
+    /// generated from the dates of the rows.
     fn generate_groups(
         field_rev: &FieldRevision,
         group_ctx: &Self::Context,
         type_option: &Option<Self::TypeOptionType>,
+        row_revs: &[Arc<RowRevision>],
     ) -> GeneratedGroupContext;
 }
 
@@ -43,6 +49,7 @@ pub struct GeneratedGroupContext {
 pub struct GeneratedGroupConfig {
     pub group_rev: GroupRevision,
     pub filter_content: String,
+    pub color: Option<SelectOptionColorPB>,
 }
 
 pub struct MoveGroupRowContext<'a> {
@@ -70,9 +77,13 @@ where
     T: TypeOptionDataDeserializer,
     G: GroupGenerator<Context = GroupContext<C>, TypeOptionType = T>,
 {
-    pub async fn new(field_rev: &Arc<FieldRevision>, mut configuration: GroupContext<C>) -> FlowyResult<Self> {
+    pub async fn new(
+        field_rev: &Arc<FieldRevision>,
+        mut configuration: GroupContext<C>,
+        row_revs: &[Arc<RowRevision>],
+    ) -> FlowyResult<Self> {
         let type_option = field_rev.get_type_option::<T>(field_rev.ty);
-        let generated_group_context = G::generate_groups(field_rev, &configuration, &type_option);
+        let generated_group_context = G::generate_groups(field_rev, &configuration, &type_option, row_revs);
         let _ = configuration.init_groups(generated_group_context)?;
 
         Ok(Self {
@@ -166,7 +177,19 @@ where
     }
 
     fn groups(&self) -> Vec<&Group> {
-        self.group_ctx.groups()
+        if self.group_ctx.is_no_status_group_hidden() {
+            self.group_ctx
+                .groups()
+                .into_iter()
+                .filter(|group| !group.is_default)
+                .collect()
+        } else {
+            self.group_ctx.groups()
+        }
+    }
+
+    fn configuration(&self) -> Option<GroupConfigurationRevision> {
+        Some(self.group_ctx.configuration().clone())
     }
 
     fn get_group(&self, group_id: &str) -> Option<(usize, Group)> {
@@ -289,8 +312,68 @@ where
         }
     }
 
-    fn did_update_group_field(&mut self, _field_rev: &FieldRevision) -> FlowyResult<Option<GroupViewChangesetPB>> {
-        Ok(None)
+    fn did_update_group_field(
+        &mut self,
+        field_rev: &Arc<FieldRevision>,
+        row_revs: &[Arc<RowRevision>],
+    ) -> FlowyResult<Option<GroupViewChangesetPB>> {
+        self.type_option = field_rev.get_type_option::<T>(field_rev.ty);
+        let generated_group_context = G::generate_groups(field_rev, &self.group_ctx, &self.type_option, row_revs);
+        let mut changeset = self.group_ctx.init_groups(generated_group_context)?;
+        // The groups are regenerated without their rows
+        let _ = self.fill_groups(row_revs, field_rev)?;
+        if let Some(changeset) = changeset.as_mut() {
+            for group_pb in changeset
+                .new_groups
+                .iter_mut()
+                .chain(changeset.update_groups.iter_mut())
+            {
+                if let Some((_, group)) = self.group_ctx.get_group(&group_pb.group_id) {
+                    *group_pb = GroupPB::from(group.clone());
+                }
+            }
+        }
+        Ok(changeset)
+    }
+
+    fn set_no_status_group_hidden(&mut self, hidden: bool) -> FlowyResult<Option<GroupViewChangesetPB>> {
+        if self.group_ctx.is_no_status_group_hidden() == hidden {
+            return Ok(None);
+        }
+        let _ = self.group_ctx.set_no_status_group_hidden(hidden)?;
+        let (index, no_status_group) = match self.group_ctx.get_no_status_group() {
+            None => return Ok(None),
+            Some(no_status_group) => match self.group_ctx.get_group(&no_status_group.id) {
+                None => return Ok(None),
+                Some((index, group)) => (index, group.clone()),
+            },
+        };
+
+        let mut changeset = GroupViewChangesetPB {
+            view_id: self.group_ctx.view_id.clone(),
+            ..Default::default()
+        };
+        if hidden {
+            changeset.deleted_groups.push(no_status_group.id);
+        } else {
+            changeset.inserted_groups.push(InsertedGroupPB {
+                group: GroupPB::from(no_status_group),
+                index: index as i32,
+            });
+        }
+        Ok(Some(changeset))
+    }
+
+    fn take_inserted_groups(&mut self) -> Option<GroupViewChangesetPB> {
+        let inserted_groups = self.group_ctx.take_inserted_groups();
+        if inserted_groups.is_empty() {
+            return None;
+        }
+        Some(GroupViewChangesetPB {
+            view_id: self.group_ctx.view_id.clone(),
+            inserted_groups,
+            ..Default::default()
+        })
     }
 }
 
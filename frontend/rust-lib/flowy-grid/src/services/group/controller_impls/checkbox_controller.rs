@@ -9,6 +9,7 @@ use crate::services::group::controller::{
 use crate::services::cell::insert_checkbox_cell;
 use crate::services::group::{move_group_row, GeneratedGroupConfig, GeneratedGroupContext};
 use grid_rev_model::{CellRevision, CheckboxGroupConfigurationRevision, FieldRevision, GroupRevision, RowRevision};
+use std::sync::Arc;
 
 pub type CheckboxGroupController = GenericGroupController<
     CheckboxGroupConfigurationRevision,
@@ -138,15 +139,18 @@ impl GroupGenerator for CheckboxGroupGenerator {
         _field_rev: &FieldRevision,
         _group_ctx: &Self::Context,
         _type_option: &Option<Self::TypeOptionType>,
+        _row_revs: &[Arc<RowRevision>],
     ) -> GeneratedGroupContext {
         let check_group = GeneratedGroupConfig {
             group_rev: GroupRevision::new(CHECK.to_string(), "".to_string()),
             filter_content: CHECK.to_string(),
+            color: None,
         };
 
         let uncheck_group = GeneratedGroupConfig {
             group_rev: GroupRevision::new(UNCHECK.to_string(), "".to_string()),
             filter_content: UNCHECK.to_string(),
+            color: None,
         };
 
         GeneratedGroupContext {
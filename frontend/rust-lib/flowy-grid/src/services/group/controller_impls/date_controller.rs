@@ -0,0 +1,513 @@
+use crate::entities::{GroupRowsNotificationPB, InsertedRowPB, RowPB};
+use crate::services::cell::{insert_date_cell, DecodedCellData, FromCellString, TypeCellData};
+use crate::services::field::{DateCellData, DateCellDataPB, DateCellDataParser, DateTypeOptionPB};
+use crate::services::group::action::GroupControllerCustomActions;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+    GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{move_group_row, GeneratedGroupConfig, GeneratedGroupContext};
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc, Weekday};
+use grid_rev_model::{
+    CellRevision, DateCondition, DateGroupConfigurationRevision, FieldRevision, GroupRevision, RowRevision,
+};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+pub type DateGroupController =
+    GenericGroupController<DateGroupConfigurationRevision, DateTypeOptionPB, DateGroupGenerator, DateCellDataParser>;
+
+pub type DateGroupContext = GroupContext<DateGroupConfigurationRevision>;
+
+impl DateGroupController {
+    fn date_condition(&self) -> DateCondition {
+        self.group_ctx
+            .get_configuration_content()
+            .map(|content| content.condition)
+            .unwrap_or_default()
+    }
+
+    /// Returns the index that keeps the groups ordered chronologically after inserting the group
+    /// with the key. The `No Date` group stays at the front.
+    fn index_of_new_group(&self, key: &str, now: i64) -> usize {
+        let condition = self.date_condition();
+        let timestamp = group_timestamp(key, &condition, now);
+        let groups = self.group_ctx.groups();
+        groups
+            .iter()
+            .position(|group| !group.is_default && group_timestamp(&group.id, &condition, now) > timestamp)
+            .unwrap_or_else(|| groups.len())
+    }
+}
+
+impl GroupControllerCustomActions for DateGroupController {
+    type CellDataType = DateCellDataPB;
+    fn default_cell_rev(&self) -> Option<CellRevision> {
+        None
+    }
+
+    fn can_group(&self, content: &str, cell_data: &Self::CellDataType) -> bool {
+        if cell_data.is_empty() {
+            return false;
+        }
+        match group_key_from_timestamp(cell_data.timestamp, &self.date_condition(), Utc::now().timestamp()) {
+            None => false,
+            Some(key) => content == key,
+        }
+    }
+
+    fn add_or_remove_row_in_groups_if_match(
+        &mut self,
+        row_rev: &RowRevision,
+        cell_data: &Self::CellDataType,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let now = Utc::now().timestamp();
+        let key = if cell_data.is_empty() {
+            None
+        } else {
+            group_key_from_timestamp(cell_data.timestamp, &self.date_condition(), now)
+        };
+
+        // The groups are generated from the dates of the rows, so a date that falls into a new
+        // period creates a new group.
+        if let Some(key) = &key {
+            if self.group_ctx.get_group(key).is_none() {
+                let index = self.index_of_new_group(key, now);
+                let group_rev = GroupRevision::new(key.clone(), group_name_from_key(key));
+                if let Err(e) = self.group_ctx.insert_new_group(index, group_rev, key.clone()) {
+                    tracing::error!("Add date group failed: {}", e);
+                }
+            }
+        }
+
+        let mut changesets = vec![];
+        self.group_ctx.iter_mut_status_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if key.as_ref() == Some(&group.id) {
+                if !group.contains_row(&row_rev.id) {
+                    let row_pb = RowPB::from(row_rev);
+                    changeset.inserted_rows.push(InsertedRowPB::new(row_pb.clone()));
+                    group.add_row(row_pb);
+                }
+            } else if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn delete_row(&mut self, row_rev: &RowRevision, _cell_data: &Self::CellDataType) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn move_row(
+        &mut self,
+        _cell_data: &Self::CellDataType,
+        mut context: MoveGroupRowContext,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut group_changeset = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            if let Some(changeset) = move_group_row(group, &mut context) {
+                group_changeset.push(changeset);
+            }
+        });
+
+        // The date of the moved row depends on the condition, e.g. the `today` group of the
+        // [DateCondition::Relative].
+        if context
+            .row_changeset
+            .cell_by_field_id
+            .contains_key(&context.field_rev.id)
+        {
+            match group_timestamp(context.to_group_id, &self.date_condition(), Utc::now().timestamp()) {
+                None => {
+                    context.row_changeset.cell_by_field_id.remove(&context.field_rev.id);
+                }
+                Some(timestamp) => {
+                    let cell_rev = insert_date_cell(timestamp, context.field_rev);
+                    context
+                        .row_changeset
+                        .cell_by_field_id
+                        .insert(context.field_rev.id.clone(), cell_rev);
+                }
+            }
+        }
+        group_changeset
+    }
+}
+
+impl GroupController for DateGroupController {
+    fn will_create_row(&mut self, row_rev: &mut RowRevision, field_rev: &FieldRevision, group_id: &str) {
+        match self.group_ctx.get_group(group_id) {
+            None => tracing::warn!("Can not find the group: {}", group_id),
+            Some((_, group)) => {
+                // The row that is created in the `No Date` group keeps its date empty
+                let timestamp = group_timestamp(&group.id, &self.date_condition(), Utc::now().timestamp());
+                if let Some(timestamp) = timestamp {
+                    let cell_rev = insert_date_cell(timestamp, field_rev);
+                    row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+                }
+            }
+        }
+    }
+
+    fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+        if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+            group.add_row(row_pb.clone())
+        }
+    }
+}
+
+pub struct DateGroupGenerator();
+impl GroupGenerator for DateGroupGenerator {
+    type Context = DateGroupContext;
+    type TypeOptionType = DateTypeOptionPB;
+
+    fn generate_groups(
+        field_rev: &FieldRevision,
+        group_ctx: &Self::Context,
+        _type_option: &Option<Self::TypeOptionType>,
+        row_revs: &[Arc<RowRevision>],
+    ) -> GeneratedGroupContext {
+        let condition = group_ctx
+            .get_configuration_content()
+            .map(|content| content.condition)
+            .unwrap_or_default();
+
+        let now = Utc::now().timestamp();
+        let mut keys = row_revs
+            .iter()
+            .flat_map(|row_rev| {
+                let cell_rev = row_rev.cells.get(&field_rev.id)?;
+                let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+                if !type_cell_data.is_date() {
+                    return None;
+                }
+                let timestamp = DateCellData::from_cell_str(&type_cell_data.cell_str).ok()?.0?;
+                group_key_from_timestamp(timestamp, &condition, now)
+            })
+            .collect::<BTreeSet<String>>()
+            .into_iter()
+            .collect::<Vec<String>>();
+
+        // The groups are ordered chronologically. The keys of the relative periods, e.g. `today`,
+        // aren't ordered by their names.
+        keys.sort_by_key(|key| group_timestamp(key, &condition, now));
+
+        let group_configs = keys
+            .into_iter()
+            .map(|key| GeneratedGroupConfig {
+                group_rev: GroupRevision::new(key.clone(), group_name_from_key(&key)),
+                filter_content: key,
+                color: None,
+            })
+            .collect();
+
+        GeneratedGroupContext {
+            no_status_group: Some(GroupRevision::new(field_rev.id.clone(), "No Date".to_owned())),
+            group_configs,
+        }
+    }
+}
+
+const TODAY: &str = "today";
+const YESTERDAY: &str = "yesterday";
+const TOMORROW: &str = "tomorrow";
+const LAST_7_DAYS: &str = "last_7_days";
+const NEXT_7_DAYS: &str = "next_7_days";
+const LAST_30_DAYS: &str = "last_30_days";
+const NEXT_30_DAYS: &str = "next_30_days";
+
+/// Returns the key of the period that the timestamp falls into, for example, "2022-03" for
+/// [DateCondition::Month].
+///
+/// The [DateCondition::Relative] groups the dates by the number of days from `now`: today,
+/// yesterday, tomorrow, the last or next 7 days and the last or next 30 days. The dates that
+/// are further away are grouped by month.
+fn group_key_from_timestamp(timestamp: i64, condition: &DateCondition, now: i64) -> Option<String> {
+    let date_time = NaiveDateTime::from_timestamp_opt(timestamp, 0)?;
+    let fmt = match condition {
+        DateCondition::Relative => {
+            let today = NaiveDateTime::from_timestamp_opt(now, 0)?.date();
+            let key = match (date_time.date() - today).num_days() {
+                0 => Some(TODAY),
+                -1 => Some(YESTERDAY),
+                1 => Some(TOMORROW),
+                -7..=-2 => Some(LAST_7_DAYS),
+                2..=7 => Some(NEXT_7_DAYS),
+                -30..=-8 => Some(LAST_30_DAYS),
+                8..=30 => Some(NEXT_30_DAYS),
+                _ => None,
+            };
+            if let Some(key) = key {
+                return Some(key.to_owned());
+            }
+            "%Y-%m"
+        }
+        DateCondition::Day => "%Y-%m-%d",
+        DateCondition::Week => "%G-W%V",
+        DateCondition::Month => "%Y-%m",
+        DateCondition::Year => "%Y",
+    };
+    Some(date_time.format(fmt).to_string())
+}
+
+fn group_name_from_key(key: &str) -> String {
+    let name = match key {
+        TODAY => "Today",
+        YESTERDAY => "Yesterday",
+        TOMORROW => "Tomorrow",
+        LAST_7_DAYS => "Last 7 days",
+        NEXT_7_DAYS => "Next 7 days",
+        LAST_30_DAYS => "Last 30 days",
+        NEXT_30_DAYS => "Next 30 days",
+        _ => key,
+    };
+    name.to_owned()
+}
+
+/// Returns a timestamp that falls into the group with the key. It's used to order the groups and
+/// as the date of the rows that are created in or moved to the group. Returns None if the key is
+/// not a date group key, e.g. the id of the `No Date` group.
+fn group_timestamp(key: &str, condition: &DateCondition, now: i64) -> Option<i64> {
+    if !matches!(condition, DateCondition::Relative) {
+        return timestamp_from_group_key(key);
+    }
+
+    let today = NaiveDateTime::from_timestamp_opt(now, 0)?.date().and_hms(0, 0, 0);
+    let days = match key {
+        TODAY => 0,
+        YESTERDAY => -1,
+        TOMORROW => 1,
+        LAST_7_DAYS => -7,
+        NEXT_7_DAYS => 2,
+        LAST_30_DAYS => -30,
+        NEXT_30_DAYS => 8,
+        _ => {
+            // The start of a future month may be covered by the `next_30_days` group
+            let timestamp = timestamp_from_group_key(key)?;
+            if timestamp > today.timestamp() {
+                return Some(timestamp.max((today + Duration::days(31)).timestamp()));
+            }
+            return Some(timestamp);
+        }
+    };
+    Some((today + Duration::days(days)).timestamp())
+}
+
+/// Returns the timestamp of the start of the period represented by the group key. Returns None
+/// if the key is not a date group key, e.g. the id of the `No Date` group.
+pub(crate) fn timestamp_from_group_key(key: &str) -> Option<i64> {
+    let date = if let Some((year, week)) = key.split_once("-W") {
+        NaiveDate::from_isoywd_opt(year.parse().ok()?, week.parse().ok()?, Weekday::Mon)?
+    } else {
+        let mut parts = key.split('-');
+        let year = parts.next()?.parse::<i32>().ok()?;
+        let month = match parts.next() {
+            None => 1,
+            Some(s) => s.parse::<u32>().ok()?,
+        };
+        let day = match parts.next() {
+            None => 1,
+            Some(s) => s.parse::<u32>().ok()?,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        NaiveDate::from_ymd_opt(year, month, day)?
+    };
+    Some(date.and_hms(0, 0, 0).timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::cell::insert_date_cell;
+    use crate::services::field::FieldBuilder;
+    use crate::services::group::controller_impls::date_controller::{
+        group_key_from_timestamp, group_timestamp, timestamp_from_group_key, DateGroupContext, DateGroupGenerator,
+    };
+    use crate::services::group::{GroupConfigurationReader, GroupConfigurationWriter, GroupGenerator};
+    use flowy_error::FlowyResult;
+    use grid_rev_model::{
+        DateCondition, DateGroupConfigurationRevision, FieldTypeRevision, GroupConfigurationRevision, RowRevision,
+    };
+    use lib_infra::future::{to_fut, Fut};
+    use std::sync::Arc;
+
+    struct MockGroupConfiguration(Arc<GroupConfigurationRevision>);
+
+    impl GroupConfigurationReader for MockGroupConfiguration {
+        fn get_configuration(&self) -> Fut<Option<Arc<GroupConfigurationRevision>>> {
+            let configuration = self.0.clone();
+            to_fut(async move { Some(configuration) })
+        }
+    }
+
+    impl GroupConfigurationWriter for MockGroupConfiguration {
+        fn save_configuration(
+            &self,
+            _field_id: &str,
+            _field_type: FieldTypeRevision,
+            _group_configuration: GroupConfigurationRevision,
+        ) -> Fut<FlowyResult<()>> {
+            to_fut(async move { Ok(()) })
+        }
+    }
+
+    #[test]
+    fn date_group_key_test() {
+        // 2022-03-14 09:56:02 UTC
+        let timestamp = 1647251762;
+        let keys = [
+            (DateCondition::Day, "2022-03-14", 1647216000),
+            (DateCondition::Week, "2022-W11", 1647216000),
+            (DateCondition::Month, "2022-03", 1646092800),
+            (DateCondition::Year, "2022", 1640995200),
+        ];
+        for (condition, key, start) in keys {
+            assert_eq!(group_key_from_timestamp(timestamp, &condition, timestamp).unwrap(), key);
+            assert_eq!(timestamp_from_group_key(key).unwrap(), start);
+        }
+    }
+
+    #[test]
+    fn date_group_iso_week_key_at_year_boundary_test() {
+        // 2021-01-01 is a Friday, which belongs to the last ISO week of 2020
+        assert_eq!(
+            group_key_from_timestamp(1609459200, &DateCondition::Week, 1609459200).unwrap(),
+            "2020-W53"
+        );
+        assert_eq!(timestamp_from_group_key("2020-W53").unwrap(), 1609113600);
+
+        // 2024-12-30 is a Monday, which starts the first ISO week of 2025
+        assert_eq!(
+            group_key_from_timestamp(1735516800, &DateCondition::Week, 1735516800).unwrap(),
+            "2025-W01"
+        );
+        assert_eq!(timestamp_from_group_key("2025-W01").unwrap(), 1735516800);
+    }
+
+    #[test]
+    fn date_group_iso_week_order_at_year_boundary_test() {
+        let field_rev = Arc::new(FieldBuilder::from_field_type(&FieldType::DateTime).build());
+        let content = DateGroupConfigurationRevision {
+            hide_empty: false,
+            condition: DateCondition::Week,
+        };
+        let configuration =
+            Arc::new(GroupConfigurationRevision::new(field_rev.id.clone(), field_rev.ty, content).unwrap());
+        let group_ctx = futures::executor::block_on(DateGroupContext::new(
+            "".to_owned(),
+            field_rev.clone(),
+            Arc::new(MockGroupConfiguration(configuration.clone())),
+            Arc::new(MockGroupConfiguration(configuration)),
+        ))
+        .unwrap();
+
+        // 2021-03-10, 2024-12-30, 2021-01-01, 2022-01-08 and 2021-01-10
+        let row_revs = [1615334400, 1735516800, 1609459200, 1641600000, 1610236800]
+            .into_iter()
+            .map(|timestamp| {
+                let mut row_rev = RowRevision::new("");
+                row_rev
+                    .cells
+                    .insert(field_rev.id.clone(), insert_date_cell(timestamp, &field_rev));
+                Arc::new(row_rev)
+            })
+            .collect::<Vec<Arc<RowRevision>>>();
+        let generated = DateGroupGenerator::generate_groups(&field_rev, &group_ctx, &None, &row_revs);
+        let keys = generated
+            .group_configs
+            .iter()
+            .map(|group_config| group_config.filter_content.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(keys, vec!["2020-W53", "2021-W01", "2021-W10", "2022-W01", "2025-W01"]);
+    }
+
+    #[test]
+    fn date_group_key_from_field_id_test() {
+        assert!(timestamp_from_group_key("abc").is_none());
+        assert!(timestamp_from_group_key("2022-13").is_none());
+    }
+
+    #[test]
+    fn date_group_relative_key_test() {
+        // 2022-03-14 09:56:02 UTC
+        let now = 1647251762;
+        let day = 86400;
+        let keys = [
+            (0, "today"),
+            (-day, "yesterday"),
+            (day, "tomorrow"),
+            (-2 * day, "last_7_days"),
+            (-7 * day, "last_7_days"),
+            (7 * day, "next_7_days"),
+            (-8 * day, "last_30_days"),
+            (30 * day, "next_30_days"),
+            (-31 * day, "2022-02"),
+            (31 * day, "2022-04"),
+        ];
+        for (offset, key) in keys {
+            let group_key = group_key_from_timestamp(now + offset, &DateCondition::Relative, now).unwrap();
+            assert_eq!(group_key, key);
+
+            // The date of the row that is created in the group falls into the group
+            let timestamp = group_timestamp(key, &DateCondition::Relative, now).unwrap();
+            assert_eq!(
+                group_key_from_timestamp(timestamp, &DateCondition::Relative, now).unwrap(),
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn date_group_relative_order_test() {
+        let now = 1647251762;
+        let mut keys = vec![
+            "2022-05",
+            "tomorrow",
+            "last_30_days",
+            "today",
+            "next_30_days",
+            "2021-12",
+            "yesterday",
+            "next_7_days",
+            "last_7_days",
+            "2022-04",
+        ];
+        keys.sort_by_key(|key| group_timestamp(key, &DateCondition::Relative, now));
+        assert_eq!(
+            keys,
+            vec![
+                "2021-12",
+                "last_30_days",
+                "last_7_days",
+                "yesterday",
+                "today",
+                "tomorrow",
+                "next_7_days",
+                "next_30_days",
+                "2022-04",
+                "2022-05",
+            ]
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::entities::{GroupRowsNotificationPB, GroupViewChangesetPB, RowPB};
 use crate::services::group::action::GroupControllerSharedActions;
 use crate::services::group::{Group, GroupController, MoveGroupRowContext};
 use flowy_error::FlowyResult;
-use grid_rev_model::{FieldRevision, RowRevision};
+use grid_rev_model::{FieldRevision, GroupConfigurationRevision, RowRevision};
 use std::sync::Arc;
 
 /// A [DefaultGroupController] is used to handle the group actions for the [FieldType] that doesn't
@@ -40,6 +40,10 @@ impl GroupControllerSharedActions for DefaultGroupController {
         vec![&self.group]
     }
 
+    fn configuration(&self) -> Option<GroupConfigurationRevision> {
+        None
+    }
+
     fn get_group(&self, _group_id: &str) -> Option<(usize, Group)> {
         Some((0, self.group.clone()))
     }
@@ -75,9 +79,21 @@ impl GroupControllerSharedActions for DefaultGroupController {
         todo!()
     }
 
-    fn did_update_group_field(&mut self, _field_rev: &FieldRevision) -> FlowyResult<Option<GroupViewChangesetPB>> {
+    fn did_update_group_field(
+        &mut self,
+        _field_rev: &Arc<FieldRevision>,
+        _row_revs: &[Arc<RowRevision>],
+    ) -> FlowyResult<Option<GroupViewChangesetPB>> {
+        Ok(None)
+    }
+
+    fn set_no_status_group_hidden(&mut self, _hidden: bool) -> FlowyResult<Option<GroupViewChangesetPB>> {
         Ok(None)
     }
+
+    fn take_inserted_groups(&mut self) -> Option<GroupViewChangesetPB> {
+        None
+    }
 }
 
 impl GroupController for DefaultGroupController {
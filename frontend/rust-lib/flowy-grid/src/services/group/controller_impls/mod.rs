@@ -1,7 +1,9 @@
 mod checkbox_controller;
+mod date_controller;
 mod default_controller;
 mod select_option_controller;
 
 pub use checkbox_controller::*;
+pub use date_controller::*;
 pub use default_controller::*;
 pub use select_option_controller::*;
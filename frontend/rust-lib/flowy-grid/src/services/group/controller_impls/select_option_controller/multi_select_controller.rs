@@ -10,6 +10,7 @@ use crate::services::group::controller_impls::select_option_controller::util::*;
 
 use crate::services::group::{make_no_status_group, GeneratedGroupContext};
 use grid_rev_model::{FieldRevision, RowRevision, SelectOptionGroupConfigurationRevision};
+use std::sync::Arc;
 
 // MultiSelect
 pub type MultiSelectGroupController = GenericGroupController<
@@ -92,6 +93,7 @@ impl GroupGenerator for MultiSelectGroupGenerator {
         field_rev: &FieldRevision,
         group_ctx: &Self::Context,
         type_option: &Option<Self::TypeOptionType>,
+        _row_revs: &[Arc<RowRevision>],
     ) -> GeneratedGroupContext {
         let group_configs = match type_option {
             None => vec![],
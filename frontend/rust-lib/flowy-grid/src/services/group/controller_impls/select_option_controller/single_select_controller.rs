@@ -11,6 +11,7 @@ use crate::services::group::entities::Group;
 
 use crate::services::group::{make_no_status_group, GeneratedGroupContext};
 use grid_rev_model::{FieldRevision, RowRevision, SelectOptionGroupConfigurationRevision};
+use std::sync::Arc;
 
 // SingleSelect
 pub type SingleSelectGroupController = GenericGroupController<
@@ -91,6 +92,7 @@ impl GroupGenerator for SingleSelectGroupGenerator {
         field_rev: &FieldRevision,
         group_ctx: &Self::Context,
         type_option: &Option<Self::TypeOptionType>,
+        _row_revs: &[Arc<RowRevision>],
     ) -> GeneratedGroupContext {
         let group_configs = match type_option {
             None => vec![],
@@ -1,8 +1,9 @@
 use crate::entities::{FieldType, GroupRowsNotificationPB, InsertedRowPB, RowPB};
-use crate::services::cell::{insert_checkbox_cell, insert_select_option_cell};
+use crate::services::cell::{insert_checkbox_cell, insert_date_cell, insert_select_option_cell};
 use crate::services::field::{SelectOptionCellDataPB, SelectOptionPB, CHECK};
 use crate::services::group::configuration::GroupContext;
 use crate::services::group::controller::MoveGroupRowContext;
+use crate::services::group::controller_impls::date_controller::timestamp_from_group_key;
 use crate::services::group::{GeneratedGroupConfig, Group};
 use grid_rev_model::{CellRevision, FieldRevision, GroupRevision, RowRevision, SelectOptionGroupConfigurationRevision};
 
@@ -143,6 +144,10 @@ pub fn make_inserted_cell_rev(group_id: &str, field_rev: &FieldRevision) -> Opti
             let cell_rev = insert_checkbox_cell(group_id == CHECK, field_rev);
             Some(cell_rev)
         }
+        FieldType::DateTime => {
+            let timestamp = timestamp_from_group_key(group_id)?;
+            Some(insert_date_cell(timestamp, field_rev))
+        }
         _ => {
             tracing::warn!("Unknown field type: {:?}", field_type);
             None
@@ -156,9 +161,11 @@ pub fn generate_select_option_groups(
 ) -> Vec<GeneratedGroupConfig> {
     let groups = options
         .iter()
+        .filter(|option| !option.archived)
         .map(|option| GeneratedGroupConfig {
-            group_rev: GroupRevision::new(option.id.clone(), option.name.clone()),
+            group_rev: GroupRevision::new(option.id.clone(), option.name_with_icon()),
             filter_content: option.id.clone(),
+            color: Some(option.color.clone()),
         })
         .collect();
 
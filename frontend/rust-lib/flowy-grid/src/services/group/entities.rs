@@ -1,4 +1,5 @@
 use crate::entities::RowPB;
+use crate::services::field::SelectOptionColorPB;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Group {
@@ -11,6 +12,9 @@ pub struct Group {
 
     /// [filter_content] is used to determine which group the cell belongs to.
     pub filter_content: String,
+
+    /// Only the groups of the select option fields have a color.
+    pub color: Option<SelectOptionColorPB>,
 }
 
 impl Group {
@@ -24,6 +28,7 @@ impl Group {
             name,
             rows: vec![],
             filter_content,
+            color: None,
         }
     }
 
@@ -2,8 +2,8 @@ use crate::entities::FieldType;
 use crate::services::group::configuration::GroupConfigurationReader;
 use crate::services::group::controller::GroupController;
 use crate::services::group::{
-    CheckboxGroupContext, CheckboxGroupController, DefaultGroupController, GroupConfigurationWriter,
-    MultiSelectGroupController, SelectOptionGroupContext, SingleSelectGroupController,
+    CheckboxGroupContext, CheckboxGroupController, DateGroupContext, DateGroupController, DefaultGroupController,
+    GroupConfigurationWriter, MultiSelectGroupController, SelectOptionGroupContext, SingleSelectGroupController,
 };
 use flowy_error::FlowyResult;
 use grid_rev_model::{
@@ -47,21 +47,27 @@ where
             let configuration =
                 SelectOptionGroupContext::new(view_id, field_rev.clone(), configuration_reader, configuration_writer)
                     .await?;
-            let controller = SingleSelectGroupController::new(&field_rev, configuration).await?;
+            let controller = SingleSelectGroupController::new(&field_rev, configuration, &row_revs).await?;
             group_controller = Box::new(controller);
         }
         FieldType::MultiSelect => {
             let configuration =
                 SelectOptionGroupContext::new(view_id, field_rev.clone(), configuration_reader, configuration_writer)
                     .await?;
-            let controller = MultiSelectGroupController::new(&field_rev, configuration).await?;
+            let controller = MultiSelectGroupController::new(&field_rev, configuration, &row_revs).await?;
             group_controller = Box::new(controller);
         }
         FieldType::Checkbox => {
             let configuration =
                 CheckboxGroupContext::new(view_id, field_rev.clone(), configuration_reader, configuration_writer)
                     .await?;
-            let controller = CheckboxGroupController::new(&field_rev, configuration).await?;
+            let controller = CheckboxGroupController::new(&field_rev, configuration, &row_revs).await?;
+            group_controller = Box::new(controller);
+        }
+        FieldType::DateTime => {
+            let configuration =
+                DateGroupContext::new(view_id, field_rev.clone(), configuration_reader, configuration_writer).await?;
+            let controller = DateGroupController::new(&field_rev, configuration, &row_revs).await?;
             group_controller = Box::new(controller);
         }
         _ => {
@@ -136,6 +142,10 @@ pub fn default_group_configuration(field_rev: &FieldRevision) -> GroupConfigurat
         FieldType::URL => {
             GroupConfigurationRevision::new(field_id, field_type_rev, UrlGroupConfigurationRevision::default()).unwrap()
         }
+        FieldType::Formula | FieldType::CreatedBy | FieldType::LastEditedBy => {
+            GroupConfigurationRevision::new(field_id, field_type_rev, TextGroupConfigurationRevision::default())
+                .unwrap()
+        }
     }
 }
 
@@ -3,10 +3,13 @@ mod util;
 pub mod block_editor;
 pub mod block_manager;
 pub mod cell;
+pub mod errors;
+pub mod export;
 pub mod field;
 pub mod filter;
 pub mod grid_editor;
 mod grid_editor_trait_impl;
+pub mod grid_event;
 pub mod group;
 pub mod persistence;
 pub mod row;
@@ -1,6 +1,7 @@
 use crate::services::persistence::GridDatabase;
 use ::diesel::{query_dsl::*, ExpressionMethods};
 use bytes::Bytes;
+use diesel::result::OptionalExtension;
 use diesel::SqliteConnection;
 use flowy_database::{
     prelude::*,
@@ -17,6 +18,12 @@ pub struct KeyValue {
     value: Vec<u8>,
 }
 
+impl KeyValue {
+    pub fn new(key: String, value: Vec<u8>) -> Self {
+        Self { key, value }
+    }
+}
+
 pub trait KVTransaction {
     fn get<T: TryFrom<Bytes, Error = ::protobuf::ProtobufError>>(&self, key: &str) -> FlowyResult<Option<T>>;
     fn set<T: Into<KeyValue>>(&self, value: T) -> FlowyResult<()>;
@@ -86,9 +93,10 @@ impl<'a> KVTransaction for SqliteTransaction<'a> {
     fn get<T: TryFrom<Bytes, Error = ::protobuf::ProtobufError>>(&self, key: &str) -> FlowyResult<Option<T>> {
         let item = dsl::kv_table
             .filter(kv_table::key.eq(key))
-            .first::<KeyValue>(self.conn)?;
-        let value = T::try_from(Bytes::from(item.value)).unwrap();
-        Ok(Some(value))
+            .first::<KeyValue>(self.conn)
+            .optional()?;
+        let value = item.map(|item| T::try_from(Bytes::from(item.value)).unwrap());
+        Ok(value)
     }
 
     fn set<T: Into<KeyValue>>(&self, value: T) -> FlowyResult<()> {
@@ -1,5 +1,7 @@
 mod row_builder;
 mod row_loader;
+mod row_normalizer;
 
 pub use row_builder::*;
 pub use row_loader::*;
+pub use row_normalizer::*;
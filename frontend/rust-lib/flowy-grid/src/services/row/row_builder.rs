@@ -122,6 +122,9 @@ impl<'a> RowRevisionBuilder<'a> {
             cells: self.payload.cell_by_field_id,
             height: self.payload.height,
             visibility: self.payload.visibility,
+            archived: false,
+            created_by: None,
+            last_edited_by: None,
         }
     }
 }
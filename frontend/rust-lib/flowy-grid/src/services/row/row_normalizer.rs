@@ -0,0 +1,109 @@
+use crate::entities::FieldType;
+use crate::services::cell::TypeCellData;
+use crate::services::field::{
+    ChecklistTypeOptionPB, MultiSelectTypeOptionPB, NumberTypeOptionPB, SelectOptionIds, SelectOptionPB,
+    SingleSelectTypeOptionPB,
+};
+use grid_rev_model::{CellRevision, FieldRevision, RowChangeset, RowRevision};
+use std::sync::Arc;
+
+/// The summary of the fixes applied by normalizing the rows of a grid.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NormalizeGridSummary {
+    /// The number of the option ids that were removed from the cells because the options don't exist.
+    pub pruned_option_ids: usize,
+    /// The number of the cells that were removed because their fields were deleted.
+    pub removed_cells: usize,
+    /// The number of the number cells that were cleared because they can't be parsed.
+    pub cleared_number_cells: usize,
+}
+
+impl NormalizeGridSummary {
+    pub fn number_of_fixes(&self) -> usize {
+        self.pruned_option_ids + self.removed_cells + self.cleared_number_cells
+    }
+}
+
+/// Returns the changeset that fixes the row, or `None` if the row is consistent with the fields.
+/// The cells that were written with another field type are left untouched, they're transformed
+/// when they're read.
+///
+/// There are no bounded number fields in the grid, so the number cells are only checked to be
+/// parsable.
+pub fn normalize_row_rev(
+    row_rev: &RowRevision,
+    field_revs: &[Arc<FieldRevision>],
+    summary: &mut NormalizeGridSummary,
+) -> Option<RowChangeset> {
+    let mut changeset = RowChangeset::new(row_rev.id.clone());
+    for (field_id, cell_rev) in row_rev.cells.iter() {
+        let field_rev = match field_revs.iter().find(|field_rev| &field_rev.id == field_id) {
+            None => {
+                changeset.deleted_field_ids.push(field_id.clone());
+                summary.removed_cells += 1;
+                continue;
+            }
+            Some(field_rev) => field_rev,
+        };
+
+        let field_type: FieldType = field_rev.ty.into();
+        let type_cell_data = match TypeCellData::try_from(cell_rev) {
+            Ok(type_cell_data) if type_cell_data.field_type == field_type => type_cell_data,
+            _ => continue,
+        };
+
+        let normalized_cell_str = match field_type {
+            FieldType::SingleSelect => {
+                let options = SingleSelectTypeOptionPB::from(field_rev).options;
+                prune_option_ids(&type_cell_data.cell_str, &options, summary)
+            }
+            FieldType::MultiSelect => {
+                let options = MultiSelectTypeOptionPB::from(field_rev).options;
+                prune_option_ids(&type_cell_data.cell_str, &options, summary)
+            }
+            FieldType::Checklist => {
+                let options = ChecklistTypeOptionPB::from(field_rev).options;
+                prune_option_ids(&type_cell_data.cell_str, &options, summary)
+            }
+            FieldType::Number => {
+                let type_option = NumberTypeOptionPB::from(field_rev);
+                if type_option.format_cell_data(&type_cell_data.cell_str).is_ok() {
+                    None
+                } else {
+                    summary.cleared_number_cells += 1;
+                    Some("".to_owned())
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(cell_str) = normalized_cell_str {
+            let cell_rev = CellRevision::new(TypeCellData::new(cell_str, field_type).to_json());
+            changeset.cell_by_field_id.insert(field_id.clone(), cell_rev);
+        }
+    }
+
+    if changeset.is_empty() {
+        None
+    } else {
+        Some(changeset)
+    }
+}
+
+/// Returns the cell string without the ids of the options that don't exist, or `None` if all the
+/// ids exist.
+fn prune_option_ids(cell_str: &str, options: &[SelectOptionPB], summary: &mut NormalizeGridSummary) -> Option<String> {
+    let ids = SelectOptionIds::from(cell_str.to_owned());
+    let existing_ids = ids
+        .iter()
+        .filter(|id| options.iter().any(|option| &option.id == *id))
+        .cloned()
+        .collect::<Vec<String>>();
+    let number_of_pruned = ids.len() - existing_ids.len();
+    if number_of_pruned == 0 {
+        return None;
+    }
+
+    summary.pruned_option_ids += number_of_pruned;
+    Some(SelectOptionIds::from(existing_ids).to_string())
+}
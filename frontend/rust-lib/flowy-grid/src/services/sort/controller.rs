@@ -1,7 +1,7 @@
 use crate::entities::FieldType;
 use crate::entities::SortChangesetNotificationPB;
 use crate::services::cell::{AtomicCellDataCache, TypeCellData};
-use crate::services::field::{default_order, TypeOptionCellExt};
+use crate::services::field::{default_order, TypeOptionCellExt, UNCHECK};
 use crate::services::sort::{ReorderAllRowsResult, ReorderSingleRowResult, SortChangeset, SortType};
 use crate::services::view_editor::{GridViewChanged, GridViewChangedNotifier};
 use flowy_error::FlowyResult;
@@ -187,9 +187,20 @@ fn cmp_row(
     field_revs: &[Arc<FieldRevision>],
     cell_data_cache: &AtomicCellDataCache,
 ) -> Ordering {
-    let order = match (left.cells.get(&sort.field_id), right.cells.get(&sort.field_id)) {
+    let field_type: FieldType = sort.field_type.into();
+    // The row without a checkbox cell is unchecked, so it's ordered together with the unchecked
+    // rows instead of before all the other rows.
+    let unchecked_cell = if field_type.is_checkbox() {
+        Some(CellRevision::new(
+            TypeCellData::new(UNCHECK.to_string(), FieldType::Checkbox).to_json(),
+        ))
+    } else {
+        None
+    };
+    let left_cell = left.cells.get(&sort.field_id).or(unchecked_cell.as_ref());
+    let right_cell = right.cells.get(&sort.field_id).or(unchecked_cell.as_ref());
+    let order = match (left_cell, right_cell) {
         (Some(left_cell), Some(right_cell)) => {
-            let field_type: FieldType = sort.field_type.into();
             match field_revs.iter().find(|field_rev| field_rev.id == sort.field_id) {
                 None => default_order(),
                 Some(field_rev) => cmp_cell(left_cell, right_cell, field_rev, field_type, cell_data_cache),
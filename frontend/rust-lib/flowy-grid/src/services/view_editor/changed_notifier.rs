@@ -1,5 +1,5 @@
 use crate::dart_notification::{send_dart_notification, GridDartNotification};
-use crate::entities::{GridRowsVisibilityChangesetPB, ReorderAllRowsPB, ReorderSingleRowPB};
+use crate::entities::{GridRowsVisibilityChangesetPB, GroupViewChangesetPB, ReorderAllRowsPB, ReorderSingleRowPB};
 use crate::services::filter::FilterResultNotification;
 use crate::services::sort::{ReorderAllRowsResult, ReorderSingleRowResult};
 use async_stream::stream;
@@ -11,6 +11,7 @@ pub enum GridViewChanged {
     FilterNotification(FilterResultNotification),
     ReorderAllRowsNotification(ReorderAllRowsResult),
     ReorderSingleRowNotification(ReorderSingleRowResult),
+    GroupViewNotification(GroupViewChangesetPB),
 }
 
 pub type GridViewChangedNotifier = broadcast::Sender<GridViewChanged>;
@@ -61,6 +62,11 @@ impl GridViewChangedReceiverRunner {
                             .payload(reorder_row)
                             .send()
                     }
+                    GridViewChanged::GroupViewNotification(changeset) => {
+                        send_dart_notification(&changeset.view_id, GridDartNotification::DidUpdateGroupView)
+                            .payload(changeset)
+                            .send()
+                    }
                 }
             })
             .await;
@@ -2,25 +2,29 @@ use crate::dart_notification::{send_dart_notification, GridDartNotification};
 use crate::entities::*;
 use crate::services::block_manager::GridBlockEvent;
 use crate::services::cell::AtomicCellDataCache;
-use crate::services::filter::{FilterChangeset, FilterController, FilterTaskHandler, FilterType, UpdatedFilterType};
+use crate::services::errors::GridError;
+use crate::services::filter::{
+    FilterChangeset, FilterController, FilterTaskHandler, FilterType, RowVisibilityPredicate, UpdatedFilterType,
+};
 use crate::services::group::{
     default_group_configuration, find_group_field, make_group_controller, Group, GroupConfigurationReader,
     GroupController, MoveGroupRowContext,
 };
 use crate::services::row::GridBlockRowRevision;
 use crate::services::sort::{DeletedSortType, SortChangeset, SortController, SortTaskHandler, SortType};
-use crate::services::view_editor::changed_notifier::GridViewChangedNotifier;
+use crate::services::view_editor::changed_notifier::{GridViewChanged, GridViewChangedNotifier};
 use crate::services::view_editor::trait_impl::*;
 use crate::services::view_editor::GridViewChangedReceiverRunner;
 use flowy_database::ConnectionPool;
 use flowy_error::FlowyResult;
 use flowy_http_model::revision::Revision;
-use flowy_revision::RevisionManager;
+use flowy_revision::{RevisionManager, RECENT_REVISIONS_AFTER_COMPACTION};
 use flowy_sync::client_grid::{make_grid_view_operations, GridViewRevisionChangeset, GridViewRevisionPad};
 use flowy_task::TaskDispatcher;
 use grid_rev_model::{
-    gen_grid_filter_id, gen_grid_sort_id, FieldRevision, FieldTypeRevision, FilterRevision, LayoutRevision,
-    RowChangeset, RowRevision, SortRevision,
+    gen_grid_filter_id, gen_grid_sort_id, DateCondition, DateGroupConfigurationRevision, FieldRevision,
+    FieldTypeRevision, FilterConjunctionRevision, FilterRevision, GridViewRevision, GroupConfigurationContentSerde,
+    GroupConfigurationRevision, LayoutRevision, RowChangeset, RowHeightRevision, RowRevision, SortRevision,
 };
 use lib_infra::async_trait::async_trait;
 use lib_infra::future::Fut;
@@ -187,6 +191,9 @@ impl GridViewRevisionEditor {
     }
 
     pub async fn filter_rows(&self, _block_id: &str, rows: &mut Vec<Arc<RowRevision>>) {
+        if !self.pad.read().await.show_archived_rows {
+            rows.retain(|row_rev| !row_rev.archived);
+        }
         self.filter_controller.write().await.filter_row_revs(rows).await;
     }
 
@@ -195,6 +202,23 @@ impl GridViewRevisionEditor {
         Ok(json_str)
     }
 
+    /// Squashes the revisions of the view into a base revision followed by the most recent
+    /// revisions.
+    pub async fn compact_view_revisions(&self) -> FlowyResult<()> {
+        let _pad = self.pad.write().await;
+        self.rev_manager
+            .compact_revisions(RECENT_REVISIONS_AFTER_COMPACTION)
+            .await
+    }
+
+    /// Returns a copy of the view's revision with the `new_view_id`. The copy keeps the filters,
+    /// sorts, groups and the field order of the view.
+    pub async fn duplicate_view_rev(&self, new_view_id: &str) -> GridViewRevision {
+        let mut view_rev = GridViewRevision::clone(&*self.pad.read().await);
+        view_rev.view_id = new_view_id.to_owned();
+        view_rev
+    }
+
     pub async fn will_create_view_row(&self, row_rev: &mut RowRevision, params: &CreateRowParams) {
         if params.group_id.is_none() {
             return;
@@ -248,12 +272,27 @@ impl GridViewRevisionEditor {
     }
 
     pub async fn did_update_view_cell(&self, row_rev: &RowRevision) {
+        // The archived row is removed from the groups unless the view shows the archived rows.
+        let is_hidden = row_rev.archived && !self.pad.read().await.show_archived_rows;
         let changesets = self
             .mut_group_controller(|group_controller, field_rev| {
-                group_controller.did_update_group_row(row_rev, &field_rev)
+                if is_hidden {
+                    group_controller.did_delete_delete_row(row_rev, &field_rev)
+                } else {
+                    group_controller.did_update_group_row(row_rev, &field_rev)
+                }
             })
             .await;
 
+        // The new groups must be notified before the rows that are moved into them.
+        let inserted_groups = self
+            .mut_group_controller(|group_controller, _| Ok(group_controller.take_inserted_groups()))
+            .await
+            .flatten();
+        if let Some(changeset) = inserted_groups {
+            self.notify_did_update_view(changeset).await;
+        }
+
         if let Some(changesets) = changesets {
             for changeset in changesets {
                 self.notify_did_update_group_rows(changeset).await;
@@ -269,6 +308,15 @@ impl GridViewRevisionEditor {
         });
     }
 
+    /// Re-filters the rows of the view if one of its filters compares with the deleted field.
+    pub async fn did_delete_view_field(&self, field_id: &str) {
+        self.filter_controller
+            .read()
+            .await
+            .did_receive_field_changed(field_id)
+            .await;
+    }
+
     pub async fn move_view_group_row(
         &self,
         row_rev: &RowRevision,
@@ -337,6 +385,58 @@ impl GridViewRevisionEditor {
         Ok(())
     }
 
+    /// Hide or show the `No status` group of the current grouping. The rows of the group are kept,
+    /// only the group itself is omitted from the groups.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn update_view_group_hide_empty(&self, hide_empty: bool) -> FlowyResult<()> {
+        let changeset = self
+            .group_controller
+            .write()
+            .await
+            .set_no_status_group_hidden(hide_empty)?;
+        if let Some(changeset) = changeset {
+            self.notify_did_update_view(changeset).await;
+        }
+        Ok(())
+    }
+
+    /// Groups the rows of the date field by the period of the `condition`, e.g. by month. The groups
+    /// are generated again from the dates of the rows.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn update_view_date_group_condition(&self, field_id: &str, condition: DateCondition) -> FlowyResult<()> {
+        let field_rev = self
+            .delegate
+            .get_field_rev(field_id)
+            .await
+            .ok_or_else(|| GridError::field_not_found(field_id))?;
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_date() {
+            return Err(GridError::type_mismatch(field_id, "date").into());
+        }
+
+        let hide_empty = self
+            .pad
+            .read()
+            .await
+            .get_all_groups()
+            .into_iter()
+            .find(|configuration| configuration.field_id == field_id)
+            .and_then(|configuration| DateGroupConfigurationRevision::from_json(&configuration.content).ok())
+            .map(|content| content.hide_empty)
+            .unwrap_or(false);
+
+        // The groups of the previous period are dropped by saving a new configuration.
+        let content = DateGroupConfigurationRevision { hide_empty, condition };
+        let configuration = GroupConfigurationRevision::new(field_id.to_owned(), field_rev.ty, content)?;
+        let _ = self
+            .modify(|pad| {
+                let changeset = pad.insert_or_update_group_configuration(field_id, &field_rev.ty, configuration)?;
+                Ok(changeset)
+            })
+            .await?;
+        self.group_by_view_field(field_id).await
+    }
+
     pub async fn group_id(&self) -> String {
         self.group_controller.read().await.field_id().to_string()
     }
@@ -347,6 +447,87 @@ impl GridViewRevisionEditor {
         grid_setting
     }
 
+    /// Returns the fields in the order of the current view
+    pub async fn get_view_field_revs(&self) -> Vec<Arc<FieldRevision>> {
+        let field_revs = self.delegate.get_field_revs(None).await;
+        self.pad.read().await.order_field_revs(field_revs)
+    }
+
+    /// Saves the order of the fields in the current view. The ids of the fields that don't
+    /// exist are ignored.
+    pub async fn update_view_field_order(&self, mut field_ids: Vec<String>) -> FlowyResult<()> {
+        let field_revs = self.delegate.get_field_revs(None).await;
+        field_ids.retain(|field_id| field_revs.iter().any(|field_rev| &field_rev.id == field_id));
+        self.modify(|pad| {
+            let changeset = pad.update_field_order(field_ids)?;
+            Ok(changeset)
+        })
+        .await
+    }
+
+    /// Moves the field to `to_index` in the field order of the current view. The index is clamped
+    /// to the number of the fields.
+    pub async fn move_view_field(&self, field_id: &str, to_index: usize) -> FlowyResult<()> {
+        let mut field_ids = self
+            .get_view_field_revs()
+            .await
+            .iter()
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        let from_index = match field_ids.iter().position(|id| id == field_id) {
+            None => return Err(GridError::field_not_found(field_id).into()),
+            Some(index) => index,
+        };
+        let field_id = field_ids.remove(from_index);
+        field_ids.insert(to_index.min(field_ids.len()), field_id);
+        self.update_view_field_order(field_ids).await
+    }
+
+    pub async fn update_view_show_archived_rows(&self, show_archived_rows: bool) -> FlowyResult<()> {
+        let _ = self
+            .modify(|pad| {
+                let changeset = pad.update_show_archived_rows(show_archived_rows)?;
+                Ok(changeset)
+            })
+            .await?;
+
+        // Regroups the rows, so the groups show or hide the archived rows too.
+        let group_field_id = self.group_controller.read().await.field_id().to_owned();
+        if self.delegate.get_field_rev(&group_field_id).await.is_some() {
+            let _ = self.group_by_view_field(&group_field_id).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn update_view_row_height(&self, row_height: RowHeightRevision) -> FlowyResult<()> {
+        self.modify(|pad| {
+            let changeset = pad.update_row_height(row_height)?;
+            Ok(changeset)
+        })
+        .await
+    }
+
+    pub async fn update_view_filter_conjunction(
+        &self,
+        filter_conjunction: FilterConjunctionRevision,
+    ) -> FlowyResult<()> {
+        self.modify(|pad| {
+            let changeset = pad.update_filter_conjunction(filter_conjunction)?;
+            Ok(changeset)
+        })
+        .await?;
+        self.filter_controller
+            .write()
+            .await
+            .did_update_conjunction(filter_conjunction)
+            .await;
+        Ok(())
+    }
+
+    pub async fn set_row_visibility_predicate(&self, predicate: Option<RowVisibilityPredicate>) {
+        self.filter_controller.write().await.set_row_predicate(predicate).await;
+    }
+
     pub async fn get_all_view_filters(&self) -> Vec<Arc<FilterRevision>> {
         let field_revs = self.delegate.get_field_revs(None).await;
         self.pad.read().await.get_all_filters(&field_revs)
@@ -526,6 +707,87 @@ impl GridViewRevisionEditor {
         Ok(())
     }
 
+    /// Removes all the filters, sorts and the group configuration of the view in one revision. The
+    /// rows are shown in their default order, and the view is grouped by its default field again.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn reset_view_settings(&self) -> FlowyResult<()> {
+        let field_revs = self.delegate.get_field_revs(None).await;
+        let (filter_revs, sort_revs, layout) = {
+            let pad = self.pad.read().await;
+            (
+                pad.get_all_filters(&field_revs),
+                pad.get_all_sorts(&field_revs),
+                pad.layout(),
+            )
+        };
+
+        // The controllers read the removed filters and sorts from the pad, so they need to receive
+        // the changes before resetting the pad.
+        for filter_rev in filter_revs {
+            let filter_type = FilterType {
+                field_id: filter_rev.field_id.clone(),
+                field_type: filter_rev.field_type.into(),
+            };
+            let changeset = self
+                .filter_controller
+                .write()
+                .await
+                .did_receive_changes(FilterChangeset::from_delete(filter_type))
+                .await;
+            if let Some(changeset) = changeset {
+                self.notify_did_update_filter(changeset).await;
+            }
+        }
+
+        for sort_rev in sort_revs {
+            let deleted_sort_type = DeletedSortType {
+                sort_type: SortType {
+                    field_id: sort_rev.field_id.clone(),
+                    field_type: sort_rev.field_type.into(),
+                },
+                sort_id: sort_rev.id.clone(),
+            };
+            let changeset = self
+                .sort_controller
+                .write()
+                .await
+                .did_receive_changes(SortChangeset::from_delete(deleted_sort_type))
+                .await;
+            self.notify_did_update_sort(changeset).await;
+        }
+
+        // Generates the groups up front, so the reset and the generated groups are saved in one
+        // revision. Regrouping the rows afterwards finds the groups unchanged.
+        let group_field_rev = find_group_field(&field_revs, &layout);
+        let configuration = match group_field_rev.as_ref() {
+            None => None,
+            Some(field_rev) => {
+                let configuration = Arc::new(default_group_configuration(field_rev));
+                let group_controller = make_group_controller(
+                    self.view_id.clone(),
+                    field_rev.clone(),
+                    get_grouped_row_revs(&self.pad, &self.delegate).await,
+                    DetachedGroupConfiguration(configuration.clone()),
+                    DetachedGroupConfiguration(configuration),
+                )
+                .await?;
+                group_controller.configuration()
+            }
+        };
+        let _ = self
+            .modify(|pad| {
+                let changeset = pad.reset_settings(configuration)?;
+                Ok(changeset)
+            })
+            .await?;
+
+        if let Some(group_field_rev) = group_field_rev {
+            let _ = self.group_by_view_field(&group_field_rev.id).await?;
+        }
+        self.notify_did_update_setting().await;
+        Ok(())
+    }
+
     #[tracing::instrument(level = "trace", skip_all, err)]
     pub async fn did_update_view_field_type_option(
         &self,
@@ -557,6 +819,27 @@ impl GridViewRevisionEditor {
         Ok(())
     }
 
+    /// Updates the groups after the type-option data of the grouping field is changed. The groups
+    /// are regenerated with the new type-option data, so the renamed or recolored groups are
+    /// reported as updated along with the new and the deleted ones.
+    #[tracing::instrument(level = "debug", skip_all, err)]
+    pub async fn did_update_group_field(&self, field_id: &str) -> FlowyResult<()> {
+        let field_rev = match self.delegate.get_field_rev(field_id).await {
+            None => return Ok(()),
+            Some(field_rev) => field_rev,
+        };
+        let row_revs = get_grouped_row_revs(&self.pad, &self.delegate).await;
+        let changeset = self
+            .group_controller
+            .write()
+            .await
+            .did_update_group_field(&field_rev, &row_revs)?;
+        if let Some(changeset) = changeset {
+            self.notify_did_update_view(changeset).await;
+        }
+        Ok(())
+    }
+
     ///
     ///
     /// # Arguments
@@ -566,7 +849,7 @@ impl GridViewRevisionEditor {
     #[tracing::instrument(level = "debug", skip_all, err)]
     pub async fn group_by_view_field(&self, field_id: &str) -> FlowyResult<()> {
         if let Some(field_rev) = self.delegate.get_field_rev(field_id).await {
-            let row_revs = self.delegate.get_row_revs(None).await;
+            let row_revs = get_grouped_row_revs(&self.pad, &self.delegate).await;
             let new_group_controller = new_group_controller_with_field_rev(
                 self.user_id.clone(),
                 self.view_id.clone(),
@@ -628,9 +911,7 @@ impl GridViewRevisionEditor {
     }
 
     async fn notify_did_update_view(&self, changeset: GroupViewChangesetPB) {
-        send_dart_notification(&self.view_id, GridDartNotification::DidUpdateGroupView)
-            .payload(changeset)
-            .send();
+        let _ = self.notifier.send(GridViewChanged::GroupViewNotification(changeset));
     }
 
     async fn modify<F>(&self, f: F) -> FlowyResult<()>
@@ -694,7 +975,7 @@ async fn new_group_controller(
 ) -> FlowyResult<Box<dyn GroupController>> {
     let configuration_reader = GroupConfigurationReaderImpl(view_rev_pad.clone());
     let field_revs = delegate.get_field_revs(None).await;
-    let row_revs = delegate.get_row_revs(None).await;
+    let row_revs = get_grouped_row_revs(&view_rev_pad, &delegate).await;
     let layout = view_rev_pad.read().await.layout();
     // Read the group field or find a new group field
     let field_rev = configuration_reader
@@ -711,6 +992,19 @@ async fn new_group_controller(
     new_group_controller_with_field_rev(user_id, view_id, view_rev_pad, rev_manager, field_rev, row_revs).await
 }
 
+/// Returns the rows that are grouped in the view. The archived rows are excluded unless the view
+/// shows them.
+async fn get_grouped_row_revs(
+    view_rev_pad: &Arc<RwLock<GridViewRevisionPad>>,
+    delegate: &Arc<dyn GridViewEditorDelegate>,
+) -> Vec<Arc<RowRevision>> {
+    let mut row_revs = delegate.get_row_revs(None).await;
+    if !view_rev_pad.read().await.show_archived_rows {
+        row_revs.retain(|row_rev| !row_rev.archived);
+    }
+    row_revs
+}
+
 /// Returns a [GroupController]  
 ///
 async fn new_group_controller_with_field_rev(
@@ -5,7 +5,7 @@ use crate::entities::{
 use crate::manager::GridUser;
 use crate::services::block_manager::GridBlockEvent;
 use crate::services::cell::AtomicCellDataCache;
-use crate::services::filter::FilterType;
+use crate::services::filter::{FilterType, RowVisibilityPredicate};
 use crate::services::persistence::rev_sqlite::{
     SQLiteGridRevisionSnapshotPersistence, SQLiteGridViewRevisionPersistence,
 };
@@ -14,8 +14,13 @@ use crate::services::view_editor::trait_impl::GridViewRevisionMergeable;
 use crate::services::view_editor::{GridViewEditorDelegate, GridViewRevisionEditor};
 use flowy_database::ConnectionPool;
 use flowy_error::FlowyResult;
+use flowy_http_model::revision::Revision;
 use flowy_revision::{RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration};
-use grid_rev_model::{FieldRevision, FilterRevision, RowChangeset, RowRevision, SortRevision};
+use flowy_sync::client_grid::make_grid_view_operations;
+use grid_rev_model::{
+    gen_grid_view_id, DateCondition, FieldRevision, FilterConjunctionRevision, FilterRevision, RowChangeset,
+    RowHeightRevision, RowRevision, SortRevision,
+};
 use lib_infra::future::Fut;
 use lib_infra::ref_map::RefCountHashMap;
 use std::borrow::Cow;
@@ -73,6 +78,25 @@ impl GridViewManager {
         Ok(view_data)
     }
 
+    pub async fn compact_revisions(&self, view_id: &str) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.compact_view_revisions().await
+    }
+
+    /// Creates a new view with the same settings as the view with `view_id`. The new view shares
+    /// the fields and the rows of the grid. Returns the id of the new view.
+    pub async fn duplicate_view(&self, view_id: &str) -> FlowyResult<String> {
+        let editor = self.get_view_editor(view_id).await?;
+        let new_view_id = gen_grid_view_id();
+        let view_rev = editor.duplicate_view_rev(&new_view_id).await;
+        let bytes = make_grid_view_operations(&view_rev).json_bytes();
+        let revision = Revision::initial_revision(&new_view_id, bytes);
+        let rev_manager = make_grid_view_rev_manager(&self.user, &new_view_id).await?;
+        let _ = rev_manager.reset_object(vec![revision]).await?;
+        let _ = self.get_view_editor(&new_view_id).await?;
+        Ok(new_view_id)
+    }
+
     /// When the row was created, we may need to modify the [RowRevision] according to the [CreateRowParams].
     pub async fn will_create_row(&self, row_rev: &mut RowRevision, params: &CreateRowParams) {
         for view_editor in self.view_editors.read().await.values() {
@@ -101,6 +125,12 @@ impl GridViewManager {
         }
     }
 
+    pub async fn did_delete_field(&self, field_id: &str) {
+        for view_editor in self.view_editors.read().await.values() {
+            view_editor.did_delete_view_field(field_id).await;
+        }
+    }
+
     pub async fn group_by_field(&self, field_id: &str) -> FlowyResult<()> {
         let view_editor = self.get_default_view_editor().await?;
         let _ = view_editor.group_by_view_field(field_id).await?;
@@ -118,6 +148,55 @@ impl GridViewManager {
         Ok(view_editor.get_view_setting().await)
     }
 
+    pub async fn get_view_setting(&self, view_id: &str) -> FlowyResult<GridSettingPB> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_view_setting().await)
+    }
+
+    pub async fn get_view_field_revs(&self, view_id: &str) -> FlowyResult<Vec<Arc<FieldRevision>>> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_view_field_revs().await)
+    }
+
+    pub async fn update_view_field_order(&self, view_id: &str, field_ids: Vec<String>) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.update_view_field_order(field_ids).await
+    }
+
+    pub async fn move_view_field(&self, view_id: &str, field_id: &str, to_index: usize) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.move_view_field(field_id, to_index).await
+    }
+
+    pub async fn update_view_show_archived_rows(&self, view_id: &str, show_archived_rows: bool) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.update_view_show_archived_rows(show_archived_rows).await
+    }
+
+    pub async fn update_view_row_height(&self, view_id: &str, row_height: RowHeightRevision) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.update_view_row_height(row_height).await
+    }
+
+    pub async fn update_view_filter_conjunction(
+        &self,
+        view_id: &str,
+        filter_conjunction: FilterConjunctionRevision,
+    ) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.update_view_filter_conjunction(filter_conjunction).await
+    }
+
+    pub async fn set_row_visibility_predicate(
+        &self,
+        view_id: &str,
+        predicate: Option<RowVisibilityPredicate>,
+    ) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.set_row_visibility_predicate(predicate).await;
+        Ok(())
+    }
+
     pub async fn get_all_filters(&self) -> FlowyResult<Vec<Arc<FilterRevision>>> {
         let view_editor = self.get_default_view_editor().await?;
         Ok(view_editor.get_all_view_filters().await)
@@ -148,12 +227,23 @@ impl GridViewManager {
         view_editor.delete_view_sort(params).await
     }
 
+    pub async fn reset_view_settings(&self, view_id: &str) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.reset_view_settings().await
+    }
+
     pub async fn load_groups(&self) -> FlowyResult<RepeatedGroupPB> {
         let view_editor = self.get_default_view_editor().await?;
         let groups = view_editor.load_view_groups().await?;
         Ok(RepeatedGroupPB { items: groups })
     }
 
+    pub async fn load_groups_of_view(&self, view_id: &str) -> FlowyResult<RepeatedGroupPB> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        let groups = view_editor.load_view_groups().await?;
+        Ok(RepeatedGroupPB { items: groups })
+    }
+
     pub async fn insert_or_update_group(&self, params: InsertGroupParams) -> FlowyResult<()> {
         let view_editor = self.get_default_view_editor().await?;
         view_editor.initialize_new_group(params).await
@@ -170,6 +260,20 @@ impl GridViewManager {
         Ok(())
     }
 
+    pub async fn update_group_hide_empty(&self, hide_empty: bool) -> FlowyResult<()> {
+        let view_editor = self.get_default_view_editor().await?;
+        let _ = view_editor.update_view_group_hide_empty(hide_empty).await?;
+        Ok(())
+    }
+
+    pub async fn update_date_group_condition(&self, field_id: &str, condition: DateCondition) -> FlowyResult<()> {
+        let view_editor = self.get_default_view_editor().await?;
+        let _ = view_editor
+            .update_view_date_group_condition(field_id, condition)
+            .await?;
+        Ok(())
+    }
+
     /// It may generate a RowChangeset when the Row was moved from one group to another.
     /// The return value, [RowChangeset], contains the changes made by the groups.
     ///
@@ -212,7 +316,7 @@ impl GridViewManager {
     ) -> FlowyResult<()> {
         let view_editor = self.get_default_view_editor().await?;
         if view_editor.group_id().await == field_id {
-            let _ = view_editor.group_by_view_field(field_id).await?;
+            let _ = view_editor.did_update_group_field(field_id).await?;
         }
 
         let _ = view_editor
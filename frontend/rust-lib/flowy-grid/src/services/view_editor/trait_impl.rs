@@ -14,7 +14,8 @@ use flowy_revision::{
 use flowy_sync::client_grid::{GridViewRevisionChangeset, GridViewRevisionPad};
 use flowy_sync::util::make_operations_from_revisions;
 use grid_rev_model::{
-    FieldRevision, FieldTypeRevision, FilterRevision, GroupConfigurationRevision, RowRevision, SortRevision,
+    FieldRevision, FieldTypeRevision, FilterConjunctionRevision, FilterRevision, GroupConfigurationRevision,
+    RowRevision, SortRevision,
 };
 use lib_infra::future::{to_fut, Fut, FutureResult};
 use lib_ot::core::EmptyAttributes;
@@ -106,6 +107,28 @@ impl GroupConfigurationWriter for GroupConfigurationWriterImpl {
     }
 }
 
+/// Generates the groups without touching the view. The reader returns the passed-in
+/// configuration and the writer drops the configurations that the group controller saves.
+pub(crate) struct DetachedGroupConfiguration(pub(crate) Arc<GroupConfigurationRevision>);
+
+impl GroupConfigurationReader for DetachedGroupConfiguration {
+    fn get_configuration(&self) -> Fut<Option<Arc<GroupConfigurationRevision>>> {
+        let configuration = self.0.clone();
+        to_fut(async move { Some(configuration) })
+    }
+}
+
+impl GroupConfigurationWriter for DetachedGroupConfiguration {
+    fn save_configuration(
+        &self,
+        _field_id: &str,
+        _field_type: FieldTypeRevision,
+        _group_configuration: GroupConfigurationRevision,
+    ) -> Fut<FlowyResult<()>> {
+        to_fut(async move { Ok(()) })
+    }
+}
+
 pub(crate) async fn apply_change(
     _user_id: &str,
     rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
@@ -126,6 +149,8 @@ pub fn make_grid_setting(view_pad: &GridViewRevisionPad, field_revs: &[Arc<Field
         layout_type,
         filters: filter_configurations.into(),
         group_configurations: group_configurations.into(),
+        row_height: view_pad.row_height.into(),
+        filter_conjunction: view_pad.filter_conjunction.into(),
     }
 }
 
@@ -164,6 +189,11 @@ impl FilterDelegate for GridViewFilterDelegateImpl {
     fn get_row_rev(&self, row_id: &str) -> Fut<Option<(usize, Arc<RowRevision>)>> {
         self.editor_delegate.get_row_rev(row_id)
     }
+
+    fn get_filter_conjunction(&self) -> Fut<FilterConjunctionRevision> {
+        let pad = self.view_revision_pad.clone();
+        to_fut(async move { pad.read().await.filter_conjunction })
+    }
 }
 
 pub(crate) struct GridViewSortDelegateImpl {
@@ -1,9 +1,18 @@
 use crate::grid::block_test::script::RowScript::*;
 use crate::grid::block_test::script::{CreateRowScriptBuilder, GridRowTest};
 use crate::grid::grid_editor::{COMPLETED, FACEBOOK, GOOGLE, PAUSED, TWITTER};
-use flowy_grid::entities::FieldType;
-use flowy_grid::services::field::{SELECTION_IDS_SEPARATOR, UNCHECK};
-use grid_rev_model::RowChangeset;
+use bytes::Bytes;
+use flowy_grid::entities::{CellPathParams, FieldType, MoveFieldParams, MoveRowParams, RowHeightPB};
+use flowy_grid::services::cell::TypeCellData;
+use flowy_grid::services::field::{
+    FieldBuilder, NumberTypeOptionBuilder, RichTextTypeOptionBuilder, SelectOptionPB, SingleSelectTypeOptionBuilder,
+    SELECTION_IDS_SEPARATOR, UNCHECK,
+};
+use flowy_grid::services::grid_event::GridChangeEvent;
+use flowy_grid::services::row::NormalizeGridSummary;
+use flowy_sync::client_grid::GridBuilder;
+use flowy_test::helper::ViewTest;
+use grid_rev_model::{CellRevision, RowChangeset};
 
 #[tokio::test]
 async fn grid_create_row_count_test() {
@@ -28,7 +37,10 @@ async fn grid_update_row() {
         row_id: row_rev.id.clone(),
         height: None,
         visibility: None,
+        archived: None,
+        last_edited_by: None,
         cell_by_field_id: Default::default(),
+        deleted_field_ids: vec![],
     };
     let row_count = test.row_revs.len();
     let scripts = vec![CreateRow { row_rev }, UpdateRow { changeset }];
@@ -65,6 +77,130 @@ async fn grid_delete_row() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_cached_row_count_test() {
+    let mut test = GridRowTest::new().await;
+    let row_1 = test.row_builder().build();
+    let row_2 = test.row_builder().build();
+    let row_3 = test.row_builder().build();
+    let row_ids = vec![row_1.id.clone(), row_2.id.clone()];
+    let row_3_id = row_3.id.clone();
+    let row_count = test.row_revs.len();
+    let scripts = vec![
+        AssertCachedRowCount(row_count),
+        CreateRow { row_rev: row_1 },
+        CreateRow { row_rev: row_2 },
+        CreateRow { row_rev: row_3 },
+        AssertCachedRowCount(row_count + 3),
+        DeleteRows { row_ids },
+        AssertCachedRowCount(row_count + 1),
+        DuplicateRow { row_id: row_3_id },
+        AssertCachedRowCount(row_count + 2),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_event_sequence_test() {
+    let test = GridRowTest::new().await;
+    let mut receiver = test.editor.subscribe_grid_event();
+
+    let field_rev = test.editor.next_field_rev(&FieldType::RichText).await.unwrap();
+    let field_id = field_rev.id.clone();
+    test.editor.create_new_field_rev(field_rev).await.unwrap();
+    let row_id = test.row_revs[0].id.clone();
+    test.editor.delete_row(&row_id).await.unwrap();
+
+    assert_eq!(receiver.try_recv().unwrap(), GridChangeEvent::FieldCreated { field_id });
+    assert_eq!(receiver.try_recv().unwrap(), GridChangeEvent::RowDeleted { row_id });
+    assert!(receiver.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn grid_event_of_moves_and_bulk_edits_test() {
+    let test = GridRowTest::new().await;
+    let mut receiver = test.editor.subscribe_grid_event();
+    let row_id = test.row_revs[0].id.clone();
+    let params = MoveRowParams {
+        view_id: test.view_id.clone(),
+        from_row_id: row_id.clone(),
+        to_row_id: test.row_revs[1].id.clone(),
+    };
+    test.editor.move_row(params).await.unwrap();
+    assert_eq!(
+        receiver.try_recv().unwrap(),
+        GridChangeEvent::RowMoved { row_id: row_id.clone() }
+    );
+
+    let field_id = test.field_revs[1].id.clone();
+    let params = MoveFieldParams {
+        grid_id: test.editor.grid_id.clone(),
+        field_id: field_id.clone(),
+        from_index: 1,
+        to_index: 2,
+    };
+    test.editor.move_field(params).await.unwrap();
+    assert_eq!(receiver.try_recv().unwrap(), GridChangeEvent::FieldMoved { field_id });
+
+    test.editor.archive_row(&row_id).await.unwrap();
+    assert_eq!(receiver.try_recv().unwrap(), GridChangeEvent::RowUpdated { row_id });
+
+    // Every updated cell is sent
+    let field_id = test.get_first_field_rev(FieldType::Checkbox).id.clone();
+    let count = test
+        .editor
+        .set_all_checkbox_cells(&test.view_id, &field_id, true)
+        .await
+        .unwrap();
+    let mut changed_cells = 0;
+    while let Ok(event) = receiver.try_recv() {
+        if let GridChangeEvent::CellChanged {
+            field_id: changed_field_id,
+            ..
+        } = event
+        {
+            assert_eq!(changed_field_id, field_id);
+            changed_cells += 1;
+        }
+    }
+    assert_eq!(changed_cells, count);
+}
+
+#[tokio::test]
+async fn grid_archive_row_test() {
+    let mut test = GridRowTest::new().await;
+    let row_count = test.row_revs.len();
+    let row_id = test.row_revs[0].id.clone();
+    // The first row is checked, so the archived row is excluded from the checked count
+    let scripts = vec![
+        AssertCheckboxCount {
+            checked: 3,
+            unchecked: 3,
+        },
+        ArchiveRow { row_id: row_id.clone() },
+        AssertRowCount(row_count - 1),
+        AssertCheckboxCount {
+            checked: 2,
+            unchecked: 3,
+        },
+        ShowArchivedRows(true),
+        AssertRowCount(row_count),
+        AssertCheckboxCount {
+            checked: 3,
+            unchecked: 3,
+        },
+        ShowArchivedRows(false),
+        AssertRowCount(row_count - 1),
+        UnarchiveRow { row_id },
+        AssertRowCount(row_count),
+        AssertCheckboxCount {
+            checked: 3,
+            unchecked: 3,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn grid_row_add_cells_test() {
     let mut test = GridRowTest::new().await;
@@ -133,3 +269,142 @@ async fn grid_row_insert_multi_select_test() {
     let scripts = builder.build();
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_normalize_dangling_option_id_test() {
+    let mut test = GridRowTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    let google = test.get_multi_select_type_option(&field_id).remove(0);
+    let row_id = test.row_revs[0].id.clone();
+
+    // Seed the cell with the id of an option that doesn't exist
+    let cell_str = vec![google.id.clone(), "dangling".to_owned()].join(SELECTION_IDS_SEPARATOR);
+    let mut changeset = RowChangeset::new(row_id.clone());
+    changeset.cell_by_field_id.insert(
+        field_id.clone(),
+        CellRevision::new(TypeCellData::new(cell_str, FieldType::MultiSelect).to_json()),
+    );
+
+    let scripts = vec![
+        UpdateRow { changeset },
+        NormalizeGrid {
+            expected: NormalizeGridSummary {
+                pruned_option_ids: 1,
+                ..Default::default()
+            },
+        },
+        AssertCell {
+            row_id,
+            field_id,
+            field_type: FieldType::MultiSelect,
+            expected: GOOGLE.to_owned(),
+        },
+        // The grid is consistent after the normalization
+        NormalizeGrid {
+            expected: NormalizeGridSummary::default(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_normalize_archived_row_test() {
+    let mut test = GridRowTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    let row_id = test.row_revs[0].id.clone();
+
+    // The archived row is hidden from the view, but it's normalized too
+    let cell_str = "dangling".to_owned();
+    let mut changeset = RowChangeset::new(row_id.clone());
+    changeset.cell_by_field_id.insert(
+        field_id,
+        CellRevision::new(TypeCellData::new(cell_str, FieldType::MultiSelect).to_json()),
+    );
+    let scripts = vec![
+        UpdateRow { changeset },
+        ArchiveRow { row_id },
+        NormalizeGrid {
+            expected: NormalizeGridSummary {
+                pruned_option_ids: 1,
+                ..Default::default()
+            },
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_update_view_row_height_test() {
+    let mut test = GridRowTest::new().await;
+    let scripts = vec![
+        AssertRowHeight(RowHeightPB::Medium),
+        UpdateRowHeight(RowHeightPB::Tall),
+        AssertRowHeight(RowHeightPB::Tall),
+        UpdateRowHeight(RowHeightPB::Compact),
+        AssertRowHeight(RowHeightPB::Compact),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_row_and_field_exists_test() {
+    let test = GridRowTest::new().await;
+    let row_id = test.row_revs[0].id.clone();
+    let field_id = test.field_revs[0].id.clone();
+    assert!(test.editor.row_exists(&row_id).await);
+    assert!(test.editor.contain_field(&field_id).await);
+
+    assert!(!test.editor.row_exists("not_a_row_id").await);
+    assert!(!test.editor.contain_field("not_a_field_id").await);
+}
+
+#[tokio::test]
+async fn grid_clone_row_to_another_grid_test() {
+    let test = GridRowTest::new().await;
+    // The second grid shares the names of some fields with the mock grid
+    let mut grid_builder = GridBuilder::new();
+    grid_builder.add_field(
+        FieldBuilder::new(RichTextTypeOptionBuilder::default())
+            .name("Name")
+            .build(),
+    );
+    grid_builder.add_field(
+        FieldBuilder::new(RichTextTypeOptionBuilder::default())
+            .name("Price")
+            .build(),
+    );
+    let single_select = SingleSelectTypeOptionBuilder::default()
+        .add_option(SelectOptionPB::new(PAUSED))
+        .add_option(SelectOptionPB::new(COMPLETED));
+    grid_builder.add_field(FieldBuilder::new(single_select).name("Status").build());
+    grid_builder.add_field(
+        FieldBuilder::new(NumberTypeOptionBuilder::default())
+            .name("Notes")
+            .build(),
+    );
+    let view_data: Bytes = grid_builder.build().into();
+    let target_view_id = ViewTest::new_grid_view(&test.sdk, view_data.to_vec()).await.view.id;
+
+    // The third mock row is "C", "$3" and "Completed"
+    let row_id = test.row_revs[2].id.clone();
+    let row_pb = test
+        .sdk
+        .grid_manager
+        .clone_row_to_grid(&test.view_id, &row_id, &target_view_id)
+        .await
+        .unwrap();
+    assert_ne!(row_pb.id, row_id);
+
+    let target_editor = test.sdk.grid_manager.open_grid(&target_view_id).await.unwrap();
+    let row_revs = target_editor.get_all_row_revs(&target_view_id).await.unwrap();
+    assert_eq!(row_revs.len(), 1);
+    for (name, expected) in [("Name", "C"), ("Price", "$3"), ("Status", COMPLETED), ("Notes", "")] {
+        let field_rev = target_editor.field_by_name(name).await.unwrap();
+        let params = CellPathParams {
+            view_id: target_view_id.clone(),
+            field_id: field_rev.id.clone(),
+            row_id: row_pb.id.clone(),
+        };
+        assert_eq!(target_editor.get_cell_display_str(&params).await, expected);
+    }
+}
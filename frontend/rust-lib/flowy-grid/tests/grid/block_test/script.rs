@@ -1,9 +1,9 @@
 use crate::grid::block_test::script::RowScript::{AssertCell, CreateRow};
 use crate::grid::block_test::util::GridRowTestBuilder;
 use crate::grid::grid_editor::GridEditorTest;
-use flowy_grid::entities::{CellPathParams, CreateRowParams, FieldType, GridLayout, RowPB};
+use flowy_grid::entities::{CellPathParams, CreateRowParams, FieldType, GridLayout, RowHeightPB, RowPB};
 use flowy_grid::services::field::*;
-use flowy_grid::services::row::GridBlockRow;
+use flowy_grid::services::row::{GridBlockRow, NormalizeGridSummary};
 use grid_rev_model::{GridBlockMetaRevision, GridBlockMetaRevisionChangeset, RowChangeset, RowRevision};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -23,6 +23,18 @@ pub enum RowScript {
     DeleteRows {
         row_ids: Vec<String>,
     },
+    DuplicateRow {
+        row_id: String,
+    },
+    ArchiveRow {
+        row_id: String,
+    },
+    UnarchiveRow {
+        row_id: String,
+    },
+    ShowArchivedRows(bool),
+    UpdateRowHeight(RowHeightPB),
+    AssertRowHeight(RowHeightPB),
     AssertCell {
         row_id: String,
         field_id: String,
@@ -30,6 +42,15 @@ pub enum RowScript {
         expected: String,
     },
     AssertRowCount(usize),
+    AssertCheckboxCount {
+        checked: usize,
+        unchecked: usize,
+    },
+    /// Asserts the row count that is cached in the grid matches the number of the rows.
+    AssertCachedRowCount(usize),
+    NormalizeGrid {
+        expected: NormalizeGridSummary,
+    },
     CreateBlock {
         block: GridBlockMetaRevision,
     },
@@ -106,6 +127,37 @@ impl GridRowTest {
                 self.row_revs = self.get_row_revs().await;
                 self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
             }
+            RowScript::DuplicateRow { row_id } => {
+                let row_pb = self.editor.duplicate_row(&row_id).await.unwrap();
+                self.row_by_row_id.insert(row_pb.row_id().to_owned(), row_pb);
+                self.row_revs = self.get_row_revs().await;
+                self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
+            }
+            RowScript::ArchiveRow { row_id } => {
+                self.editor.archive_row(&row_id).await.unwrap();
+                self.row_revs = self.get_row_revs().await;
+            }
+            RowScript::UnarchiveRow { row_id } => {
+                self.editor.unarchive_row(&row_id).await.unwrap();
+                self.row_revs = self.get_row_revs().await;
+            }
+            RowScript::ShowArchivedRows(show_archived_rows) => {
+                self.editor
+                    .update_view_show_archived_rows(&self.view_id, show_archived_rows)
+                    .await
+                    .unwrap();
+                self.row_revs = self.get_row_revs().await;
+            }
+            RowScript::UpdateRowHeight(row_height) => {
+                self.editor
+                    .update_view_row_height(&self.view_id, row_height)
+                    .await
+                    .unwrap();
+            }
+            RowScript::AssertRowHeight(expected) => {
+                let setting = self.editor.get_view_setting(&self.view_id).await.unwrap();
+                assert_eq!(setting.row_height, expected);
+            }
             RowScript::AssertCell {
                 row_id,
                 field_id,
@@ -131,6 +183,25 @@ impl GridRowTest {
             RowScript::AssertRowCount(expected_row_count) => {
                 assert_eq!(expected_row_count, self.row_revs.len());
             }
+            RowScript::AssertCheckboxCount { checked, unchecked } => {
+                let field_id = self.get_first_field_rev(FieldType::Checkbox).id.clone();
+                let count = self
+                    .editor
+                    .count_checkbox_cells(&self.view_id, &field_id)
+                    .await
+                    .unwrap();
+                assert_eq!(count.checked, checked);
+                assert_eq!(count.unchecked, unchecked);
+            }
+            RowScript::AssertCachedRowCount(expected_row_count) => {
+                assert_eq!(self.editor.row_count().await, expected_row_count);
+                assert_eq!(self.editor.row_count().await, self.row_revs.len());
+            }
+            RowScript::NormalizeGrid { expected } => {
+                let summary = self.editor.normalize_grid().await.unwrap();
+                assert_eq!(summary, expected);
+                self.row_revs = self.get_row_revs().await;
+            }
             RowScript::CreateBlock { block } => {
                 self.editor.create_block(block).await.unwrap();
                 self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
@@ -265,6 +336,17 @@ impl GridRowTest {
                 assert_eq!(cell_data.content, expected);
                 // assert_eq!(cell_data.url, expected);
             }
+            FieldType::Formula | FieldType::CreatedBy | FieldType::LastEditedBy => {
+                let cell_data = self
+                    .editor
+                    .get_cell_bytes(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<TextCellDataParser>()
+                    .unwrap();
+
+                assert_eq!(cell_data.as_ref(), &expected);
+            }
         }
     }
 }
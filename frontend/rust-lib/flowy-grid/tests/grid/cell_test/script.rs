@@ -1,8 +1,109 @@
 use crate::grid::grid_editor::GridEditorTest;
-use flowy_grid::entities::CellChangesetPB;
+use flowy_grid::entities::{
+    CalculationOptions, CalculationTypePB, CellChangesetPB, CellPathParams, CreateRowParams, FieldType, GridLayout,
+};
+use flowy_grid::services::cell::CellFormatter;
+use flowy_grid::services::export::ExportOptions;
+use flowy_grid::services::field::{select_type_option_from_field_rev, CheckboxAggregation, FormulaTypeOptionPB};
+use grid_rev_model::{RowRevision, TypeOptionDataSerializer};
+use std::sync::Arc;
 
 pub enum CellScript {
-    UpdateCell { changeset: CellChangesetPB, is_err: bool },
+    UpdateCell {
+        changeset: CellChangesetPB,
+        is_err: bool,
+    },
+    ValidateCellInput {
+        field_id: String,
+        input: String,
+        is_err: bool,
+    },
+    AssertCheckboxAggregation {
+        field_id: String,
+        expected: CheckboxAggregation,
+    },
+    AssertSum {
+        field_id: String,
+        expected: String,
+    },
+    AssertCalculation {
+        field_id: String,
+        calculation_type: CalculationTypePB,
+        empty_as_zero: bool,
+        expected: String,
+    },
+    RegisterCellFormatter {
+        field_type: FieldType,
+        formatter: Arc<dyn CellFormatter>,
+    },
+    AssertCellDisplayStr {
+        field_id: String,
+        row_index: usize,
+        expected: String,
+    },
+    UpdateFormula {
+        field_id: String,
+        expression: String,
+    },
+    AssertExportFooter {
+        field_id: String,
+        expected: String,
+    },
+    PasteCells {
+        anchor_row_index: usize,
+        anchor_field_id: String,
+        tsv: String,
+    },
+    AssertMatrixSize {
+        number_of_rows: usize,
+        number_of_fields: usize,
+    },
+    AssertMatrixCell {
+        field_id: String,
+        row_index: usize,
+        expected: String,
+    },
+    AssertCellValue {
+        field_id: String,
+        row_index: usize,
+        raw_value: Option<String>,
+        display_value: String,
+    },
+    AssertRowDisplayTitle {
+        row_index: usize,
+        expected: String,
+    },
+    AppendToCell {
+        field_id: String,
+        row_index: usize,
+        value: String,
+        is_err: bool,
+    },
+    CreateEmptyRow,
+    /// Inserts a row that was created and last edited by another user, e.g. an imported row.
+    InsertRowByUser {
+        user_id: String,
+    },
+    SetCellFromStr {
+        field_id: String,
+        row_index: usize,
+        display_str: String,
+        is_err: bool,
+    },
+    AssertSelectOptionsByUsage {
+        field_id: String,
+        persist: bool,
+        expected: Vec<&'static str>,
+    },
+    AssertStoredSelectOptions {
+        field_id: String,
+        expected: Vec<&'static str>,
+    },
+    CopyCell {
+        field_id: String,
+        from_row_index: usize,
+        to_row_index: usize,
+    },
 }
 
 pub struct GridCellTest {
@@ -38,6 +139,221 @@ impl GridCellTest {
                 } else {
                     let _ = result.unwrap();
                 }
+            }
+            CellScript::ValidateCellInput {
+                field_id,
+                input,
+                is_err,
+            } => {
+                let result = self.editor.validate_cell_input(&field_id, input).await;
+                assert_eq!(is_err, result.is_err());
+            }
+            CellScript::AssertCheckboxAggregation { field_id, expected } => {
+                let aggregation = self
+                    .editor
+                    .get_checkbox_aggregation(&self.view_id, &field_id)
+                    .await
+                    .unwrap();
+                assert_eq!(expected, aggregation);
+            }
+            CellScript::AssertSum { field_id, expected } => {
+                let calculation = self.editor.calculate_sum(&self.view_id, &field_id).await.unwrap();
+                assert_eq!(calculation.field_id, field_id);
+                assert_eq!(calculation.value, expected);
+            }
+            CellScript::AssertCalculation {
+                field_id,
+                calculation_type,
+                empty_as_zero,
+                expected,
+            } => {
+                let calculation = self
+                    .editor
+                    .calculate(
+                        &self.view_id,
+                        &field_id,
+                        calculation_type,
+                        CalculationOptions {
+                            empty_as_zero,
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(calculation.value, expected);
+            }
+            CellScript::RegisterCellFormatter { field_type, formatter } => {
+                self.editor.register_cell_formatter(field_type, formatter);
+            }
+            CellScript::AssertCellDisplayStr {
+                field_id,
+                row_index,
+                expected,
+            } => {
+                let params = CellPathParams {
+                    view_id: self.view_id.clone(),
+                    field_id,
+                    row_id: self.row_revs[row_index].id.clone(),
+                };
+                let display_str = self.editor.get_cell_display_str(&params).await;
+                assert_eq!(display_str, expected);
+            }
+            CellScript::UpdateFormula { field_id, expression } => {
+                let type_option_data = FormulaTypeOptionPB { expression }.protobuf_bytes().to_vec();
+                self.editor
+                    .update_field_type_option(&self.view_id, &field_id, type_option_data, None)
+                    .await
+                    .unwrap();
+            }
+            CellScript::PasteCells {
+                anchor_row_index,
+                anchor_field_id,
+                tsv,
+            } => {
+                let row_revs = self.editor.get_all_row_revs(&self.view_id).await.unwrap();
+                let anchor_row_id = row_revs[anchor_row_index].id.clone();
+                self.editor
+                    .paste_cells(&self.view_id, &anchor_row_id, &anchor_field_id, &tsv)
+                    .await
+                    .unwrap();
+            }
+            CellScript::AssertMatrixSize {
+                number_of_rows,
+                number_of_fields,
+            } => {
+                let matrix = self.editor.grid_as_matrix(&self.view_id).await.unwrap();
+                assert_eq!(matrix.len(), number_of_rows);
+                for row in matrix.iter() {
+                    assert_eq!(row.len(), number_of_fields);
+                }
+            }
+            CellScript::AssertMatrixCell {
+                field_id,
+                row_index,
+                expected,
+            } => {
+                let matrix = self.editor.grid_as_matrix(&self.view_id).await.unwrap();
+                let field_revs = self.editor.get_view_field_revs(&self.view_id).await.unwrap();
+                let index = field_revs
+                    .iter()
+                    .position(|field_rev| field_rev.id == field_id)
+                    .unwrap();
+                assert_eq!(matrix[row_index][index], expected);
+            }
+            CellScript::AssertExportFooter { field_id, expected } => {
+                let options = ExportOptions {
+                    include_aggregation_footer: true,
+                };
+                let csv = self.editor.export_csv(&self.view_id, options).await.unwrap();
+                let records = csv::ReaderBuilder::new()
+                    .has_headers(false)
+                    .from_reader(csv.as_bytes())
+                    .records()
+                    .map(|record| record.unwrap())
+                    .collect::<Vec<csv::StringRecord>>();
+                // The header line, the lines of the rows and the footer line
+                assert_eq!(records.len(), self.row_revs.len() + 2);
+
+                let field_revs = self.editor.get_view_field_revs(&self.view_id).await.unwrap();
+                let index = field_revs
+                    .iter()
+                    .position(|field_rev| field_rev.id == field_id)
+                    .unwrap();
+                let footer = records.last().unwrap();
+                assert_eq!(footer.len(), field_revs.len());
+                assert_eq!(&footer[index], expected);
+            }
+            CellScript::AssertCellValue {
+                field_id,
+                row_index,
+                raw_value,
+                display_value,
+            } => {
+                let params = CellPathParams {
+                    view_id: self.view_id.clone(),
+                    field_id,
+                    row_id: self.row_revs[row_index].id.clone(),
+                };
+                assert_eq!(self.editor.cell_raw_value(&params).await, raw_value);
+                assert_eq!(self.editor.cell_display_value(&params).await, display_value);
+            }
+            CellScript::AssertRowDisplayTitle { row_index, expected } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let title = self.editor.row_display_title(&self.view_id, &row_id).await.unwrap();
+                assert_eq!(title, expected);
+            }
+            CellScript::AppendToCell {
+                field_id,
+                row_index,
+                value,
+                is_err,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let result = self.editor.append_to_cell(&row_id, &field_id, &value).await;
+                assert_eq!(is_err, result.is_err());
+            }
+            CellScript::CreateEmptyRow => {
+                let params = CreateRowParams {
+                    grid_id: self.editor.grid_id.clone(),
+                    start_row_id: None,
+                    group_id: None,
+                    layout: GridLayout::Table,
+                };
+                let _ = self.editor.create_row(params).await.unwrap();
+                self.row_revs = self.get_row_revs().await;
+            }
+            CellScript::InsertRowByUser { user_id } => {
+                let mut row_rev = RowRevision::new(&self.block_meta_revs[0].block_id);
+                row_rev.created_by = Some(user_id.clone());
+                row_rev.last_edited_by = Some(user_id);
+                let _ = self.editor.insert_rows(vec![row_rev]).await.unwrap();
+                self.row_revs = self.get_row_revs().await;
+            }
+            CellScript::SetCellFromStr {
+                field_id,
+                row_index,
+                display_str,
+                is_err,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let result = self.editor.set_cell_from_str(&row_id, &field_id, &display_str).await;
+                assert_eq!(is_err, result.is_err());
+            }
+            CellScript::AssertSelectOptionsByUsage {
+                field_id,
+                persist,
+                expected,
+            } => {
+                let options = self
+                    .editor
+                    .select_options_by_usage(&self.view_id, &field_id, persist)
+                    .await
+                    .unwrap();
+                let names = options.iter().map(|option| option.name.as_str()).collect::<Vec<&str>>();
+                assert_eq!(names, expected);
+            }
+            CellScript::CopyCell {
+                field_id,
+                from_row_index,
+                to_row_index,
+            } => {
+                let from_row_id = self.row_revs[from_row_index].id.clone();
+                let to_row_id = self.row_revs[to_row_index].id.clone();
+                self.editor
+                    .copy_cell(&from_row_id, &to_row_id, &field_id)
+                    .await
+                    .unwrap();
+                self.row_revs = self.get_row_revs().await;
+            }
+            CellScript::AssertStoredSelectOptions { field_id, expected } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                let type_option = select_type_option_from_field_rev(&field_rev).unwrap();
+                let names = type_option
+                    .options()
+                    .iter()
+                    .map(|option| option.name.as_str())
+                    .collect::<Vec<&str>>();
+                assert_eq!(names, expected);
             } // CellScript::AssertGridRevisionPad => {
               //     sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
               //     let mut grid_rev_manager = grid_manager.make_grid_rev_manager(&self.grid_id, pool.clone()).unwrap();
@@ -1,10 +1,25 @@
 use crate::grid::cell_test::script::CellScript::*;
 use crate::grid::cell_test::script::GridCellTest;
 use crate::grid::field_test::util::make_date_cell_string;
-use flowy_grid::entities::{CellChangesetPB, FieldType};
-use flowy_grid::services::cell::ToCellChangesetString;
+use crate::grid::grid_editor::{COMPLETED, FACEBOOK, GOOGLE, PAUSED, PLANNED, TWITTER};
+use bytes::Bytes;
+use flowy_error::ErrorCode;
+use flowy_grid::entities::{
+    CalculationOptions, CalculationTypePB, CellChangesetPB, CellPathParams, FieldChangesetParams, FieldType,
+};
+use flowy_grid::services::cell::{CellFormatter, ToCellChangesetString, TypeCellData, UNKNOWN_FIELD_TYPE_PLACEHOLDER};
 use flowy_grid::services::field::selection_type_option::SelectOptionCellChangeset;
-use flowy_grid::services::field::{ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SingleSelectTypeOptionPB};
+use flowy_grid::services::field::{
+    CheckboxAggregation, ChecklistTypeOptionPB, FieldBuilder, FormulaTypeOptionPB, MultiSelectTypeOptionPB,
+    NumberFormat, NumberLocale, NumberTypeOptionPB, SelectOptionIds, SelectOptionPB, SingleSelectTypeOptionBuilder,
+    SingleSelectTypeOptionPB,
+};
+use flowy_grid::services::grid_event::GridChangeEvent;
+use flowy_revision::REVISION_WRITE_INTERVAL_IN_MILLIS;
+use grid_rev_model::{CellRevision, FieldRevision, RowChangeset, RowRevision, TypeOptionDataSerializer};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
 
 #[tokio::test]
 async fn grid_cell_update() {
@@ -41,6 +56,8 @@ async fn grid_cell_update() {
                 }
                 FieldType::Checkbox => "1".to_string(),
                 FieldType::URL => "1".to_string(),
+                FieldType::Formula => "1".to_string(),
+                FieldType::CreatedBy | FieldType::LastEditedBy => "1".to_string(),
             };
 
             scripts.push(UpdateCell {
@@ -50,10 +67,1270 @@ async fn grid_cell_update() {
                     field_id: field_rev.id.clone(),
                     type_cell_data: data,
                 },
-                is_err: false,
+                // The formula and the user cells are computed, they can't be edited
+                is_err: field_type.is_formula() || field_type.is_user(),
             });
         }
     }
 
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_cell_validate_number_input_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let row_id = test.row_revs.first().unwrap().id.clone();
+    let cell_rev = test.editor.get_cell_rev(&row_id, &field_id).await.unwrap();
+    let scripts = vec![
+        ValidateCellInput {
+            field_id: field_id.clone(),
+            input: "abc".to_string(),
+            is_err: true,
+        },
+        ValidateCellInput {
+            field_id: field_id.clone(),
+            input: "123".to_string(),
+            is_err: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    // The validation doesn't write the cell
+    let unchanged_cell_rev = test.editor.get_cell_rev(&row_id, &field_id).await.unwrap();
+    assert_eq!(
+        cell_rev.map(|cell_rev| cell_rev.type_cell_data),
+        unchanged_cell_rev.map(|cell_rev| cell_rev.type_cell_data)
+    );
+
+    // The imported cells are checked with the same validation
+    assert!(test.editor.cell_is_valid(&field_id, Bytes::from("123")).await);
+    assert!(!test.editor.cell_is_valid(&field_id, Bytes::from("abc")).await);
+    assert!(!test.editor.cell_is_valid("unknown field", Bytes::from("123")).await);
+
+    // The number field without a currency rejects the input that is not a number too
+    let field_rev = test.editor.get_field_rev(&field_id).await.unwrap();
+    let mut type_option = NumberTypeOptionPB::from(&field_rev);
+    type_option.format = NumberFormat::Num;
+    test.editor
+        .update_field_type_option(&test.view_id, &field_id, type_option.protobuf_bytes().to_vec(), None)
+        .await
+        .unwrap();
+    test.run_scripts(vec![
+        ValidateCellInput {
+            field_id: field_id.clone(),
+            input: "abc".to_string(),
+            is_err: true,
+        },
+        ValidateCellInput {
+            field_id: field_id.clone(),
+            input: "1.5".to_string(),
+            is_err: false,
+        },
+        ValidateCellInput {
+            field_id: field_id.clone(),
+            input: "".to_string(),
+            is_err: false,
+        },
+    ])
+    .await;
+    assert!(!test.editor.cell_is_valid(&field_id, Bytes::from("abc")).await);
+}
+
+#[tokio::test]
+async fn grid_cell_checkbox_aggregation_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Checkbox).id.clone();
+    test.run_scripts(vec![AssertCheckboxAggregation {
+        field_id: field_id.clone(),
+        expected: CheckboxAggregation::Mixed,
+    }])
+    .await;
+
+    let mut scripts = test
+        .row_revs
+        .iter()
+        .map(|row_rev| UpdateCell {
+            changeset: CellChangesetPB {
+                grid_id: test.view_id.clone(),
+                row_id: row_rev.id.clone(),
+                field_id: field_id.clone(),
+                type_cell_data: "1".to_string(),
+            },
+            is_err: false,
+        })
+        .collect::<Vec<_>>();
+    scripts.push(AssertCheckboxAggregation {
+        field_id,
+        expected: CheckboxAggregation::AllChecked,
+    });
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_sum_currency_field_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    test.run_scripts(vec![AssertSum {
+        field_id,
+        // The cells of the USD number field are 1, 2, 3, 4, empty and 5
+        expected: "$15.00".to_owned(),
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_average_with_empty_cell_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    // The cells of the USD number field are 1, 2, 3, 4, empty and 5
+    test.run_scripts(vec![
+        AssertCalculation {
+            field_id: field_id.clone(),
+            calculation_type: CalculationTypePB::Average,
+            empty_as_zero: false,
+            expected: "$3.00".to_owned(),
+        },
+        AssertCalculation {
+            field_id: field_id.clone(),
+            calculation_type: CalculationTypePB::Average,
+            empty_as_zero: true,
+            expected: "$2.50".to_owned(),
+        },
+        // The empty cells don't change the sum
+        AssertCalculation {
+            field_id,
+            calculation_type: CalculationTypePB::Sum,
+            empty_as_zero: true,
+            expected: "$15.00".to_owned(),
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_earliest_and_latest_date_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+    // The date of the new row is empty, which is skipped
+    test.run_scripts(vec![
+        CreateEmptyRow,
+        AssertCalculation {
+            field_id: field_id.clone(),
+            calculation_type: CalculationTypePB::Earliest,
+            empty_as_zero: false,
+            expected: "2022/03/14".to_owned(),
+        },
+        AssertCalculation {
+            field_id,
+            calculation_type: CalculationTypePB::Latest,
+            empty_as_zero: false,
+            expected: "2022/12/25".to_owned(),
+        },
+    ])
+    .await;
+
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let result = test
+        .editor
+        .calculate(
+            &test.view_id,
+            &number_field_id,
+            CalculationTypePB::Earliest,
+            CalculationOptions::default(),
+        )
+        .await;
+    assert_eq!(result.unwrap_err().code, ErrorCode::FieldTypeMismatch.value());
+}
+
+#[tokio::test]
+async fn grid_cell_export_with_aggregation_footer_test() {
+    let mut test = GridCellTest::new().await;
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let checkbox_field_id = test.get_first_field_rev(FieldType::Checkbox).id.clone();
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let scripts = vec![
+        AssertExportFooter {
+            field_id: number_field_id,
+            expected: "$15.00".to_owned(),
+        },
+        AssertExportFooter {
+            field_id: checkbox_field_id,
+            expected: "3 checked / 3 unchecked".to_owned(),
+        },
+        AssertExportFooter {
+            field_id: text_field_id,
+            expected: "".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_matrix_test() {
+    let mut test = GridCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        AssertMatrixSize {
+            number_of_rows: test.row_revs.len(),
+            number_of_fields: test.field_revs.len(),
+        },
+        AssertMatrixCell {
+            field_id: text_field_id.clone(),
+            row_index: 0,
+            expected: "A".to_owned(),
+        },
+        AssertMatrixCell {
+            field_id: text_field_id,
+            row_index: 3,
+            expected: "DA".to_owned(),
+        },
+        AssertMatrixCell {
+            field_id: number_field_id,
+            row_index: 1,
+            expected: "$2".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_paste_cells_test() {
+    let mut test = GridCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let number_of_rows = test.row_revs.len();
+    // The second line goes into a new row after the last one
+    let scripts = vec![
+        PasteCells {
+            anchor_row_index: number_of_rows - 1,
+            anchor_field_id: text_field_id.clone(),
+            tsv: "Hello\t12\nWorld\t$34\n".to_owned(),
+        },
+        AssertMatrixSize {
+            number_of_rows: number_of_rows + 1,
+            number_of_fields: test.field_revs.len(),
+        },
+        AssertMatrixCell {
+            field_id: text_field_id.clone(),
+            row_index: number_of_rows - 1,
+            expected: "Hello".to_owned(),
+        },
+        AssertMatrixCell {
+            field_id: number_field_id.clone(),
+            row_index: number_of_rows - 1,
+            expected: "$12".to_owned(),
+        },
+        AssertMatrixCell {
+            field_id: text_field_id,
+            row_index: number_of_rows,
+            expected: "World".to_owned(),
+        },
+        AssertMatrixCell {
+            field_id: number_field_id,
+            row_index: number_of_rows,
+            expected: "$34".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_paste_fractions_test() {
+    let mut test = GridCellTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let mut type_option = NumberTypeOptionPB::from(&field_rev);
+    type_option.accept_fractions = true;
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &field_rev.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    test.run_scripts(vec![
+        PasteCells {
+            anchor_row_index: 0,
+            anchor_field_id: field_rev.id.clone(),
+            tsv: "3 1/4\n1/2\n".to_owned(),
+        },
+        AssertCellValue {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            raw_value: Some("3.25".to_owned()),
+            display_value: "$3.25".to_owned(),
+        },
+        AssertCellValue {
+            field_id: field_rev.id.clone(),
+            row_index: 1,
+            raw_value: Some("0.5".to_owned()),
+            display_value: "$0.5".to_owned(),
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_paste_invalid_cells_test() {
+    let test = GridCellTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect).clone();
+    let mut type_option = MultiSelectTypeOptionPB::from(&field_rev);
+    type_option.max_selected = Some(2);
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &field_rev.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // The second line selects more options than the field allows and would create a new row
+    let row_revs = test.editor.get_all_row_revs(&test.view_id).await.unwrap();
+    let anchor_row_id = row_revs.last().unwrap().id.clone();
+    let tsv = format!("{}\n{},{},{}\n", GOOGLE, GOOGLE, FACEBOOK, TWITTER);
+    let error = test
+        .editor
+        .paste_cells(&test.view_id, &anchor_row_id, &field_rev.id, &tsv)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidCellData.value());
+
+    let matrix = test.editor.grid_as_matrix(&test.view_id).await.unwrap();
+    assert_eq!(matrix.len(), row_revs.len());
+    let row_rev = test.editor.get_row_rev(&anchor_row_id).await.unwrap().unwrap();
+    assert_eq!(
+        row_rev.cells.get(&field_rev.id),
+        row_revs.last().unwrap().cells.get(&field_rev.id)
+    );
+}
+
+#[tokio::test]
+async fn grid_cell_read_only_field_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let changeset = FieldChangesetParams {
+        field_id: field_id.clone(),
+        grid_id: test.view_id.clone(),
+        read_only: Some(true),
+        ..Default::default()
+    };
+    test.editor.update_field(changeset).await.unwrap();
+
+    let row_id = test.row_revs[0].id.clone();
+    let error = test
+        .editor
+        .update_cell_with_changeset(&row_id, &field_id, "B".to_owned())
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldInvalidOperation.value());
+    let error = test
+        .editor
+        .set_cell_color(&row_id, &field_id, Some("#FF0000".to_owned()))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldInvalidOperation.value());
+    assert_eq!(test.editor.cell_color(&row_id, &field_id).await.unwrap(), None);
+
+    // The cell keeps its content and can still be read
+    test.run_scripts(vec![AssertCellValue {
+        field_id,
+        row_index: 0,
+        raw_value: Some("A".to_owned()),
+        display_value: "A".to_owned(),
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_update_row_of_not_writable_field_test() {
+    let test = GridCellTest::new().await;
+    let row_id = test.row_revs[0].id.clone();
+    for field_type in [FieldType::Formula, FieldType::CreatedBy, FieldType::LastEditedBy] {
+        let field_id = test.get_first_field_rev(field_type).id.clone();
+        let mut changeset = RowChangeset::new(row_id.clone());
+        changeset
+            .cell_by_field_id
+            .insert(field_id.clone(), CellRevision::new("1".to_owned()));
+        let error = test.editor.update_row(changeset).await.unwrap_err();
+        assert_eq!(error.code, ErrorCode::FieldInvalidOperation.value());
+
+        let error = test
+            .editor
+            .copy_cell(&test.row_revs[1].id, &row_id, &field_id)
+            .await
+            .unwrap_err();
+        assert_eq!(error.code, ErrorCode::FieldInvalidOperation.value());
+    }
+}
+
+#[tokio::test]
+async fn grid_cell_date_raw_and_display_value_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+    test.run_scripts(vec![AssertCellValue {
+        field_id,
+        row_index: 0,
+        raw_value: Some("1647251762".to_owned()),
+        // The date field uses the US date format without the time
+        display_value: "2022/03/14".to_owned(),
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_set_from_display_str_test() {
+    let mut test = GridCellTest::new().await;
+    let date_field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    test.run_scripts(vec![
+        // The date field uses the US date format, but the other formats are parsed too
+        SetCellFromStr {
+            field_id: date_field_id.clone(),
+            row_index: 0,
+            display_str: "Mar 14, 2022".to_owned(),
+            is_err: false,
+        },
+        AssertCellValue {
+            field_id: date_field_id.clone(),
+            row_index: 0,
+            raw_value: Some("1647216000".to_owned()),
+            display_value: "2022/03/14".to_owned(),
+        },
+        SetCellFromStr {
+            field_id: date_field_id,
+            row_index: 0,
+            display_str: "14th of March".to_owned(),
+            is_err: true,
+        },
+        SetCellFromStr {
+            field_id: number_field_id.clone(),
+            row_index: 0,
+            display_str: "$1,200".to_owned(),
+            is_err: false,
+        },
+        AssertCellValue {
+            field_id: number_field_id.clone(),
+            row_index: 0,
+            raw_value: Some("1200".to_owned()),
+            display_value: "$1,200".to_owned(),
+        },
+        SetCellFromStr {
+            field_id: number_field_id,
+            row_index: 0,
+            display_str: "twelve".to_owned(),
+            is_err: true,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_set_number_from_german_display_str_test() {
+    let mut test = GridCellTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let mut type_option = NumberTypeOptionPB::from(&field_rev);
+    type_option.locale = NumberLocale::De;
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &field_rev.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // The separators of the locale are only read once
+    test.run_scripts(vec![
+        SetCellFromStr {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            display_str: "1.234,56".to_owned(),
+            is_err: false,
+        },
+        AssertCellValue {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            raw_value: Some("1234.56".to_owned()),
+            display_value: "$1,234.56".to_owned(),
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_row_display_title_fallback_test() {
+    let mut test = GridCellTest::new().await;
+    let primary_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        AssertRowDisplayTitle {
+            row_index: 0,
+            expected: "A".to_owned(),
+        },
+        UpdateCell {
+            changeset: CellChangesetPB {
+                grid_id: test.view_id.clone(),
+                row_id,
+                field_id: primary_field_id,
+                type_cell_data: "".to_owned(),
+            },
+            is_err: false,
+        },
+        // Falls back to the number cell, which is the first non-empty cell after the primary one
+        AssertRowDisplayTitle {
+            row_index: 0,
+            expected: "$1".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+struct EuroNumberFormatter();
+impl CellFormatter for EuroNumberFormatter {
+    fn format(&self, cell_str: String, _decoded_field_type: &FieldType, _field_rev: &FieldRevision) -> String {
+        format!("{} €", cell_str)
+    }
+}
+
+#[tokio::test]
+async fn grid_cell_custom_number_formatter_test() {
+    let mut test = GridCellTest::new().await;
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    test.run_scripts(vec![
+        AssertCellDisplayStr {
+            field_id: number_field_id.clone(),
+            row_index: 0,
+            expected: "$1".to_owned(),
+        },
+        RegisterCellFormatter {
+            field_type: FieldType::Number,
+            formatter: Arc::new(EuroNumberFormatter()),
+        },
+        AssertCellDisplayStr {
+            field_id: number_field_id,
+            row_index: 0,
+            expected: "1 €".to_owned(),
+        },
+        // The other field types are still rendered by the default formatters
+        AssertCellDisplayStr {
+            field_id: text_field_id,
+            row_index: 0,
+            expected: "A".to_owned(),
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_formula_multiplication_test() {
+    let mut test = GridCellTest::new().await;
+    let formula_field_id = test.get_first_field_rev(FieldType::Formula).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let mut scripts = vec![UpdateFormula {
+        field_id: formula_field_id.clone(),
+        expression: "{Price} * 2".to_owned(),
+    }];
+    // The empty number cell is treated as zero
+    for (row_index, expected) in ["2", "4", "6", "8", "0", "10"].iter().enumerate() {
+        scripts.push(AssertCellDisplayStr {
+            field_id: formula_field_id.clone(),
+            row_index,
+            expected: expected.to_string(),
+        });
+    }
+    test.run_scripts(scripts).await;
+
+    // The formula cell is recomputed after the price cell is updated
+    let row_id = test.row_revs[0].id.clone();
+    let mut receiver = test.editor.subscribe_grid_event();
+    test.run_scripts(vec![
+        UpdateCell {
+            changeset: CellChangesetPB {
+                grid_id: test.view_id.clone(),
+                row_id: row_id.clone(),
+                field_id: number_field_id,
+                type_cell_data: "10".to_owned(),
+            },
+            is_err: false,
+        },
+        AssertCellDisplayStr {
+            field_id: formula_field_id.clone(),
+            row_index: 0,
+            expected: "20".to_owned(),
+        },
+    ])
+    .await;
+
+    // The recomputed formula cell is sent as changed
+    let mut events = vec![];
+    while let Ok(event) = receiver.try_recv() {
+        events.push(event);
+    }
+    assert!(events.contains(&GridChangeEvent::CellChanged {
+        row_id,
+        field_id: formula_field_id,
+    }));
+}
+
+#[tokio::test]
+async fn grid_cell_formula_if_test() {
+    let mut test = GridCellTest::new().await;
+    let formula_field_id = test.get_first_field_rev(FieldType::Formula).id.clone();
+    let mut scripts = vec![UpdateFormula {
+        field_id: formula_field_id.clone(),
+        expression: r#"if({Price} > 2, "High", "Low")"#.to_owned(),
+    }];
+    for (row_index, expected) in ["Low", "Low", "High", "High", "Low", "High"].iter().enumerate() {
+        scripts.push(AssertCellDisplayStr {
+            field_id: formula_field_id.clone(),
+            row_index,
+            expected: expected.to_string(),
+        });
+    }
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_formula_reference_error_test() {
+    let mut test = GridCellTest::new().await;
+    let formula_field_id = test.get_first_field_rev(FieldType::Formula).id.clone();
+    test.run_scripts(vec![
+        UpdateFormula {
+            field_id: formula_field_id.clone(),
+            expression: "{Price} * {Qty}".to_owned(),
+        },
+        AssertCellDisplayStr {
+            field_id: formula_field_id,
+            row_index: 0,
+            expected: "#REF!".to_owned(),
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_formula_rename_and_delete_field_test() {
+    let mut test = GridCellTest::new().await;
+    let formula_field_id = test.get_first_field_rev(FieldType::Formula).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    test.run_scripts(vec![UpdateFormula {
+        field_id: formula_field_id.clone(),
+        expression: "{Price} * 2".to_owned(),
+    }])
+    .await;
+
+    // The formula follows the renamed field
+    test.editor.rename_field(&number_field_id, "Cost").await.unwrap();
+    let formula_field_rev = test.editor.get_field_rev(&formula_field_id).await.unwrap();
+    assert_eq!(FormulaTypeOptionPB::from(&formula_field_rev).expression, "{Cost} * 2");
+    test.run_scripts(vec![AssertCellDisplayStr {
+        field_id: formula_field_id.clone(),
+        row_index: 0,
+        expected: "2".to_owned(),
+    }])
+    .await;
+
+    test.editor.delete_field(&number_field_id).await.unwrap();
+    test.run_scripts(vec![AssertCellDisplayStr {
+        field_id: formula_field_id,
+        row_index: 0,
+        expected: "#REF!".to_owned(),
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_formula_of_new_row_test() {
+    let mut test = GridCellTest::new().await;
+    let formula_field_id = test.get_first_field_rev(FieldType::Formula).id.clone();
+    let row_count = test.row_revs.len();
+    test.run_scripts(vec![
+        UpdateFormula {
+            field_id: formula_field_id.clone(),
+            expression: "{Price} + 1".to_owned(),
+        },
+        CreateEmptyRow,
+        AssertCellDisplayStr {
+            field_id: formula_field_id,
+            row_index: row_count,
+            expected: "1".to_owned(),
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_append_option_to_multi_select_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    // The multi-select cell of the third row has the Facebook option
+    test.run_scripts(vec![
+        AppendToCell {
+            field_id: field_id.clone(),
+            row_index: 2,
+            value: TWITTER.to_owned(),
+            is_err: false,
+        },
+        // The option that is already selected is not duplicated
+        AppendToCell {
+            field_id: field_id.clone(),
+            row_index: 2,
+            value: FACEBOOK.to_owned(),
+            is_err: false,
+        },
+        AssertCellDisplayStr {
+            field_id: field_id.clone(),
+            row_index: 2,
+            expected: format!("{},{}", FACEBOOK, TWITTER),
+        },
+        AppendToCell {
+            field_id,
+            row_index: 2,
+            value: "Unknown".to_owned(),
+            is_err: true,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_select_options_by_usage_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    // Google is selected by two rows, Facebook by two rows and Twitter by one row
+    test.run_scripts(vec![
+        AssertSelectOptionsByUsage {
+            field_id: field_id.clone(),
+            persist: false,
+            expected: vec![GOOGLE, FACEBOOK, TWITTER],
+        },
+        AppendToCell {
+            field_id: field_id.clone(),
+            row_index: 2,
+            value: TWITTER.to_owned(),
+            is_err: false,
+        },
+        AppendToCell {
+            field_id: field_id.clone(),
+            row_index: 3,
+            value: TWITTER.to_owned(),
+            is_err: false,
+        },
+        AssertSelectOptionsByUsage {
+            field_id: field_id.clone(),
+            persist: false,
+            expected: vec![TWITTER, GOOGLE, FACEBOOK],
+        },
+        AssertStoredSelectOptions {
+            field_id: field_id.clone(),
+            expected: vec![GOOGLE, FACEBOOK, TWITTER],
+        },
+        AssertSelectOptionsByUsage {
+            field_id: field_id.clone(),
+            persist: true,
+            expected: vec![TWITTER, GOOGLE, FACEBOOK],
+        },
+        AssertStoredSelectOptions {
+            field_id,
+            expected: vec![TWITTER, GOOGLE, FACEBOOK],
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_copy_select_cell_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    test.run_scripts(vec![
+        AssertCellDisplayStr {
+            field_id: field_id.clone(),
+            row_index: 4,
+            expected: "".to_owned(),
+        },
+        CopyCell {
+            field_id: field_id.clone(),
+            from_row_index: 0,
+            to_row_index: 4,
+        },
+        AssertCellDisplayStr {
+            field_id: field_id.clone(),
+            row_index: 4,
+            expected: format!("{},{}", GOOGLE, FACEBOOK),
+        },
+    ])
+    .await;
+
+    // The destination cell shares the option ids of the source cell
+    let cell_path = |row_index: usize| CellPathParams {
+        view_id: test.view_id.clone(),
+        field_id: field_id.clone(),
+        row_id: test.row_revs[row_index].id.clone(),
+    };
+    assert_eq!(
+        test.editor.cell_raw_value(&cell_path(4)).await,
+        test.editor.cell_raw_value(&cell_path(0)).await
+    );
+}
+
+#[tokio::test]
+async fn grid_cell_copy_select_cell_over_max_selected_test() {
+    let mut test = GridCellTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect).clone();
+    let mut type_option = MultiSelectTypeOptionPB::from(&field_rev);
+    type_option.max_selected = Some(1);
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &field_rev.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // The source cell selects two options
+    let error = test
+        .editor
+        .copy_cell(&test.row_revs[0].id, &test.row_revs[4].id, &field_rev.id)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidCellData.value());
+    test.run_scripts(vec![AssertCellDisplayStr {
+        field_id: field_rev.id.clone(),
+        row_index: 4,
+        expected: "".to_owned(),
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_last_edited_by_test() {
+    let mut test = GridCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let checkbox_field_id = test.get_first_field_rev(FieldType::Checkbox).id.clone();
+    let created_by_field_id = test.get_first_field_rev(FieldType::CreatedBy).id.clone();
+    let last_edited_by_field_id = test.get_first_field_rev(FieldType::LastEditedBy).id.clone();
+    let editor_name = test.sdk.user_session.user_name().unwrap();
+    let row_index = test.row_revs.len();
+    test.run_scripts(vec![
+        CreateEmptyRow,
+        AssertCellDisplayStr {
+            field_id: created_by_field_id.clone(),
+            row_index,
+            expected: editor_name.clone(),
+        },
+        AssertCellDisplayStr {
+            field_id: last_edited_by_field_id.clone(),
+            row_index,
+            expected: editor_name.clone(),
+        },
+    ])
+    .await;
+
+    // The id is displayed because the name of the other user is unknown
+    let row_index = test.row_revs.len();
+    test.run_scripts(vec![
+        InsertRowByUser {
+            user_id: "other_user".to_owned(),
+        },
+        AssertCellDisplayStr {
+            field_id: last_edited_by_field_id.clone(),
+            row_index,
+            expected: "other_user".to_owned(),
+        },
+        SetCellFromStr {
+            field_id: text_field_id,
+            row_index,
+            display_str: "hello".to_owned(),
+            is_err: false,
+        },
+        AssertCellDisplayStr {
+            field_id: last_edited_by_field_id.clone(),
+            row_index,
+            expected: editor_name.clone(),
+        },
+        AssertCellDisplayStr {
+            field_id: created_by_field_id.clone(),
+            row_index,
+            expected: "other_user".to_owned(),
+        },
+    ])
+    .await;
+
+    // The bulk edits record the editor of every row
+    let row_index = test.row_revs.len();
+    test.run_scripts(vec![InsertRowByUser {
+        user_id: "other_user".to_owned(),
+    }])
+    .await;
+    test.editor
+        .set_all_checkbox_cells(&test.view_id, &checkbox_field_id, true)
+        .await
+        .unwrap();
+    test.run_scripts(vec![
+        AssertCellDisplayStr {
+            field_id: last_edited_by_field_id,
+            row_index,
+            expected: editor_name,
+        },
+        AssertCellDisplayStr {
+            field_id: created_by_field_id,
+            row_index,
+            expected: "other_user".to_owned(),
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_cell_unknown_field_type_round_trip_test() {
+    let test = GridCellTest::new().await;
+    // The field type 200 stands for a field type that is added by a newer version
+    let field_rev = FieldRevision::new("Future", "", 200, 150, false);
+    let field_id = field_rev.id.clone();
+    test.editor.create_new_field_rev(field_rev).await.unwrap();
+
+    let type_cell_data = r#"{"data":"opaque data","field_type":200}"#.to_owned();
+    let mut row_rev = RowRevision::new(&test.block_meta_revs[0].block_id);
+    row_rev
+        .cells
+        .insert(field_id.clone(), CellRevision::new(type_cell_data.clone()));
+    let row_id = row_rev.id.clone();
+    test.editor.insert_rows(vec![row_rev]).await.unwrap();
+
+    // The cell is read-only and it's displayed as a placeholder
+    let result = test
+        .editor
+        .update_cell(row_id.clone(), field_id.clone(), "text".to_owned())
+        .await;
+    assert!(result.is_err());
+    let params = CellPathParams {
+        view_id: test.view_id.clone(),
+        field_id: field_id.clone(),
+        row_id: row_id.clone(),
+    };
+    assert_eq!(
+        test.editor.get_cell_display_str(&params).await,
+        UNKNOWN_FIELD_TYPE_PLACEHOLDER
+    );
+
+    // Reopen the grid to load the saved data
+    sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+    test.sdk.grid_manager.close_grid(&test.view_id).await.unwrap();
+    let editor = test.sdk.grid_manager.open_grid(&test.view_id).await.unwrap();
+    assert_eq!(editor.get_field_rev(&field_id).await.unwrap().ty, 200);
+    let cell_rev = editor.get_cell_rev(&row_id, &field_id).await.unwrap().unwrap();
+    assert_eq!(cell_rev.type_cell_data, type_cell_data);
+}
+
+#[tokio::test]
+async fn grid_cell_copy_number_column_to_text_column_test() {
+    let test = GridCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    test.editor.copy_column(&number_field_id, &text_field_id).await.unwrap();
+
+    let field_revs = test.editor.get_view_field_revs(&test.view_id).await.unwrap();
+    let index = field_revs
+        .iter()
+        .position(|field_rev| field_rev.id == text_field_id)
+        .unwrap();
+    let texts = test
+        .editor
+        .grid_as_matrix(&test.view_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| row[index].clone())
+        .collect::<Vec<String>>();
+    // The cells of the USD number field are 1, 2, 3, 4, empty and 5
+    assert_eq!(texts, vec!["$1", "$2", "$3", "$4", "", "$5"]);
+
+    // The text can't be copied into a number field
+    let error = test
+        .editor
+        .copy_column(&text_field_id, &number_field_id)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldTypeMismatch.value());
+}
+
+#[tokio::test]
+async fn grid_cell_copy_single_select_column_to_single_select_column_test() {
+    let test = GridCellTest::new().await;
+    let from_field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+    // The options of the new field have the same names, but different ids and order
+    let single_select = SingleSelectTypeOptionBuilder::default()
+        .add_option(SelectOptionPB::new(PAUSED))
+        .add_option(SelectOptionPB::new(COMPLETED))
+        .add_option(SelectOptionPB::new(PLANNED));
+    let to_field_rev = FieldBuilder::new(single_select).name("Status").build();
+    let to_field_id = to_field_rev.id.clone();
+    test.editor.create_new_field_rev(to_field_rev).await.unwrap();
+    test.editor.copy_column(&from_field_id, &to_field_id).await.unwrap();
+
+    let field_revs = test.editor.get_view_field_revs(&test.view_id).await.unwrap();
+    let from_index = field_revs
+        .iter()
+        .position(|field_rev| field_rev.id == from_field_id)
+        .unwrap();
+    let to_index = field_revs
+        .iter()
+        .position(|field_rev| field_rev.id == to_field_id)
+        .unwrap();
+    let matrix = test.editor.grid_as_matrix(&test.view_id).await.unwrap();
+    for row in matrix.iter() {
+        assert_eq!(row[to_index], row[from_index]);
+    }
+    assert!(matrix.iter().any(|row| row[to_index] == COMPLETED));
+
+    // The cells refer to the options of the new field
+    let to_field_rev = test.editor.get_field_rev(&to_field_id).await.unwrap();
+    let option_ids = SingleSelectTypeOptionPB::from(&to_field_rev)
+        .options
+        .into_iter()
+        .map(|option| option.id)
+        .collect::<Vec<String>>();
+    for row_rev in test.editor.get_all_row_revs(&test.view_id).await.unwrap() {
+        let cell_rev = test
+            .editor
+            .get_cell_rev(&row_rev.id, &to_field_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let cell_str = TypeCellData::from_json_str(&cell_rev.type_cell_data).unwrap().cell_str;
+        let selected_option_ids = SelectOptionIds::from(cell_str).into_inner();
+        assert!(selected_option_ids
+            .iter()
+            .all(|option_id| option_ids.contains(option_id)));
+    }
+}
+
+#[tokio::test]
+async fn grid_cell_cells_for_number_field_test() {
+    let test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let cells = test.editor.cells_for_field(&field_id).await.unwrap();
+
+    let row_ids = cells.iter().map(|(row_id, _)| row_id.clone()).collect::<Vec<String>>();
+    let expected_row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(row_ids, expected_row_ids);
+
+    let cell_strs = cells
+        .into_iter()
+        .map(|(_, type_cell_data)| {
+            assert!(type_cell_data.is_number());
+            type_cell_data.cell_str
+        })
+        .collect::<Vec<String>>();
+    assert_eq!(cell_strs, vec!["1", "2", "3", "4", "", "5"]);
+
+    let error = test.editor.cells_for_field("unknown").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldRecordNotFound.value());
+}
+
+#[tokio::test]
+async fn grid_cell_single_select_keeps_first_option_id_test() {
+    let test = GridCellTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::SingleSelect).clone();
+    let type_option = SingleSelectTypeOptionPB::from(&field_rev);
+    let option_ids = type_option
+        .options
+        .iter()
+        .map(|option| option.id.clone())
+        .collect::<Vec<String>>();
+    assert!(option_ids.len() > 1);
+
+    let row_id = test.row_revs[0].id.clone();
+    let changeset = SelectOptionCellChangeset::from_insert_options(option_ids.clone());
+    test.editor
+        .update_cell_with_changeset(&row_id, &field_rev.id, changeset)
+        .await
+        .unwrap();
+
+    let params = CellPathParams {
+        view_id: test.view_id.clone(),
+        field_id: field_rev.id.clone(),
+        row_id,
+    };
+    assert_eq!(test.editor.cell_raw_value(&params).await.unwrap(), option_ids[0]);
+}
+
+#[tokio::test]
+async fn grid_cell_text_color_test() {
+    let test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let row_id = test.row_revs[0].id.clone();
+    test.editor
+        .set_cell_color(&row_id, &field_id, Some("#FFD700".to_owned()))
+        .await
+        .unwrap();
+
+    // Changing the width of the field saves a new revision of the field, not of the cells
+    let changeset = FieldChangesetParams {
+        field_id: field_id.clone(),
+        grid_id: test.view_id.clone(),
+        width: Some(300),
+        ..Default::default()
+    };
+    test.editor.update_field(changeset).await.unwrap();
+    assert_eq!(
+        test.editor.cell_color(&row_id, &field_id).await.unwrap(),
+        Some("#FFD700".to_owned())
+    );
+
+    // Editing the text keeps the color
+    test.editor
+        .update_cell_with_changeset(&row_id, &field_id, "B".to_owned())
+        .await
+        .unwrap();
+    let params = CellPathParams {
+        view_id: test.view_id.clone(),
+        field_id: field_id.clone(),
+        row_id: row_id.clone(),
+    };
+    assert_eq!(test.editor.get_cell_display_str(&params).await, "B");
+    assert_eq!(
+        test.editor.cell_color(&row_id, &field_id).await.unwrap(),
+        Some("#FFD700".to_owned())
+    );
+
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let error = test
+        .editor
+        .set_cell_color(&row_id, &number_field_id, Some("#FFD700".to_owned()))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldTypeMismatch.value());
+    let error = test
+        .editor
+        .set_cell_color(&row_id, &field_id, Some("gold".to_owned()))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidCellData.value());
+
+    test.editor.set_cell_color(&row_id, &field_id, None).await.unwrap();
+    assert_eq!(test.editor.cell_color(&row_id, &field_id).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn grid_cell_recent_select_options_test() {
+    let mut test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    test.run_scripts(vec![
+        AppendToCell {
+            field_id: field_id.clone(),
+            row_index: 2,
+            value: TWITTER.to_owned(),
+            is_err: false,
+        },
+        AppendToCell {
+            field_id: field_id.clone(),
+            row_index: 4,
+            value: GOOGLE.to_owned(),
+            is_err: false,
+        },
+    ])
+    .await;
+
+    let names = |options: Vec<SelectOptionPB>| options.into_iter().map(|option| option.name).collect::<Vec<String>>();
+    let options = test.editor.recent_options(&field_id, 10).await.unwrap();
+    assert_eq!(names(options), vec![GOOGLE, TWITTER]);
+
+    let options = test.editor.recent_options(&field_id, 1).await.unwrap();
+    assert_eq!(names(options), vec![GOOGLE]);
+
+    // The recent options are kept after reopening the grid
+    sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+    test.sdk.grid_manager.close_grid(&test.view_id).await.unwrap();
+    let editor = test.sdk.grid_manager.open_grid(&test.view_id).await.unwrap();
+    let options = editor.recent_options(&field_id, 10).await.unwrap();
+    assert_eq!(names(options), vec![GOOGLE, TWITTER]);
+}
+
+#[tokio::test]
+async fn grid_cell_checklist_items_test() {
+    let test = GridCellTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Checklist).id.clone();
+    let milk = test.editor.add_checklist_item(&field_id, "Milk").await.unwrap();
+    let eggs = test.editor.add_checklist_item(&field_id, "Eggs").await.unwrap();
+    let flour = test.editor.add_checklist_item(&field_id, "Flour").await.unwrap();
+    let error = test.editor.add_checklist_item(&field_id, "Milk").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldInvalidOperation.value());
+    let error = test.editor.add_checklist_item(&field_id, " ").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::SelectOptionNameIsEmpty.value());
+
+    let row_ids = vec![test.row_revs[0].id.clone(), test.row_revs[1].id.clone()];
+    for row_id in row_ids.iter() {
+        assert!(test
+            .editor
+            .toggle_checklist_item(row_id, &field_id, &eggs.id)
+            .await
+            .unwrap());
+    }
+    assert!(test
+        .editor
+        .toggle_checklist_item(&row_ids[0], &field_id, &milk.id)
+        .await
+        .unwrap());
+
+    test.editor.move_checklist_item(&field_id, &flour.id, 0).await.unwrap();
+    test.editor.remove_checklist_item(&field_id, &eggs.id).await.unwrap();
+
+    let field_rev = test.editor.get_field_rev(&field_id).await.unwrap();
+    let type_option = ChecklistTypeOptionPB::from(&field_rev);
+    let item_ids = type_option
+        .options
+        .iter()
+        .map(|option| option.id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(item_ids.first(), Some(&flour.id.as_str()));
+    assert_eq!(item_ids.last(), Some(&milk.id.as_str()));
+    assert!(!item_ids.contains(&eggs.id.as_str()));
+
+    // The completion of the removed item is pruned from the cells
+    for row_id in row_ids.iter() {
+        let params = CellPathParams {
+            view_id: test.view_id.clone(),
+            field_id: field_id.clone(),
+            row_id: row_id.clone(),
+        };
+        let cell_str = test.editor.cell_raw_value(&params).await.unwrap();
+        assert!(!cell_str.contains(&eggs.id));
+    }
+    let params = CellPathParams {
+        view_id: test.view_id.clone(),
+        field_id: field_id.clone(),
+        row_id: row_ids[0].clone(),
+    };
+    assert!(test.editor.cell_raw_value(&params).await.unwrap().contains(&milk.id));
+
+    // The items of a read-only checklist can't be removed from its cells
+    let changeset = FieldChangesetParams {
+        field_id: field_id.clone(),
+        grid_id: test.view_id.clone(),
+        read_only: Some(true),
+        ..Default::default()
+    };
+    test.editor.update_field(changeset).await.unwrap();
+    let error = test
+        .editor
+        .remove_checklist_item(&field_id, &milk.id)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldInvalidOperation.value());
+    assert!(test.editor.cell_raw_value(&params).await.unwrap().contains(&milk.id));
+}
@@ -1,7 +1,7 @@
 use crate::grid::grid_editor::GridEditorTest;
 use flowy_grid::entities::{CreateFieldParams, FieldChangesetParams, FieldType};
-use flowy_grid::services::cell::{stringify_cell_data, TypeCellData};
 use grid_rev_model::FieldRevision;
+use std::collections::HashMap;
 
 pub enum FieldScript {
     CreateField {
@@ -17,23 +17,72 @@ pub enum FieldScript {
         field_id: String,
         new_field_type: FieldType,
     },
+    SwitchToSelectFieldWithMapping {
+        field_id: String,
+        new_field_type: FieldType,
+        option_mapping: HashMap<String, String>,
+    },
     UpdateTypeOption {
         field_id: String,
         type_option: Vec<u8>,
     },
+    /// Previews switching the field to the `new_field_type`, then switches it and asserts the
+    /// cells match the preview.
+    AssertSwitchFieldPreview {
+        field_id: String,
+        new_field_type: FieldType,
+    },
+    RenameField {
+        field_id: String,
+        name: String,
+        is_err: bool,
+    },
+    AssertFieldName {
+        field_id: String,
+        expected_name: String,
+    },
+    AssertFieldByName {
+        name: String,
+        expected_field_id: Option<String>,
+    },
+    UpdateViewFieldOrder {
+        view_id: String,
+        field_ids: Vec<String>,
+    },
+    AssertViewFieldOrder {
+        view_id: String,
+        expected_field_ids: Vec<String>,
+    },
+    MoveFieldInView {
+        view_id: String,
+        field_id: String,
+        to_index: usize,
+    },
+    SwapFields {
+        field_id_a: String,
+        field_id_b: String,
+        is_err: bool,
+    },
+    AssertFieldOrder {
+        expected_field_ids: Vec<String>,
+    },
     AssertFieldCount(usize),
     AssertFieldFrozen {
         field_index: usize,
         frozen: bool,
     },
+    AssertFieldWidth {
+        field_id: String,
+        width: i32,
+    },
     AssertFieldTypeOptionEqual {
         field_index: usize,
         expected_type_option_data: String,
     },
+    /// Asserts the display string of the cell, as `grid_as_matrix` renders it in the view.
     AssertCellContent {
         field_id: String,
         row_index: usize,
-        from_field_type: FieldType,
         expected_content: String,
     },
 }
@@ -56,6 +105,18 @@ impl GridFieldTest {
         self.field_count
     }
 
+    /// Returns the display strings of the field's cells in the order of the view's rows.
+    pub async fn get_cell_contents(&self, field_id: &str) -> Vec<String> {
+        let view_id = self.view_id();
+        let matrix = self.editor.grid_as_matrix(&view_id).await.unwrap();
+        let field_revs = self.editor.get_view_field_revs(&view_id).await.unwrap();
+        let index = field_revs
+            .iter()
+            .position(|field_rev| field_rev.id == field_id)
+            .unwrap();
+        matrix.into_iter().map(|mut row| row.remove(index)).collect()
+    }
+
     pub async fn run_scripts(&mut self, scripts: Vec<FieldScript>) {
         for script in scripts {
             self.run_script(script).await;
@@ -97,6 +158,17 @@ impl GridFieldTest {
                     .unwrap();
                 self.field_revs = self.editor.get_field_revs(None).await.unwrap();
             }
+            FieldScript::SwitchToSelectFieldWithMapping {
+                field_id,
+                new_field_type,
+                option_mapping,
+            } => {
+                self.editor
+                    .switch_to_select_field_with_mapping(&field_id, &new_field_type, option_mapping)
+                    .await
+                    .unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
             FieldScript::UpdateTypeOption { field_id, type_option } => {
                 //
                 self.editor
@@ -105,9 +177,111 @@ impl GridFieldTest {
                     .unwrap();
                 self.field_revs = self.editor.get_field_revs(None).await.unwrap();
             }
+            FieldScript::AssertSwitchFieldPreview {
+                field_id,
+                new_field_type,
+            } => {
+                let preview = self
+                    .editor
+                    .preview_switch_field(&field_id, &new_field_type)
+                    .await
+                    .unwrap();
+                assert_eq!(preview.len(), self.get_row_revs().await.len());
+
+                // The preview doesn't change the field
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                let field_type: FieldType = field_rev.ty.into();
+                assert_ne!(field_type, new_field_type);
+
+                self.editor
+                    .switch_to_field_type(&field_id, &new_field_type)
+                    .await
+                    .unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+                let contents = self.get_cell_contents(&field_id).await;
+                let row_revs = self.editor.get_all_row_revs(&self.view_id()).await.unwrap();
+                for (row_id, expected_content) in preview {
+                    let row_index = row_revs.iter().position(|row_rev| row_rev.id == row_id).unwrap();
+                    assert_eq!(contents[row_index], expected_content);
+                }
+            }
+            FieldScript::RenameField { field_id, name, is_err } => {
+                let result = self.editor.rename_field(&field_id, &name).await;
+                assert_eq!(is_err, result.is_err());
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FieldScript::AssertFieldName {
+                field_id,
+                expected_name,
+            } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                assert_eq!(field_rev.name, expected_name);
+            }
+            FieldScript::AssertFieldByName {
+                name,
+                expected_field_id,
+            } => {
+                let field_id = self
+                    .editor
+                    .field_by_name(&name)
+                    .await
+                    .map(|field_rev| field_rev.id.clone());
+                assert_eq!(field_id, expected_field_id);
+            }
+            FieldScript::UpdateViewFieldOrder { view_id, field_ids } => {
+                self.editor.update_view_field_order(&view_id, field_ids).await.unwrap();
+            }
+            FieldScript::MoveFieldInView {
+                view_id,
+                field_id,
+                to_index,
+            } => {
+                self.editor
+                    .move_field_in_view(&view_id, &field_id, to_index)
+                    .await
+                    .unwrap();
+            }
+            FieldScript::AssertViewFieldOrder {
+                view_id,
+                expected_field_ids,
+            } => {
+                let field_ids = self
+                    .editor
+                    .get_view_field_revs(&view_id)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|field_rev| field_rev.id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(field_ids, expected_field_ids);
+            }
+            FieldScript::SwapFields {
+                field_id_a,
+                field_id_b,
+                is_err,
+            } => {
+                let result = self.editor.swap_fields(&field_id_a, &field_id_b).await;
+                assert_eq!(result.is_err(), is_err);
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FieldScript::AssertFieldOrder { expected_field_ids } => {
+                let field_ids = self
+                    .editor
+                    .get_field_revs(None)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|field_rev| field_rev.id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(field_ids, expected_field_ids);
+            }
             FieldScript::AssertFieldCount(count) => {
                 assert_eq!(self.editor.get_field_revs(None).await.unwrap().len(), count);
             }
+            FieldScript::AssertFieldWidth { field_id, width } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                assert_eq!(field_rev.width, width);
+            }
             FieldScript::AssertFieldFrozen { field_index, frozen } => {
                 let field_revs = self.editor.get_field_revs(None).await.unwrap();
                 let field_rev = field_revs[field_index].as_ref();
@@ -125,20 +299,10 @@ impl GridFieldTest {
             FieldScript::AssertCellContent {
                 field_id,
                 row_index,
-                from_field_type,
                 expected_content,
             } => {
-                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
-                let field_type: FieldType = field_rev.ty.into();
-
-                let rows = self.editor.get_grid(&self.view_id()).await.unwrap().rows;
-                let row = rows.get(row_index).unwrap();
-                let row_rev = self.editor.get_row_rev(&row.id).await.unwrap().unwrap();
-
-                let cell_rev = row_rev.cells.get(&field_id).unwrap().clone();
-                let type_cell_data: TypeCellData = cell_rev.try_into().unwrap();
-                let content = stringify_cell_data(type_cell_data.cell_str, &from_field_type, &field_type, &field_rev);
-                assert_eq!(content, expected_content);
+                let contents = self.get_cell_contents(&field_id).await;
+                assert_eq!(contents[row_index], expected_content);
             }
         }
     }
@@ -1,10 +1,22 @@
 use crate::grid::field_test::script::FieldScript::*;
 use crate::grid::field_test::script::GridFieldTest;
 use crate::grid::field_test::util::*;
+use crate::grid::grid_editor::{COMPLETED, PAUSED, PLANNED};
 use bytes::Bytes;
-use flowy_grid::entities::{FieldChangesetParams, FieldType};
-use flowy_grid::services::field::selection_type_option::SelectOptionPB;
-use flowy_grid::services::field::{gen_option_id, SingleSelectTypeOptionPB, CHECK, UNCHECK};
+use flowy_error::ErrorCode;
+use flowy_grid::entities::{FieldChangesetParams, FieldType, MoveFieldParams, MAX_FIELD_WIDTH, MIN_FIELD_WIDTH};
+use flowy_grid::services::errors::GridError;
+use flowy_grid::services::field::selection_type_option::{SelectOptionPB, SelectOptionRenamePB};
+use flowy_grid::services::field::{
+    gen_option_id, supported_transforms, validate_type_option, CheckboxTypeOptionPB, DateFormat, DateTypeOptionPB,
+    FieldSwitchWarning, SingleSelectTypeOptionPB, TransformQuality, CHECK, UNCHECK,
+};
+use flowy_revision::REVISION_WRITE_INTERVAL_IN_MILLIS;
+use grid_rev_model::{gen_grid_view_id, RowRevision};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
 
 #[tokio::test]
 async fn grid_create_field() {
@@ -105,6 +117,39 @@ async fn grid_update_field() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_update_field_width_clamp_test() {
+    let mut test = GridFieldTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let changeset = |width: i32| FieldChangesetParams {
+        field_id: field_id.clone(),
+        grid_id: test.view_id(),
+        width: Some(width),
+        ..Default::default()
+    };
+    let scripts = vec![
+        UpdateField {
+            changeset: changeset(10000),
+        },
+        AssertFieldWidth {
+            field_id: field_id.clone(),
+            width: MAX_FIELD_WIDTH,
+        },
+        UpdateField {
+            changeset: changeset(-5),
+        },
+        AssertFieldWidth {
+            field_id: field_id.clone(),
+            width: MIN_FIELD_WIDTH,
+        },
+        UpdateField {
+            changeset: changeset(200),
+        },
+        AssertFieldWidth { field_id, width: 200 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn grid_delete_field() {
     let mut test = GridFieldTest::new().await;
@@ -136,12 +181,14 @@ async fn grid_switch_from_select_option_to_checkbox_test() {
         id: gen_option_id(),
         name: CHECK.to_string(),
         color: Default::default(),
+        ..Default::default()
     });
     // Add a new option with name UNCHECK
     single_select_type_option.options.push(SelectOptionPB {
         id: gen_option_id(),
         name: UNCHECK.to_string(),
         color: Default::default(),
+        ..Default::default()
     });
 
     let bytes: Bytes = single_select_type_option.try_into().unwrap();
@@ -177,8 +224,6 @@ async fn grid_switch_from_checkbox_to_select_option_test() {
             field_id: field_rev.id.clone(),
             // the mock data of the checkbox with row_index one is "true"
             row_index: 1,
-            // the from_field_type represents as the current field type
-            from_field_type: FieldType::Checkbox,
             // The content of the checkbox should transform to the corresponding option name.
             expected_content: CHECK.to_string(),
         },
@@ -197,6 +242,150 @@ async fn grid_switch_from_checkbox_to_select_option_test() {
         .any(|option| option.name == CHECK));
 }
 
+#[tokio::test]
+async fn grid_validate_type_option_test() {
+    let mut single_select_type_option = SingleSelectTypeOptionPB::default();
+    let option = SelectOptionPB::new("Google");
+    single_select_type_option.options.push(option.clone());
+    let bytes: Bytes = single_select_type_option.clone().try_into().unwrap();
+    assert!(validate_type_option(&FieldType::SingleSelect, bytes).is_ok());
+
+    let error = validate_type_option(&FieldType::SingleSelect, vec![255, 255, 255]).unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidData.value());
+
+    // The options with the same id
+    single_select_type_option.options.push(option);
+    let bytes: Bytes = single_select_type_option.try_into().unwrap();
+    let error = validate_type_option(&FieldType::SingleSelect, bytes).unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidData.value());
+}
+
+#[tokio::test]
+async fn grid_switch_from_multi_select_to_single_select_warning_test() {
+    let test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect).clone();
+    // The first two rows select two options, the third row selects one.
+    let warnings = test
+        .editor
+        .switch_to_field_type(&field_rev.id, &FieldType::SingleSelect)
+        .await
+        .unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].row_id, test.row_revs[0].id);
+    assert_eq!(warnings[0].message, "dropped 1 of 2 selected options");
+    assert_eq!(warnings[1].row_id, test.row_revs[1].id);
+}
+
+#[tokio::test]
+async fn grid_switch_field_to_its_own_type_test() {
+    let mut test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::SingleSelect).clone();
+    let rev_id = test.editor.rev_manager().rev_id();
+    let row_revs = test.editor.get_all_row_revs(&test.view_id()).await.unwrap();
+
+    let scripts = vec![SwitchToField {
+        field_id: field_rev.id.clone(),
+        new_field_type: FieldType::SingleSelect,
+    }];
+    test.run_scripts(scripts).await;
+
+    assert_eq!(test.editor.rev_manager().rev_id(), rev_id);
+    let new_field_rev = test.editor.get_field_rev(&field_rev.id).await.unwrap();
+    assert_eq!(new_field_rev.as_ref(), field_rev.as_ref());
+    let new_row_revs = test.editor.get_all_row_revs(&test.view_id()).await.unwrap();
+    for (row_rev, new_row_rev) in row_revs.iter().zip(new_row_revs.iter()) {
+        assert_eq!(row_rev.cells.get(&field_rev.id), new_row_rev.cells.get(&field_rev.id));
+    }
+}
+
+// Test when switching the current field from Text to Single-select test
+// input:
+//      "A", "", "C", "DA", "AE", "AE" -> one option for each of "A", "C", "DA" and "AE"
+#[tokio::test]
+async fn grid_switch_from_text_to_single_select_test() {
+    let mut test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+    let scripts = vec![
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::SingleSelect,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            expected_content: "A".to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 1,
+            expected_content: "".to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 5,
+            expected_content: "AE".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let single_select_type_option = test.get_single_select_type_option(&field_rev.id);
+    let names = single_select_type_option
+        .options
+        .iter()
+        .map(|option| option.name.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(names, vec!["A", "C", "DA", "AE"]);
+}
+
+// Test when switching the current field from Text to Single-select with an option mapping
+// input:
+//      "A" -> the existing "Alpha" option, the other texts are transformed as usual
+#[tokio::test]
+async fn grid_switch_from_text_to_single_select_with_mapping_test() {
+    let mut test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+    let alpha = SelectOptionPB::new("Alpha");
+    let alpha_option = alpha.clone();
+    test.editor
+        .modify_field_rev(&field_rev.id, |field_rev| {
+            let mut type_option = SingleSelectTypeOptionPB::default();
+            type_option.options.push(alpha_option);
+            field_rev.insert_type_option(&type_option);
+            Ok(Some(()))
+        })
+        .await
+        .unwrap();
+
+    let option_mapping = HashMap::from([("A".to_owned(), alpha.id.clone())]);
+    let scripts = vec![
+        SwitchToSelectFieldWithMapping {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::SingleSelect,
+            option_mapping,
+        },
+        // The mapped cell is rewritten with the id of the option
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            expected_content: "Alpha".to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            expected_content: "DA".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let single_select_type_option = test.get_single_select_type_option(&field_rev.id);
+    let names = single_select_type_option
+        .options
+        .iter()
+        .map(|option| option.name.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(names, vec!["Alpha", "C", "DA", "AE"]);
+}
+
 // Test when switching the current field from Multi-select to Text test
 // The build-in test data is located in `make_test_grid` method(flowy-grid/tests/grid_editor.rs).
 // input:
@@ -218,7 +407,6 @@ async fn grid_switch_from_multi_select_to_text_test() {
     let script_assert_field = vec![AssertCellContent {
         field_id: field_rev.id.clone(),
         row_index: 0,
-        from_field_type: FieldType::MultiSelect,
         expected_content: format!(
             "{},{}",
             multi_select_type_option.get(0).unwrap().name,
@@ -236,7 +424,7 @@ async fn grid_switch_from_multi_select_to_text_test() {
 #[tokio::test]
 async fn grid_switch_from_checkbox_to_text_test() {
     let mut test = GridFieldTest::new().await;
-    let field_rev = test.get_first_field_rev(FieldType::Checkbox);
+    let field_rev = test.get_first_field_rev(FieldType::Checkbox).clone();
 
     let scripts = vec![
         SwitchToField {
@@ -246,17 +434,46 @@ async fn grid_switch_from_checkbox_to_text_test() {
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 1,
-            from_field_type: FieldType::Checkbox,
             expected_content: "Yes".to_string(),
         },
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 2,
-            from_field_type: FieldType::Checkbox,
             expected_content: "No".to_string(),
         },
     ];
     test.run_scripts(scripts).await;
+
+    // Switch back to set the custom labels, which are used when converting the cells to text
+    let mut checkbox_type_option = field_rev.get_type_option::<CheckboxTypeOptionPB>(field_rev.ty).unwrap();
+    checkbox_type_option.checked_label = "Done".to_owned();
+    checkbox_type_option.unchecked_label = "Pending".to_owned();
+    let bytes: Bytes = checkbox_type_option.try_into().unwrap();
+    let scripts = vec![
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::Checkbox,
+        },
+        UpdateTypeOption {
+            field_id: field_rev.id.clone(),
+            type_option: bytes.to_vec(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::RichText,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 1,
+            expected_content: "Done".to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 2,
+            expected_content: "Pending".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
 }
 
 // Test when switching the current field from Checkbox to Text test
@@ -281,17 +498,55 @@ async fn grid_switch_from_date_to_text_test() {
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 2,
-            from_field_type: FieldType::DateTime,
             expected_content: "2022/03/14".to_string(),
         },
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 3,
-            from_field_type: FieldType::DateTime,
             expected_content: "2022/11/17".to_string(),
         },
     ];
     test.run_scripts(scripts).await;
+
+    // The time is only appended when the cells are converted to text
+    let mut test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::DateTime).clone();
+    let mut date_type_option = field_rev.get_type_option::<DateTypeOptionPB>(field_rev.ty).unwrap();
+    date_type_option.include_time = true;
+    let bytes: Bytes = date_type_option.try_into().unwrap();
+    let scripts = vec![
+        UpdateTypeOption {
+            field_id: field_rev.id.clone(),
+            type_option: bytes.to_vec(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 2,
+            expected_content: "2022/03/14".to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::RichText,
+        },
+        // 1647251762 is 2022/03/14 09:56:02 in UTC
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 2,
+            expected_content: "2022/03/14 09:56".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_preview_switch_from_checkbox_to_text_test() {
+    let mut test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Checkbox).clone();
+    let scripts = vec![AssertSwitchFieldPreview {
+        field_id: field_rev.id.clone(),
+        new_field_type: FieldType::RichText,
+    }];
+    test.run_scripts(scripts).await;
 }
 
 // Test when switching the current field from Number to Text test
@@ -310,16 +565,554 @@ async fn grid_switch_from_number_to_text_test() {
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 0,
-            from_field_type: FieldType::Number,
             expected_content: "$1".to_string(),
         },
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 4,
-            from_field_type: FieldType::Number,
             expected_content: "".to_string(),
         },
     ];
 
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_reorder_fields_in_one_view_test() {
+    let mut test = GridFieldTest::new().await;
+    let grid_field_ids = test
+        .field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+    let mut reordered_field_ids = grid_field_ids.clone();
+    reordered_field_ids.reverse();
+
+    let other_view_id = gen_grid_view_id();
+    let scripts = vec![
+        AssertViewFieldOrder {
+            view_id: other_view_id.clone(),
+            expected_field_ids: grid_field_ids.clone(),
+        },
+        UpdateViewFieldOrder {
+            view_id: test.view_id(),
+            field_ids: reordered_field_ids.clone(),
+        },
+        AssertViewFieldOrder {
+            view_id: test.view_id(),
+            expected_field_ids: reordered_field_ids,
+        },
+        AssertViewFieldOrder {
+            view_id: other_view_id,
+            expected_field_ids: grid_field_ids.clone(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    // The grid's canonical order is unchanged
+    let field_ids = test
+        .editor
+        .get_field_revs(None)
+        .await
+        .unwrap()
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+    assert_eq!(field_ids, grid_field_ids);
+}
+
+#[tokio::test]
+async fn grid_move_field_in_one_view_test() {
+    let mut test = GridFieldTest::new().await;
+    let grid_field_ids = test
+        .field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+    let mut moved_field_ids = grid_field_ids.clone();
+    let first_field_id = moved_field_ids.remove(0);
+    moved_field_ids.insert(2, first_field_id.clone());
+
+    let other_view_id = gen_grid_view_id();
+    let scripts = vec![
+        AssertViewFieldOrder {
+            view_id: other_view_id.clone(),
+            expected_field_ids: grid_field_ids.clone(),
+        },
+        MoveFieldInView {
+            view_id: test.view_id(),
+            field_id: first_field_id,
+            to_index: 2,
+        },
+        AssertViewFieldOrder {
+            view_id: test.view_id(),
+            expected_field_ids: moved_field_ids,
+        },
+        AssertViewFieldOrder {
+            view_id: other_view_id,
+            expected_field_ids: grid_field_ids.clone(),
+        },
+        AssertFieldOrder {
+            expected_field_ids: grid_field_ids,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_rename_field_test() {
+    let mut test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let scripts = vec![
+        RenameField {
+            field_id: field_rev.id.clone(),
+            name: "  Cost ".to_string(),
+            is_err: false,
+        },
+        AssertFieldName {
+            field_id: field_rev.id.clone(),
+            expected_name: "Cost".to_string(),
+        },
+        RenameField {
+            field_id: field_rev.id.clone(),
+            name: "   ".to_string(),
+            is_err: true,
+        },
+        AssertFieldName {
+            field_id: field_rev.id.clone(),
+            expected_name: "Cost".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_find_field_by_name_test() {
+    let mut test = GridFieldTest::new().await;
+    let owner = test
+        .editor
+        .create_new_field_rev_with_type_option(&FieldType::RichText, None)
+        .await
+        .unwrap();
+    let reviewer = test
+        .editor
+        .create_new_field_rev_with_type_option(&FieldType::RichText, None)
+        .await
+        .unwrap();
+
+    let scripts = vec![
+        RenameField {
+            field_id: owner.id.clone(),
+            name: "Owner".to_string(),
+            is_err: false,
+        },
+        RenameField {
+            field_id: reviewer.id.clone(),
+            name: "Reviewer".to_string(),
+            is_err: false,
+        },
+        AssertFieldByName {
+            name: "Owner".to_string(),
+            expected_field_id: Some(owner.id.clone()),
+        },
+        AssertFieldByName {
+            name: "REVIEWER".to_string(),
+            expected_field_id: Some(reviewer.id.clone()),
+        },
+        AssertFieldByName {
+            name: "Assignee".to_string(),
+            expected_field_id: None,
+        },
+        // The first field in the field order is returned if the names are duplicated
+        RenameField {
+            field_id: reviewer.id.clone(),
+            name: "owner".to_string(),
+            is_err: false,
+        },
+        AssertFieldByName {
+            name: "owner".to_string(),
+            expected_field_id: Some(owner.id.clone()),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_swap_first_and_last_fields_test() {
+    let mut test = GridFieldTest::new().await;
+    let mut expected_field_ids = test
+        .field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+    let first_field_id = expected_field_ids.first().unwrap().clone();
+    let last_field_id = expected_field_ids.last().unwrap().clone();
+    let last_index = expected_field_ids.len() - 1;
+    expected_field_ids.swap(0, last_index);
+
+    let scripts = vec![
+        SwapFields {
+            field_id_a: first_field_id.clone(),
+            field_id_b: last_field_id,
+            is_err: false,
+        },
+        AssertFieldOrder {
+            expected_field_ids: expected_field_ids.clone(),
+        },
+        // The order is unchanged if either of the fields doesn't exist
+        SwapFields {
+            field_id_a: first_field_id,
+            field_id_b: "unknown".to_owned(),
+            is_err: true,
+        },
+        AssertFieldOrder { expected_field_ids },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_rename_not_exist_field_test() {
+    let test = GridFieldTest::new().await;
+    let error = test.editor.rename_field("unknown", "Cost").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldRecordNotFound.value());
+    assert_eq!(error.msg, GridError::field_not_found("unknown").to_string());
+}
+
+#[tokio::test]
+async fn grid_schema_fingerprint_test() {
+    let mut test = GridFieldTest::new().await;
+    let view_id = test.view_id();
+    let fingerprint = test.editor.schema_fingerprint(&view_id).await.unwrap();
+    let _ = test.editor.get_grid(&view_id).await.unwrap();
+    let _ = test.editor.get_field_revs(None).await.unwrap();
+    assert_eq!(test.editor.schema_fingerprint(&view_id).await.unwrap(), fingerprint);
+
+    let field_rev = test.get_first_field_rev(FieldType::Checkbox).clone();
+    let scripts = vec![SwitchToField {
+        field_id: field_rev.id.clone(),
+        new_field_type: FieldType::RichText,
+    }];
+    test.run_scripts(scripts).await;
+    assert_ne!(test.editor.schema_fingerprint(&view_id).await.unwrap(), fingerprint);
+}
+
+#[tokio::test]
+async fn grid_duplicate_select_option_test() {
+    let test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::SingleSelect).clone();
+    let options = SingleSelectTypeOptionPB::from(&field_rev).options;
+    let cells = |row_revs: Vec<Arc<RowRevision>>| {
+        row_revs
+            .iter()
+            .map(|row_rev| row_rev.cells.get(&field_rev.id).cloned())
+            .collect::<Vec<_>>()
+    };
+    let expected_cells = cells(test.editor.get_all_row_revs(&test.view_id()).await.unwrap());
+
+    let copy = test
+        .editor
+        .duplicate_select_option(&field_rev.id, &options[0].id)
+        .await
+        .unwrap();
+    assert_eq!(copy.name, format!("{} (copy)", options[0].name));
+
+    let field_rev = test.editor.get_field_rev(&field_rev.id).await.unwrap();
+    let new_options = SingleSelectTypeOptionPB::from(&field_rev).options;
+    assert_eq!(new_options.len(), options.len() + 1);
+    assert_eq!(new_options[1], copy);
+    assert!(options.iter().all(|option| option.id != copy.id));
+    assert_eq!(
+        cells(test.editor.get_all_row_revs(&test.view_id()).await.unwrap()),
+        expected_cells
+    );
+}
+
+#[tokio::test]
+async fn grid_rename_select_options_test() {
+    let test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::SingleSelect).clone();
+    let options = SingleSelectTypeOptionPB::from(&field_rev).options;
+    let option_id = |name: &str| options.iter().find(|option| option.name == name).unwrap().id.clone();
+    let row_revs = test.editor.get_all_row_revs(&test.view_id()).await.unwrap();
+    let contents = test.get_cell_contents(&field_rev.id).await;
+
+    // Renaming an option to the name of another option is rejected
+    let renames = vec![SelectOptionRenamePB {
+        option_id: option_id(COMPLETED),
+        name: PAUSED.to_owned(),
+    }];
+    let error = test
+        .editor
+        .rename_select_options(&field_rev.id, renames)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldInvalidOperation.value());
+
+    let renames = vec![
+        SelectOptionRenamePB {
+            option_id: option_id(COMPLETED),
+            name: "Done".to_owned(),
+        },
+        SelectOptionRenamePB {
+            option_id: option_id(PLANNED),
+            name: "Todo".to_owned(),
+        },
+    ];
+    test.editor.rename_select_options(&field_rev.id, renames).await.unwrap();
+    let field_rev = test.editor.get_field_rev(&field_rev.id).await.unwrap();
+    let names = SingleSelectTypeOptionPB::from(&field_rev)
+        .options
+        .into_iter()
+        .map(|option| option.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Done".to_owned(), "Todo".to_owned(), PAUSED.to_owned()]);
+
+    // The cells still select the same options, which are shown with the new names
+    let new_row_revs = test.editor.get_all_row_revs(&test.view_id()).await.unwrap();
+    for (row_rev, new_row_rev) in row_revs.iter().zip(new_row_revs.iter()) {
+        assert_eq!(row_rev.cells.get(&field_rev.id), new_row_rev.cells.get(&field_rev.id));
+    }
+    let expected_contents = contents
+        .into_iter()
+        .map(|content| match content.as_str() {
+            COMPLETED => "Done".to_owned(),
+            PLANNED => "Todo".to_owned(),
+            _ => content,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(test.get_cell_contents(&field_rev.id).await, expected_contents);
+}
+
+#[tokio::test]
+async fn grid_switch_from_single_select_to_text_with_color_test() {
+    let mut test = GridFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::SingleSelect).clone();
+    let mut single_select_type_option = test.get_single_select_type_option(&field_rev.id);
+    let completed = single_select_type_option
+        .options
+        .iter()
+        .find(|option| option.name == COMPLETED)
+        .unwrap()
+        .clone();
+    single_select_type_option.include_color_in_text = true;
+    let bytes: Bytes = single_select_type_option.try_into().unwrap();
+
+    let scripts = vec![
+        UpdateTypeOption {
+            field_id: field_rev.id.clone(),
+            type_option: bytes.to_vec(),
+        },
+        // The color is only appended when the cells are converted to text
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 2,
+            expected_content: COMPLETED.to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::RichText,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 2,
+            expected_content: format!("{} [{}]", COMPLETED, completed.color.name()),
+        },
+        // The row without an option stays empty
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            expected_content: "".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_migrate_legacy_date_type_option_test() {
+    let test = GridFieldTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+    // The date type option that was saved before the `include_time` was added
+    let legacy_str = r#"{"date_format":"ISO","time_format":"TwentyFourHour"}"#;
+    test.editor
+        .modify_field_rev(&field_id, |field_rev| {
+            let field_type = field_rev.ty;
+            field_rev.insert_type_option_str(&field_type, legacy_str.to_owned());
+            Ok(Some(()))
+        })
+        .await
+        .unwrap();
+
+    // Reopen the grid to load the saved data
+    sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+    test.sdk.grid_manager.close_grid(&test.view_id()).await.unwrap();
+    let editor = test.sdk.grid_manager.open_grid(&test.view_id()).await.unwrap();
+    let field_rev = editor.get_field_rev(&field_id).await.unwrap();
+    let type_option_str = field_rev.get_type_option_str(field_rev.ty).unwrap();
+    assert!(type_option_str.contains(r#""include_time":false"#));
+
+    let type_option = field_rev.get_type_option::<DateTypeOptionPB>(field_rev.ty).unwrap();
+    assert!(matches!(type_option.date_format, DateFormat::ISO));
+    assert!(!type_option.include_time);
+}
+
+#[tokio::test]
+async fn grid_validate_switch_to_single_select_without_options_test() {
+    let test = GridFieldTest::new().await;
+    // The new text field has no cells to create the options from
+    let field_rev = test
+        .editor
+        .create_new_field_rev_with_type_option(&FieldType::RichText, None)
+        .await
+        .unwrap();
+    let warnings = test
+        .editor
+        .validate_switch_field(&field_rev.id, &FieldType::SingleSelect)
+        .await
+        .unwrap();
+    assert_eq!(warnings, vec![FieldSwitchWarning::NoSelectOptions]);
+
+    // Nothing is saved by the validation
+    let field_rev = test.editor.get_field_rev(&field_rev.id).await.unwrap();
+    assert_eq!(FieldType::from(field_rev.ty), FieldType::RichText);
+
+    // The options are created from the texts of the mock rows
+    let text_field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+    let warnings = test
+        .editor
+        .validate_switch_field(&text_field_rev.id, &FieldType::SingleSelect)
+        .await
+        .unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn grid_supported_transforms_test() {
+    let transforms = supported_transforms();
+    let quality = |from: FieldType, to: FieldType| {
+        transforms
+            .iter()
+            .find(|(from_type, to_type, _)| from_type == &from && to_type == &to)
+            .map(|(_, _, quality)| *quality)
+    };
+
+    assert_eq!(
+        quality(FieldType::Checkbox, FieldType::RichText),
+        Some(TransformQuality::Lossless)
+    );
+    assert_eq!(
+        quality(FieldType::RichText, FieldType::Checkbox),
+        Some(TransformQuality::Lossy)
+    );
+    assert_eq!(
+        quality(FieldType::Checkbox, FieldType::SingleSelect),
+        Some(TransformQuality::Lossless)
+    );
+    assert_eq!(
+        quality(FieldType::Checkbox, FieldType::MultiSelect),
+        Some(TransformQuality::Lossless)
+    );
+    // The checkbox doesn't transform the select cells, so they are displayed as unchecked
+    assert_eq!(quality(FieldType::SingleSelect, FieldType::Checkbox), None);
+    assert_eq!(quality(FieldType::MultiSelect, FieldType::Checkbox), None);
+}
+
+#[tokio::test]
+async fn grid_supported_transforms_match_switches_test() {
+    for (from, to, quality) in supported_transforms() {
+        let test = GridFieldTest::new().await;
+        let field_id = test.get_first_field_rev(from.clone()).id.clone();
+        let row_id = test.row_revs[0].id.clone();
+        // The first row has no single-select nor URL cell, and its text is not a number
+        let display_str = match from {
+            FieldType::RichText => Some("1"),
+            FieldType::SingleSelect => Some(COMPLETED),
+            FieldType::URL => Some("https://appflowy.io"),
+            _ => None,
+        };
+        if let Some(display_str) = display_str {
+            test.editor
+                .set_cell_from_str(&row_id, &field_id, display_str)
+                .await
+                .unwrap();
+        }
+
+        let before = test.get_cell_contents(&field_id).await[0].clone();
+        assert!(!before.is_empty(), "{:?} -> {:?}", from, to);
+        test.editor.switch_to_field_type(&field_id, &to).await.unwrap();
+        let after = test.get_cell_contents(&field_id).await[0].clone();
+        match quality {
+            TransformQuality::Lossless => assert_eq!(after, before, "{:?} -> {:?}", from, to),
+            TransformQuality::Lossy => assert!(!after.is_empty(), "{:?} -> {:?}", from, to),
+        }
+    }
+}
+
+#[tokio::test]
+async fn grid_field_last_modified_test() {
+    let test = GridFieldTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let other_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let before = test.editor.field_last_modified(&field_id).await.unwrap();
+    let other_before = test.editor.field_last_modified(&other_field_id).await.unwrap();
+
+    let changeset = FieldChangesetParams {
+        field_id: field_id.clone(),
+        grid_id: test.view_id(),
+        name: Some("Title".to_owned()),
+        ..Default::default()
+    };
+    test.editor.update_field(changeset).await.unwrap();
+    let renamed = test.editor.field_last_modified(&field_id).await.unwrap();
+    assert!(renamed > before);
+    assert_eq!(
+        test.editor.field_last_modified(&other_field_id).await.unwrap(),
+        other_before
+    );
+
+    // Moving the field changes its position, but not the position of the first field, and the
+    // field that it's swapped with isn't modified either
+    let from_index = test
+        .field_revs
+        .iter()
+        .position(|field_rev| field_rev.id == other_field_id)
+        .unwrap();
+    let shifted_field_id = test.field_revs[from_index + 1].id.clone();
+    let shifted_before = test.editor.field_last_modified(&shifted_field_id).await.unwrap();
+    let params = MoveFieldParams {
+        grid_id: test.view_id(),
+        field_id: other_field_id.clone(),
+        from_index: from_index as i32,
+        to_index: from_index as i32 + 1,
+    };
+    test.editor.move_field(params).await.unwrap();
+    let moved = test.editor.field_last_modified(&other_field_id).await.unwrap();
+    assert!(moved > other_before);
+    assert_eq!(test.editor.field_last_modified(&field_id).await.unwrap(), renamed);
+    assert_eq!(
+        test.editor.field_last_modified(&shifted_field_id).await.unwrap(),
+        shifted_before
+    );
+
+    // The legacy type-option data is migrated when the grid is reopened
+    let date_field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+    let legacy_str = r#"{"date_format":"ISO","time_format":"TwentyFourHour"}"#;
+    test.editor
+        .modify_field_rev(&date_field_id, |field_rev| {
+            let field_type = field_rev.ty;
+            field_rev.insert_type_option_str(&field_type, legacy_str.to_owned());
+            Ok(Some(()))
+        })
+        .await
+        .unwrap();
+    let legacy = test.editor.field_last_modified(&date_field_id).await.unwrap();
+
+    sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+    test.sdk.grid_manager.close_grid(&test.view_id()).await.unwrap();
+    let editor = test.sdk.grid_manager.open_grid(&test.view_id()).await.unwrap();
+    assert_eq!(editor.field_last_modified(&field_id).await.unwrap(), renamed);
+    assert_eq!(editor.field_last_modified(&other_field_id).await.unwrap(), moved);
+    assert!(editor.field_last_modified(&date_field_id).await.unwrap() > legacy);
+
+    let error = editor.field_last_modified("unknown").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldRecordNotFound.value());
+}
@@ -1,6 +1,6 @@
 use crate::grid::filter_test::script::FilterScript::*;
 use crate::grid::filter_test::script::{FilterRowChanged, GridFilterTest};
-use flowy_grid::entities::CheckboxFilterConditionPB;
+use flowy_grid::entities::{CheckboxFilterConditionPB, NumberFilterConditionPB};
 
 #[tokio::test]
 async fn grid_filter_checkbox_is_check_test() {
@@ -35,3 +35,44 @@ async fn grid_filter_checkbox_is_uncheck_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_set_all_visible_checkbox_cells_test() {
+    let mut test = GridFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    // The prices are 1, 2, 3, 4, empty and 5, so the rows with 3, 4 and 5 are visible. Their
+    // checkbox cells are unchecked, unchecked and checked.
+    let expected = 3;
+    let scripts = vec![
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::GreaterThan,
+            content: "2".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertVisibleCheckboxCount {
+            checked: 1,
+            unchecked: 2,
+        },
+        SetAllCheckboxCells {
+            checked: true,
+            expected_number_of_updated_rows: expected,
+        },
+        AssertVisibleCheckboxCount {
+            checked: 3,
+            unchecked: 0,
+        },
+        // The row with the empty price is filtered out, it stays unchecked
+        AssertCheckboxCell {
+            row_index: 4,
+            checked: false,
+        },
+        AssertCheckboxCell {
+            row_index: 2,
+            checked: true,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -1,6 +1,7 @@
 use crate::grid::filter_test::script::FilterScript::*;
 use crate::grid::filter_test::script::{FilterRowChanged, GridFilterTest};
-use flowy_grid::entities::DateFilterConditionPB;
+use flowy_grid::entities::{DateFilterConditionPB, FieldType};
+use flowy_grid::services::field::DateCellChangeset;
 
 #[tokio::test]
 async fn grid_filter_date_is_test() {
@@ -106,3 +107,87 @@ async fn grid_filter_date_within_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_date_before_other_field_test() {
+    let mut test = GridFilterTest::new().await;
+    let other_field_id = create_start_date_field(&test).await;
+    let scripts = vec![
+        CreateDateFieldComparisonFilter {
+            condition: DateFilterConditionPB::DateBeforeField,
+            other_field_id,
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_date_before_other_field_after_updating_other_field_test() {
+    let mut test = GridFilterTest::new().await;
+    let other_field_id = create_start_date_field(&test).await;
+    let scripts = vec![
+        CreateDateFieldComparisonFilter {
+            condition: DateFilterConditionPB::DateBeforeField,
+            other_field_id: other_field_id.clone(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+
+    // Only the cell of the other field changes: 11/17/2022 is before 11/14/2023
+    let changeset = DateCellChangeset {
+        date: Some("1699920000".to_string()),
+        time: None,
+        is_utc: true,
+    };
+    test.editor
+        .update_cell_with_changeset(&test.row_revs[3].id, &other_field_id, changeset)
+        .await
+        .unwrap();
+    let scripts = vec![Wait { millisecond: 100 }, AssertNumberOfVisibleRows { expected: 2 }];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_date_after_other_field_test() {
+    let mut test = GridFilterTest::new().await;
+    let other_field_id = create_start_date_field(&test).await;
+    let scripts = vec![
+        CreateDateFieldComparisonFilter {
+            condition: DateFilterConditionPB::DateAfterField,
+            other_field_id,
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+// Creates another date field and fills the first, second and fourth rows. The rows without
+// the date of this field are excluded by the field comparison filter.
+//      row 0:  3/14/2022 is before 11/14/2022
+//      row 1:  3/14/2022 is after 3/1/2022
+//      row 3: 11/17/2022 is after 11/14/2022
+async fn create_start_date_field(test: &GridFilterTest) -> String {
+    let field_rev = test
+        .editor
+        .create_new_field_rev_with_type_option(&FieldType::DateTime, None)
+        .await
+        .unwrap();
+
+    for (row_index, timestamp) in vec![(0, 1668359085), (1, 1646092800), (3, 1668359085)] {
+        let changeset = DateCellChangeset {
+            date: Some(timestamp.to_string()),
+            time: None,
+            is_utc: true,
+        };
+        test.editor
+            .update_cell_with_changeset(&test.row_revs[row_index].id, &field_rev.id, changeset)
+            .await
+            .unwrap();
+    }
+    field_rev.id
+}
@@ -0,0 +1,33 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::GridFilterTest;
+use flowy_grid::entities::{CheckboxFilterConditionPB, FilterConjunctionPB, NumberFilterConditionPB};
+
+// The checked rows are the 1st, 2nd and 6th rows. The prices are 1, 2, 3, 4, empty and 5, so
+// the prices of the 3rd, 4th and 6th rows are greater than 2.
+#[tokio::test]
+async fn grid_filter_conjunction_and_or_test() {
+    let mut test = GridFilterTest::new().await;
+    let scripts = vec![
+        CreateCheckboxFilter {
+            condition: CheckboxFilterConditionPB::IsChecked,
+            changed: None,
+        },
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::GreaterThan,
+            content: "2".to_string(),
+            changed: None,
+        },
+        // Only the 6th row matches both filters
+        AssertNumberOfVisibleRows { expected: 1 },
+        UpdateFilterConjunction {
+            conjunction: FilterConjunctionPB::Or,
+        },
+        // The 5th row matches none of the filters
+        AssertNumberOfVisibleRows { expected: 5 },
+        UpdateFilterConjunction {
+            conjunction: FilterConjunctionPB::And,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -1,6 +1,8 @@
 use crate::grid::filter_test::script::FilterScript::*;
 use crate::grid::filter_test::script::{FilterRowChanged, GridFilterTest};
-use flowy_grid::entities::NumberFilterConditionPB;
+use flowy_grid::entities::{
+    CalculationOptions, CalculationTypePB, FieldType, NumberFilterConditionPB, NumberFilterRange,
+};
 
 #[tokio::test]
 async fn grid_filter_number_is_equal_test() {
@@ -116,3 +118,80 @@ async fn grid_filter_number_is_not_empty_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_number_is_between_test() {
+    let mut test = GridFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    // The rows whose price is 2, 3 and 4. The row with the empty price doesn't match
+    let expected = 3;
+    let content = NumberFilterRange {
+        min: "2".to_owned(),
+        max: "4".to_owned(),
+    };
+    let scripts = vec![
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::NumberIsBetween,
+            content: content.to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_is_between_reversed_bounds_test() {
+    let mut test = GridFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 3;
+    let content = NumberFilterRange {
+        min: "4".to_owned(),
+        max: "2".to_owned(),
+    };
+    let scripts = vec![
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::NumberIsBetween,
+            content: content.to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_sum_of_visible_rows_test() {
+    let mut test = GridFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let scripts = vec![CreateNumberFilter {
+        condition: NumberFilterConditionPB::LessThan,
+        content: "3".to_string(),
+        changed: Some(FilterRowChanged {
+            showing_num_of_rows: 0,
+            hiding_num_of_rows: row_count - 2,
+        }),
+    }];
+    test.run_scripts(scripts).await;
+
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let calculation = test.editor.calculate_sum(&test.view_id, &field_id).await.unwrap();
+    assert_eq!(calculation.value, "$3.00");
+
+    let options = CalculationOptions {
+        respect_filters: false,
+        ..Default::default()
+    };
+    let calculation = test
+        .editor
+        .calculate(&test.view_id, &field_id, CalculationTypePB::Sum, options)
+        .await
+        .unwrap();
+    assert_eq!(calculation.value, "$15.00");
+}
@@ -0,0 +1,46 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::*;
+use flowy_grid::entities::{FieldType, TextFilterConditionPB};
+use flowy_grid::services::cell::TypeCellData;
+use flowy_grid::services::filter::RowVisibilityPredicate;
+use grid_rev_model::RowRevision;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn grid_filter_hide_empty_primary_cell_with_predicate_test() {
+    let mut test = GridFilterTest::new().await;
+    let primary_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let predicate: RowVisibilityPredicate = Arc::new(move |row_rev: &RowRevision| {
+        row_rev
+            .cells
+            .get(&primary_field_id)
+            .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+            .map(|type_cell_data| !type_cell_data.cell_str.is_empty())
+            .unwrap_or(false)
+    });
+
+    // Only one row's text of the initial rows is ""
+    let scripts = vec![
+        SetRowVisibilityPredicate {
+            predicate: Some(predicate),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 5 },
+        // The predicate and the filters are combined by AND
+        CreateTextFilter {
+            condition: TextFilterConditionPB::Is,
+            content: "A".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+        SetRowVisibilityPredicate {
+            predicate: None,
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -7,13 +7,13 @@ use std::time::Duration;
 use bytes::Bytes;
 use futures::TryFutureExt;
 use tokio::sync::broadcast::Receiver;
-use flowy_grid::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, GridLayout, GridSettingChangesetParams, GridSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB};
-use flowy_grid::services::field::{SelectOptionCellChangeset, SelectOptionIds};
+use flowy_grid::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, GridLayout, GridSettingChangesetParams, GridSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB, FilterConjunctionPB};
+use flowy_grid::services::field::{CheckboxCellData, CheckboxCount, SelectOptionCellChangeset, SelectOptionIds};
 use flowy_grid::services::setting::GridSettingChangesetBuilder;
 use grid_rev_model::{FieldRevision, FieldTypeRevision};
 use flowy_database::schema::view_table::dsl::view_table;
-use flowy_grid::services::cell::insert_select_option_cell;
-use flowy_grid::services::filter::FilterType;
+use flowy_grid::services::cell::{insert_select_option_cell, FromCellString, TypeCellData};
+use flowy_grid::services::filter::{FilterType, RowVisibilityPredicate};
 use flowy_grid::services::view_editor::GridViewChanged;
 use crate::grid::grid_editor::GridEditorTest;
 
@@ -63,6 +63,11 @@ pub enum FilterScript {
         timestamp: Option<i64>,
         changed: Option<FilterRowChanged>,
     },
+    CreateDateFieldComparisonFilter {
+        condition: DateFilterConditionPB,
+        other_field_id: String,
+        changed: Option<FilterRowChanged>,
+    },
     CreateMultiSelectFilter {
         condition: SelectOptionConditionPB,
         option_ids: Vec<String>,
@@ -92,6 +97,33 @@ pub enum FilterScript {
     AssertNumberOfVisibleRows {
         expected: usize,
     },
+    AssertViewFilterCount {
+        view_id: String,
+        count: usize,
+    },
+    AssertNumberOfVisibleRowsInView {
+        view_id: String,
+        expected: usize,
+    },
+    UpdateFilterConjunction {
+        conjunction: FilterConjunctionPB,
+    },
+    SetAllCheckboxCells {
+        checked: bool,
+        expected_number_of_updated_rows: usize,
+    },
+    AssertVisibleCheckboxCount {
+        checked: usize,
+        unchecked: usize,
+    },
+    AssertCheckboxCell {
+        row_index: usize,
+        checked: bool,
+    },
+    SetRowVisibilityPredicate {
+        predicate: Option<RowVisibilityPredicate>,
+        changed: Option<FilterRowChanged>,
+    },
     #[allow(dead_code)]
     AssertGridSetting {
         expected_setting: GridSettingPB,
@@ -203,7 +235,25 @@ impl GridFilterTest {
                     condition,
                     start,
                     end,
-                    timestamp
+                    timestamp,
+                    ..Default::default()
+                };
+
+                let payload =
+                    AlterFilterPayloadPB::new( &self.view_id(), field_rev, date_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreateDateFieldComparisonFilter { condition, other_field_id, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::DateTime);
+                let date_filter = DateFilterPB {
+                    condition,
+                    start: None,
+                    end: None,
+                    timestamp: None,
+                    other_field_id: Some(other_field_id),
+                    ..Default::default()
                 };
 
                 let payload =
@@ -261,6 +311,40 @@ impl GridFilterTest {
                 let grid = self.editor.get_grid(&self.view_id()).await.unwrap();
                 assert_eq!(grid.rows.len(), expected);
             }
+            FilterScript::AssertViewFilterCount { view_id, count } => {
+                let setting = self.editor.get_view_setting(&view_id).await.unwrap();
+                assert_eq!(setting.filters.items.len(), count);
+            }
+            FilterScript::AssertNumberOfVisibleRowsInView { view_id, expected } => {
+                let grid = self.editor.get_grid(&view_id).await.unwrap();
+                assert_eq!(grid.rows.len(), expected);
+            }
+            FilterScript::UpdateFilterConjunction { conjunction } => {
+                self.editor.update_view_filter_conjunction(&self.view_id(), conjunction).await.unwrap();
+            }
+            FilterScript::SetAllCheckboxCells { checked, expected_number_of_updated_rows } => {
+                let field_id = self.get_first_field_rev(FieldType::Checkbox).id.clone();
+                let number_of_updated_rows = self.editor.set_all_checkbox_cells(&self.view_id(), &field_id, checked).await.unwrap();
+                assert_eq!(number_of_updated_rows, expected_number_of_updated_rows);
+            }
+            FilterScript::AssertVisibleCheckboxCount { checked, unchecked } => {
+                let field_id = self.get_first_field_rev(FieldType::Checkbox).id.clone();
+                let count = self.editor.count_checkbox_cells(&self.view_id(), &field_id).await.unwrap();
+                assert_eq!(count, CheckboxCount { checked, unchecked });
+            }
+            FilterScript::AssertCheckboxCell { row_index, checked } => {
+                let field_id = self.get_first_field_rev(FieldType::Checkbox).id.clone();
+                let row_id = self.row_revs[row_index].id.clone();
+                let cell_rev = self.editor.get_cell_rev(&row_id, &field_id).await.unwrap().unwrap();
+                let type_cell_data = TypeCellData::try_from(cell_rev).unwrap();
+                let cell_data = CheckboxCellData::from_cell_str(&type_cell_data.cell_str).unwrap();
+                assert_eq!(cell_data.is_check(), checked);
+            }
+            FilterScript::SetRowVisibilityPredicate { predicate, changed } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                self.editor.set_row_visibility_predicate(&self.view_id(), predicate).await.unwrap();
+            }
             FilterScript::Wait { millisecond } => {
                 tokio::time::sleep(Duration::from_millis(millisecond)).await;
             }
@@ -1,7 +1,9 @@
 use crate::grid::filter_test::script::FilterScript::*;
 use crate::grid::filter_test::script::*;
 use flowy_grid::entities::{AlterFilterPayloadPB, FieldType, TextFilterConditionPB, TextFilterPB};
+use flowy_grid::services::field::{RichTextTypeOptionBuilder, TypeOptionBuilder};
 use flowy_grid::services::filter::FilterType;
+use grid_rev_model::TypeOptionDataSerializer;
 
 #[tokio::test]
 async fn grid_filter_text_is_empty_test() {
@@ -239,3 +241,95 @@ async fn grid_filter_update_empty_text_cell_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_duplicate_view_test() {
+    let mut test = GridFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    test.run_scripts(vec![CreateTextFilter {
+        condition: TextFilterConditionPB::TextIsEmpty,
+        content: "".to_string(),
+        changed: None,
+    }])
+    .await;
+
+    let view_id = test.view_id();
+    let duplicated_view_id = test.editor.duplicate_view(&view_id).await.unwrap();
+    assert_ne!(view_id, duplicated_view_id);
+    test.run_scripts(vec![
+        AssertViewFilterCount {
+            view_id: duplicated_view_id.clone(),
+            count: 1,
+        },
+        AssertNumberOfVisibleRowsInView {
+            view_id: duplicated_view_id.clone(),
+            expected: 1,
+        },
+        // The views share the rows, so the edit is visible in both of them
+        UpdateTextCell {
+            row_id: row_revs[0].id.clone(),
+            text: "".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+        AssertNumberOfVisibleRowsInView {
+            view_id: duplicated_view_id,
+            expected: 2,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_filter_empty_string_is_empty_cell_by_default_test() {
+    let mut test = GridFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let scripts = vec![
+        CreateTextFilter {
+            condition: TextFilterConditionPB::TextIsEmpty,
+            content: "".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+        UpdateTextCell {
+            row_id: row_revs[2].id.clone(),
+            text: "".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_text_is_empty_with_kept_empty_string_test() {
+    let mut test = GridFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+    let type_option_data = RichTextTypeOptionBuilder::default()
+        .keep_empty_string(true)
+        .serializer()
+        .protobuf_bytes()
+        .to_vec();
+    test.editor
+        .update_field_type_option(&test.view_id(), &field_rev.id, type_option_data, None)
+        .await
+        .unwrap();
+
+    // The second row's text is the empty string, which is a value now
+    let scripts = vec![
+        CreateTextFilter {
+            condition: TextFilterConditionPB::TextIsEmpty,
+            content: "".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 0 },
+        UpdateTextCell {
+            row_id: row_revs[2].id.clone(),
+            text: "".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 0 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -283,6 +283,27 @@ fn make_test_grid() -> BuildGridContext {
                 let checklist_field = FieldBuilder::new(checklist).name("TODO").visibility(true).build();
                 grid_builder.add_field(checklist_field);
             }
+            FieldType::Formula => {
+                let formula = FormulaTypeOptionBuilder::default();
+                let formula_field = FieldBuilder::new(formula).name("Total").visibility(true).build();
+                grid_builder.add_field(formula_field);
+            }
+            FieldType::CreatedBy => {
+                let created_by = CreatedByTypeOptionBuilder::default();
+                let created_by_field = FieldBuilder::new(created_by)
+                    .name("Created By")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(created_by_field);
+            }
+            FieldType::LastEditedBy => {
+                let last_edited_by = LastEditedByTypeOptionBuilder::default();
+                let last_edited_by_field = FieldBuilder::new(last_edited_by)
+                    .name("Last Edited By")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(last_edited_by_field);
+            }
         }
     }
 
@@ -458,6 +479,27 @@ fn make_test_board() -> BuildGridContext {
                 let checklist_field = FieldBuilder::new(checklist).name("TODO").visibility(true).build();
                 grid_builder.add_field(checklist_field);
             }
+            FieldType::Formula => {
+                let formula = FormulaTypeOptionBuilder::default();
+                let formula_field = FieldBuilder::new(formula).name("Total").visibility(true).build();
+                grid_builder.add_field(formula_field);
+            }
+            FieldType::CreatedBy => {
+                let created_by = CreatedByTypeOptionBuilder::default();
+                let created_by_field = FieldBuilder::new(created_by)
+                    .name("Created By")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(created_by_field);
+            }
+            FieldType::LastEditedBy => {
+                let last_edited_by = LastEditedByTypeOptionBuilder::default();
+                let last_edited_by_field = FieldBuilder::new(last_edited_by)
+                    .name("Last Edited By")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(last_edited_by_field);
+            }
         }
     }
 
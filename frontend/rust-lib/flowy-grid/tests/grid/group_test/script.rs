@@ -2,12 +2,16 @@ use crate::grid::grid_editor::GridEditorTest;
 use flowy_grid::entities::{
     CreateRowParams, FieldType, GridLayout, GroupPB, MoveGroupParams, MoveGroupRowParams, RowPB,
 };
-use flowy_grid::services::cell::{delete_select_option_cell, insert_select_option_cell};
+use flowy_grid::services::cell::{delete_select_option_cell, insert_select_option_cell, TypeCellData};
 use flowy_grid::services::field::{
-    edit_single_select_type_option, SelectOptionPB, SelectTypeOptionSharedAction, SingleSelectTypeOptionPB,
+    edit_single_select_type_option, SelectOptionColorPB, SelectOptionPB, SelectTypeOptionSharedAction,
+    SingleSelectTypeOptionPB,
 };
-use grid_rev_model::{FieldRevision, RowChangeset};
+use flowy_revision::REVISION_WRITE_INTERVAL_IN_MILLIS;
+use grid_rev_model::{DateCondition, FieldRevision, RowChangeset};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
 
 pub enum GroupScript {
     AssertGroupRowCount {
@@ -42,6 +46,15 @@ pub enum GroupScript {
         row_index: usize,
         to_group_index: usize,
     },
+    MoveRowToGroup {
+        from_group_index: usize,
+        row_index: usize,
+        to_group_index: usize,
+    },
+    AssertSingleSelectCell {
+        row_id: String,
+        option_id: String,
+    },
     MoveGroup {
         from_group_index: usize,
         to_group_index: usize,
@@ -52,6 +65,23 @@ pub enum GroupScript {
     GroupByField {
         field_id: String,
     },
+    UpdateGroupHideEmpty(bool),
+    UpdateDateGroupCondition {
+        field_id: String,
+        condition: DateCondition,
+    },
+    AssertGroupName {
+        group_index: usize,
+        name: String,
+    },
+    AssertGroupColor {
+        group_index: usize,
+        color: Option<SelectOptionColorPB>,
+    },
+    AssertNoStatusGroupVisible(bool),
+    AssertGridRowCount(usize),
+    /// Closes the grid and opens it again, which reloads the groups from the saved configuration.
+    ReopenGrid,
 }
 
 pub struct GridGroupTest {
@@ -165,6 +195,32 @@ impl GridGroupTest {
                 row_changeset.cell_by_field_id.insert(field_id, cell_rev);
                 self.editor.update_row(row_changeset).await.unwrap();
             }
+            GroupScript::MoveRowToGroup {
+                from_group_index,
+                row_index,
+                to_group_index,
+            } => {
+                let row_id = self.row_at_index(from_group_index, row_index).await.id;
+                let to_group = self.group_at_index(to_group_index).await;
+                self.editor
+                    .move_row_to_group(&row_id, &to_group.group_id)
+                    .await
+                    .unwrap();
+            }
+            GroupScript::AssertSingleSelectCell { row_id, option_id } => {
+                let field_rev = self.get_single_select_field().await;
+                let row_rev = self.editor.get_row_rev(&row_id).await.unwrap().unwrap();
+                let cell_rev = row_rev.cells.get(&field_rev.id).unwrap();
+                let type_cell_data = TypeCellData::try_from(cell_rev).unwrap();
+                assert_eq!(type_cell_data.cell_str, option_id);
+            }
+            GroupScript::ReopenGrid => {
+                let view_id = self.inner.view_id.clone();
+                // Wait for the pending revisions to be written to the disk
+                sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+                self.sdk.grid_manager.close_grid(&view_id).await.unwrap();
+                self.inner.editor = self.sdk.grid_manager.open_grid(&view_id).await.unwrap();
+            }
             GroupScript::MoveGroup {
                 from_group_index,
                 to_group_index,
@@ -198,6 +254,30 @@ impl GridGroupTest {
             GroupScript::GroupByField { field_id } => {
                 self.editor.group_by_field(&field_id).await.unwrap();
             }
+            GroupScript::UpdateGroupHideEmpty(hide_empty) => {
+                self.editor.update_group_hide_empty(hide_empty).await.unwrap();
+            }
+            GroupScript::UpdateDateGroupCondition { field_id, condition } => {
+                self.editor
+                    .update_date_group_condition(&field_id, condition)
+                    .await
+                    .unwrap();
+            }
+            GroupScript::AssertGroupName { group_index, name } => {
+                let group = self.group_at_index(group_index).await;
+                assert_eq!(group.desc, name);
+            }
+            GroupScript::AssertGroupColor { group_index, color } => {
+                let group = self.group_at_index(group_index).await;
+                assert_eq!(group.color, color);
+            }
+            GroupScript::AssertNoStatusGroupVisible(visible) => {
+                let groups = self.editor.load_groups().await.unwrap().items;
+                assert_eq!(visible, groups.iter().any(|group| group.is_default));
+            }
+            GroupScript::AssertGridRowCount(count) => {
+                assert_eq!(count, self.get_row_revs().await.len());
+            }
         }
     }
 
@@ -226,6 +306,18 @@ impl GridGroupTest {
         field
     }
 
+    pub async fn get_date_field(&self) -> Arc<FieldRevision> {
+        self.inner
+            .field_revs
+            .iter()
+            .find(|field_rev| {
+                let field_type: FieldType = field_rev.ty.into();
+                field_type.is_date()
+            })
+            .unwrap()
+            .clone()
+    }
+
     pub async fn get_single_select_field(&self) -> Arc<FieldRevision> {
         self.inner
             .field_revs
@@ -1,7 +1,13 @@
 use crate::grid::group_test::script::GridGroupTest;
 use crate::grid::group_test::script::GroupScript::*;
 
-use flowy_grid::services::field::SelectOptionPB;
+use flowy_error::ErrorCode;
+use flowy_grid::entities::FieldType;
+use flowy_grid::services::field::{
+    DateCellChangeset, SelectOptionColorPB, SelectOptionPB, SelectTypeOptionSharedAction, SingleSelectTypeOptionPB,
+};
+use flowy_grid::services::view_editor::GridViewChanged;
+use grid_rev_model::DateCondition;
 
 #[tokio::test]
 async fn group_init_test() {
@@ -81,6 +87,38 @@ async fn group_move_row_to_other_group_test() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn group_move_row_to_the_end_of_other_group_test() {
+    let mut test = GridGroupTest::new().await;
+    let row = test.row_at_index(1, 0).await;
+    let to_group = test.group_at_index(2).await;
+    let scripts = vec![
+        MoveRowToGroup {
+            from_group_index: 1,
+            row_index: 0,
+            to_group_index: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 1,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 3,
+        },
+        AssertRow {
+            group_index: 2,
+            row_index: 2,
+            row: row.clone(),
+        },
+        AssertSingleSelectCell {
+            row_id: row.id,
+            option_id: to_group.group_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn group_move_two_row_to_other_group_test() {
     let mut test = GridGroupTest::new().await;
@@ -450,6 +488,37 @@ async fn group_move_group_to_default_group_pos_test() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn group_order_persists_test() {
+    let mut test = GridGroupTest::new().await;
+    let group_1 = test.group_at_index(1).await;
+    let group_3 = test.group_at_index(3).await;
+    let new_option = SelectOptionPB::new("New option");
+    let scripts = vec![
+        MoveGroup {
+            from_group_index: 3,
+            to_group_index: 1,
+        },
+        // The group of the new option is appended after the reordered groups
+        UpdateSingleSelectSelectOption {
+            inserted_options: vec![new_option.clone()],
+        },
+        ReopenGrid,
+        AssertGroupCount(5),
+        AssertGroup {
+            group_index: 1,
+            expected_group: group_3,
+        },
+        AssertGroup {
+            group_index: 2,
+            expected_group: group_1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+    let new_group = test.group_at_index(4).await;
+    assert_eq!(new_group.group_id, new_option.id);
+}
+
 #[tokio::test]
 async fn group_insert_single_select_option_test() {
     let mut test = GridGroupTest::new().await;
@@ -466,6 +535,34 @@ async fn group_insert_single_select_option_test() {
     assert_eq!(new_group.desc, new_option_name);
 }
 
+#[tokio::test]
+async fn group_insert_single_select_option_with_icon_test() {
+    let mut test = GridGroupTest::new().await;
+    let mut new_option = SelectOptionPB::new("Urgent");
+    new_option.icon = Some("🔥".to_owned());
+    let scripts = vec![
+        UpdateSingleSelectSelectOption {
+            inserted_options: vec![new_option.clone()],
+        },
+        AssertGroupCount(5),
+    ];
+    test.run_scripts(scripts).await;
+
+    // The icon survives the serialization of the type option
+    let field_id = test.get_single_select_field().await.id.clone();
+    let field_rev = test.editor.get_field_rev(&field_id).await.unwrap();
+    let type_option = SingleSelectTypeOptionPB::from(&field_rev);
+    let option = type_option
+        .options
+        .iter()
+        .find(|option| option.id == new_option.id)
+        .unwrap();
+    assert_eq!(option.icon, Some("🔥".to_owned()));
+
+    let new_group = test.group_at_index(4).await;
+    assert_eq!(new_group.desc, "🔥 Urgent");
+}
+
 #[tokio::test]
 async fn group_group_by_other_field() {
     let mut test = GridGroupTest::new().await;
@@ -486,3 +583,408 @@ async fn group_group_by_other_field() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn group_hide_empty_group_test() {
+    let mut test = GridGroupTest::new().await;
+    let row_count = test.get_row_revs().await.len();
+    let scripts = vec![
+        // Move one row to the no status group
+        UpdateRow {
+            from_group_index: 1,
+            row_index: 0,
+            to_group_index: 0,
+        },
+        UpdateGroupHideEmpty(true),
+        AssertGroupCount(3),
+        AssertNoStatusGroupVisible(false),
+        AssertGridRowCount(row_count),
+        UpdateGroupHideEmpty(false),
+        AssertGroupCount(4),
+        AssertNoStatusGroupVisible(true),
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_date_month_test() {
+    let mut test = GridGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: date_field.id.clone(),
+        },
+        // The default condition groups the dates relative to the current date
+        UpdateDateGroupCondition {
+            field_id: date_field.id.clone(),
+            condition: DateCondition::Month,
+        },
+        AssertGroupCount(3),
+        AssertGroupName {
+            group_index: 0,
+            name: "No Date".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 0,
+        },
+        AssertGroupName {
+            group_index: 1,
+            name: "2022-03".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 3,
+        },
+        AssertGroupName {
+            group_index: 2,
+            name: "2022-11".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 2,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_date_update_condition_test() {
+    let mut test = GridGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    let scripts = vec![
+        UpdateDateGroupCondition {
+            field_id: date_field.id.clone(),
+            condition: DateCondition::Day,
+        },
+        AssertGroupCount(4),
+        AssertGroupName {
+            group_index: 1,
+            name: "2022-03-14".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 3,
+        },
+        AssertGroupName {
+            group_index: 2,
+            name: "2022-11-13".to_owned(),
+        },
+        AssertGroupName {
+            group_index: 3,
+            name: "2022-11-17".to_owned(),
+        },
+        UpdateDateGroupCondition {
+            field_id: date_field.id.clone(),
+            condition: DateCondition::Year,
+        },
+        AssertGroupCount(2),
+        AssertGroupName {
+            group_index: 1,
+            name: "2022".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 5,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_update_single_select_option_color_test() {
+    let mut test = GridGroupTest::new().await;
+    let group = test.group_at_index(1).await;
+    let field_id = test.get_single_select_field().await.id.clone();
+    let field_rev = test.editor.get_field_rev(&field_id).await.unwrap();
+    let mut option = SingleSelectTypeOptionPB::from(&field_rev)
+        .options
+        .into_iter()
+        .find(|option| option.id == group.group_id)
+        .unwrap();
+    assert_ne!(option.color, SelectOptionColorPB::Blue);
+
+    let scripts = vec![
+        AssertGroupColor {
+            group_index: 1,
+            color: Some(option.color.clone()),
+        },
+        // The no status group is not generated from an option
+        AssertGroupColor {
+            group_index: 0,
+            color: None,
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    option.color = SelectOptionColorPB::Blue;
+    let scripts = vec![
+        UpdateSingleSelectSelectOption {
+            inserted_options: vec![option],
+        },
+        AssertGroupCount(4),
+        AssertGroupColor {
+            group_index: 1,
+            color: Some(SelectOptionColorPB::Blue),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_rename_single_select_option_keeps_rows_test() {
+    let mut test = GridGroupTest::new().await;
+    let group = test.group_at_index(1).await;
+    let field_id = test.get_single_select_field().await.id.clone();
+    let field_rev = test.editor.get_field_rev(&field_id).await.unwrap();
+    let mut option = SingleSelectTypeOptionPB::from(&field_rev)
+        .options
+        .into_iter()
+        .find(|option| option.id == group.group_id)
+        .unwrap();
+    option.name = "Renamed".to_owned();
+
+    let scripts = vec![
+        UpdateSingleSelectSelectOption {
+            inserted_options: vec![option],
+        },
+        AssertGroupCount(4),
+        AssertGroupName {
+            group_index: 1,
+            name: "Renamed".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    // The group is identified by the option id, so it keeps its rows
+    let renamed_group = test.group_at_index(1).await;
+    assert_eq!(renamed_group.group_id, group.group_id);
+    assert!(!group.rows.is_empty());
+    assert_eq!(renamed_group.rows, group.rows);
+}
+
+#[tokio::test]
+async fn group_rename_single_select_option_notifies_updated_group_test() {
+    let test = GridGroupTest::new().await;
+    let group = test.group_at_index(1).await;
+    let field_id = test.get_single_select_field().await.id.clone();
+    let field_rev = test.editor.get_field_rev(&field_id).await.unwrap();
+    let mut option = SingleSelectTypeOptionPB::from(&field_rev)
+        .options
+        .into_iter()
+        .find(|option| option.id == group.group_id)
+        .unwrap();
+    assert_ne!(option.color, SelectOptionColorPB::Blue);
+    option.name = "Renamed".to_owned();
+    option.color = SelectOptionColorPB::Blue;
+
+    let mut receiver = test.editor.subscribe_view_changed(&test.view_id).await.unwrap();
+    test.edit_single_select_type_option(|type_option| type_option.insert_option(option))
+        .await;
+    let changeset = loop {
+        match receiver.try_recv().unwrap() {
+            GridViewChanged::GroupViewNotification(changeset) => break changeset,
+            _ => continue,
+        }
+    };
+
+    // The group is updated in place with its rows
+    assert!(changeset.new_groups.is_empty());
+    assert!(changeset.deleted_groups.is_empty());
+    assert_eq!(changeset.update_groups.len(), 1);
+    let updated_group = &changeset.update_groups[0];
+    assert_eq!(updated_group.group_id, group.group_id);
+    assert_eq!(updated_group.desc, "Renamed");
+    assert_eq!(updated_group.color, Some(SelectOptionColorPB::Blue));
+    assert_eq!(updated_group.rows, group.rows);
+}
+
+#[tokio::test]
+async fn group_set_option_color_test() {
+    let mut test = GridGroupTest::new().await;
+    let group = test.group_at_index(1).await;
+    let field_id = test.get_single_select_field().await.id.clone();
+    test.editor
+        .set_option_color(&field_id, &group.group_id, SelectOptionColorPB::Blue)
+        .await
+        .unwrap();
+
+    let field_rev = test.editor.get_field_rev(&field_id).await.unwrap();
+    let option = SingleSelectTypeOptionPB::from(&field_rev)
+        .options
+        .into_iter()
+        .find(|option| option.id == group.group_id)
+        .unwrap();
+    assert_eq!(option.color, SelectOptionColorPB::Blue);
+
+    let scripts = vec![
+        AssertGroupCount(4),
+        AssertGroupColor {
+            group_index: 1,
+            color: Some(SelectOptionColorPB::Blue),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    // Setting the same color again doesn't produce a revision
+    let rev_id = test.editor.rev_manager().rev_id();
+    test.editor
+        .set_option_color(&field_id, &group.group_id, SelectOptionColorPB::Blue)
+        .await
+        .unwrap();
+    assert_eq!(test.editor.rev_manager().rev_id(), rev_id);
+
+    let error = test
+        .editor
+        .set_option_color(&field_id, "unknown", SelectOptionColorPB::Blue)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::SelectOptionNotFound.value());
+}
+
+#[tokio::test]
+async fn group_summaries_with_number_sum_test() {
+    let test = GridGroupTest::new().await;
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let summaries = test
+        .editor
+        .group_summaries(&test.view_id, Some(&number_field_id))
+        .await
+        .unwrap();
+    let values = summaries
+        .iter()
+        .map(|summary| (summary.count, summary.sum.clone().unwrap()))
+        .collect::<Vec<(i32, String)>>();
+    // The No Status group is empty, and the price of the Paused row is empty
+    assert_eq!(
+        values,
+        vec![
+            (0, "$0.00".to_owned()),
+            (2, "$3.00".to_owned()),
+            (2, "$7.00".to_owned()),
+            (1, "$0.00".to_owned()),
+        ]
+    );
+
+    let summaries = test.editor.group_summaries(&test.view_id, None).await.unwrap();
+    assert!(summaries.iter().all(|summary| summary.sum.is_none()));
+
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let error = test
+        .editor
+        .group_summaries(&test.view_id, Some(&text_field_id))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldTypeMismatch.value());
+}
+
+#[tokio::test]
+async fn group_archive_row_test() {
+    let mut test = GridGroupTest::new().await;
+    // The first row is in the Completed group
+    let row_id = test.row_revs[0].id.clone();
+    test.editor.archive_row(&row_id).await.unwrap();
+    test.run_scripts(vec![AssertGroupRowCount {
+        group_index: 1,
+        row_count: 1,
+    }])
+    .await;
+    let summaries = test.editor.group_summaries(&test.view_id, None).await.unwrap();
+    assert_eq!(summaries.iter().map(|summary| summary.count).sum::<i32>(), 4);
+
+    test.editor
+        .update_view_show_archived_rows(&test.view_id, true)
+        .await
+        .unwrap();
+    test.run_scripts(vec![AssertGroupRowCount {
+        group_index: 1,
+        row_count: 2,
+    }])
+    .await;
+    test.editor
+        .update_view_show_archived_rows(&test.view_id, false)
+        .await
+        .unwrap();
+
+    test.editor.unarchive_row(&row_id).await.unwrap();
+    test.run_scripts(vec![AssertGroupRowCount {
+        group_index: 1,
+        row_count: 2,
+    }])
+    .await;
+    let summaries = test.editor.group_summaries(&test.view_id, None).await.unwrap();
+    assert_eq!(summaries.iter().map(|summary| summary.count).sum::<i32>(), 5);
+}
+
+#[tokio::test]
+async fn group_by_date_insert_new_group_in_order_test() {
+    let mut test = GridGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    test.run_scripts(vec![
+        GroupByField {
+            field_id: date_field.id.clone(),
+        },
+        AssertGroupCount(3),
+    ])
+    .await;
+
+    // 2022-06-01 falls between the groups of 2022-03 and 2022-11
+    let row = test.row_at_index(1, 0).await;
+    let changeset = DateCellChangeset {
+        date: Some("1654041600".to_string()),
+        time: None,
+        is_utc: true,
+    };
+    test.editor
+        .update_cell_with_changeset(&row.id, &date_field.id, changeset)
+        .await
+        .unwrap();
+    test.run_scripts(vec![
+        AssertGroupCount(4),
+        AssertGroupName {
+            group_index: 2,
+            name: "2022-06".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 1,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        AssertGroupName {
+            group_index: 3,
+            name: "2022-11".to_owned(),
+        },
+    ])
+    .await;
+
+    // The relative groups are named after the period
+    let row = test.row_at_index(1, 0).await;
+    let changeset = DateCellChangeset {
+        date: Some(chrono::Utc::now().timestamp().to_string()),
+        time: None,
+        is_utc: true,
+    };
+    test.editor
+        .update_cell_with_changeset(&row.id, &date_field.id, changeset)
+        .await
+        .unwrap();
+    test.run_scripts(vec![
+        AssertGroupCount(5),
+        AssertGroupName {
+            group_index: 4,
+            name: "Today".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 4,
+            row_count: 1,
+        },
+    ])
+    .await;
+}
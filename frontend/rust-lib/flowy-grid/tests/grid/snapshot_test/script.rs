@@ -24,6 +24,10 @@ pub enum SnapshotScript {
     DeleteField {
         field_rev: FieldRevision,
     },
+    /// Acks all the revisions as if they were synced to the server
+    AckRevisions,
+    CompactRevisions,
+    AssertNumberOfRevisions(usize),
 }
 
 pub struct GridSnapshotTest {
@@ -58,6 +62,12 @@ impl GridSnapshotTest {
         }
     }
 
+    /// Returns the number of the revisions of the grid that are written to the disk.
+    pub async fn number_of_revisions(&self) -> usize {
+        sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+        self.editor.rev_manager().load_revisions().await.unwrap().len()
+    }
+
     pub async fn get_latest_snapshot(&self) -> Option<RevisionSnapshot> {
         self.editor.rev_manager().read_snapshot(None).await.unwrap()
     }
@@ -87,6 +97,17 @@ impl GridSnapshotTest {
             SnapshotScript::DeleteField { field_rev } => {
                 self.editor.delete_field(&field_rev.id).await.unwrap();
             }
+            SnapshotScript::AckRevisions => {
+                while let Some(rev_id) = rev_manager.next_sync_rev_id().await {
+                    rev_manager.ack_revision(rev_id).await.unwrap();
+                }
+            }
+            SnapshotScript::CompactRevisions => {
+                self.editor.compact_revisions(&self.view_id).await.unwrap();
+            }
+            SnapshotScript::AssertNumberOfRevisions(expected) => {
+                assert_eq!(self.number_of_revisions().await, expected);
+            }
         }
     }
 }
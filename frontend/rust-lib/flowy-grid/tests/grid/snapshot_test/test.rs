@@ -1,5 +1,6 @@
 use crate::grid::field_test::util::create_text_field;
 use crate::grid::snapshot_test::script::{GridSnapshotTest, SnapshotScript::*};
+use flowy_revision::RECENT_REVISIONS_AFTER_COMPACTION;
 
 #[tokio::test]
 async fn snapshot_create_test() {
@@ -43,3 +44,45 @@ async fn snapshot_multi_version_test() {
     }])
     .await;
 }
+
+#[tokio::test]
+async fn snapshot_compact_revisions_test() {
+    let mut test = GridSnapshotTest::new().await;
+    let (_, field_rev) = create_text_field(&test.grid_id());
+    let mut scripts = vec![CreateField {
+        field_rev: field_rev.clone(),
+    }];
+    for _ in 0..RECENT_REVISIONS_AFTER_COMPACTION {
+        let (_, other_field_rev) = create_text_field(&test.grid_id());
+        scripts.push(CreateField {
+            field_rev: other_field_rev,
+        });
+    }
+    scripts.push(DeleteField { field_rev });
+    test.run_scripts(scripts).await;
+    let number_of_revisions = test.number_of_revisions().await;
+    assert!(number_of_revisions > RECENT_REVISIONS_AFTER_COMPACTION + 1);
+
+    // The revisions that are not acked yet are kept, so they can still be synced
+    test.run_scripts(vec![CompactRevisions, AssertNumberOfRevisions(number_of_revisions)])
+        .await;
+
+    // The old revisions are squashed into the base revision, the recent ones are kept
+    let pad = test.grid_pad().await;
+    let content = pad.json_str().unwrap();
+    let operations = pad.operations_json_str();
+    test.run_scripts(vec![
+        AckRevisions,
+        CompactRevisions,
+        AssertNumberOfRevisions(RECENT_REVISIONS_AFTER_COMPACTION + 1),
+    ])
+    .await;
+    assert_eq!(test.grid_pad().await.json_str().unwrap(), content);
+
+    // The grid that is opened from the compacted revisions is the same
+    test.sdk.grid_manager.close_grid(&test.view_id).await.unwrap();
+    let editor = test.sdk.grid_manager.open_grid(&test.view_id).await.unwrap();
+    let pad = editor.grid_pad().read().await.clone();
+    assert_eq!(pad.json_str().unwrap(), content);
+    assert_eq!(pad.operations_json_str(), operations);
+}
@@ -1,6 +1,9 @@
 use crate::grid::grid_editor::GridEditorTest;
 use async_stream::stream;
-use flowy_grid::entities::{AlterSortParams, CellPathParams, DeleteSortParams};
+use flowy_grid::entities::{
+    AlterFilterParams, AlterFilterPayloadPB, AlterSortParams, CellPathParams, CreateRowParams, DeleteSortParams,
+    FieldType, GridLayout, TextFilterConditionPB, TextFilterPB,
+};
 use flowy_grid::services::sort::SortType;
 use flowy_grid::services::view_editor::GridViewChanged;
 use futures::stream::StreamExt;
@@ -27,10 +30,19 @@ pub enum SortScript {
         row_id: String,
         text: String,
     },
+    CreateEmptyRow,
     AssertSortChanged {
         old_row_orders: Vec<&'static str>,
         new_row_orders: Vec<&'static str>,
     },
+    InsertTextFilter {
+        condition: TextFilterConditionPB,
+        content: String,
+    },
+    ResetViewSettings,
+    AssertNumberOfVisibleRows {
+        expected: usize,
+    },
     Wait {
         millis: u64,
     },
@@ -104,6 +116,16 @@ impl GridSortTest {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id).await.unwrap());
                 self.update_text_cell(row_id, &text).await;
             }
+            SortScript::CreateEmptyRow => {
+                let params = CreateRowParams {
+                    grid_id: self.editor.grid_id.clone(),
+                    start_row_id: None,
+                    group_id: None,
+                    layout: GridLayout::Table,
+                };
+                let _ = self.editor.create_row(params).await.unwrap();
+                self.row_revs = self.get_row_revs().await;
+            }
             SortScript::AssertSortChanged {
                 new_row_orders,
                 old_row_orders,
@@ -117,6 +139,21 @@ impl GridSortTest {
                     .await;
                 }
             }
+            SortScript::InsertTextFilter { condition, content } => {
+                let field_rev = self.get_first_field_rev(FieldType::RichText);
+                let text_filter = TextFilterPB { condition, content };
+                let payload = AlterFilterPayloadPB::new(&self.view_id, field_rev, text_filter);
+                let params: AlterFilterParams = payload.try_into().unwrap();
+                self.editor.create_or_update_filter(params).await.unwrap();
+            }
+            SortScript::ResetViewSettings => {
+                self.editor.reset_view_settings(&self.view_id).await.unwrap();
+                self.current_sort_rev = None;
+            }
+            SortScript::AssertNumberOfVisibleRows { expected } => {
+                let grid = self.editor.get_grid(&self.view_id).await.unwrap();
+                assert_eq!(grid.rows.len(), expected);
+            }
             SortScript::Wait { millis } => {
                 tokio::time::sleep(Duration::from_millis(millis)).await;
             }
@@ -1,5 +1,6 @@
 use crate::grid::sort_test::script::{GridSortTest, SortScript::*};
-use flowy_grid::entities::FieldType;
+use flowy_grid::entities::{FieldType, TextFilterConditionPB};
+use flowy_grid::services::field::edit_single_select_type_option;
 use grid_rev_model::SortCondition;
 
 #[tokio::test]
@@ -116,6 +117,43 @@ async fn sort_checkbox_by_ascending_test() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn sort_checkbox_with_unchecked_first_test() {
+    let mut test = GridSortTest::new().await;
+    let checkbox_field = test.get_first_field_rev(FieldType::Checkbox).clone();
+    // The new row has no checkbox cell, which is sorted as unchecked
+    let scripts = vec![
+        CreateEmptyRow,
+        InsertSort {
+            field_rev: checkbox_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: checkbox_field.id.clone(),
+            orders: vec!["No", "No", "No", "", "Yes", "Yes", "Yes"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_checkbox_with_checked_first_test() {
+    let mut test = GridSortTest::new().await;
+    let checkbox_field = test.get_first_field_rev(FieldType::Checkbox).clone();
+    let scripts = vec![
+        CreateEmptyRow,
+        InsertSort {
+            field_rev: checkbox_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertCellContentOrder {
+            field_id: checkbox_field.id.clone(),
+            orders: vec!["Yes", "Yes", "Yes", "No", "No", "No", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn sort_checkbox_by_descending_test() {
     let mut test = GridSortTest::new().await;
@@ -255,3 +293,86 @@ async fn sort_multi_select_by_ascending_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn sort_and_filter_then_reset_view_settings_test() {
+    let mut test = GridSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let scripts = vec![
+        InsertTextFilter {
+            condition: TextFilterConditionPB::Contains,
+            content: "A".to_string(),
+        },
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertNumberOfVisibleRows { expected: 4 },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DA", "AE", "AE", "A"],
+        },
+        ResetViewSettings,
+        AssertNumberOfVisibleRows { expected: 6 },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "", "C", "DA", "AE", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_and_filter_grid_as_matrix_test() {
+    let mut test = GridSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let scripts = vec![
+        InsertTextFilter {
+            condition: TextFilterConditionPB::Contains,
+            content: "A".to_string(),
+        },
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertNumberOfVisibleRows { expected: 4 },
+    ];
+    test.run_scripts(scripts).await;
+
+    // The matrix only holds the visible rows, in the sorted order
+    let matrix = test.editor.grid_as_matrix(&test.view_id).await.unwrap();
+    assert_eq!(matrix.len(), 4);
+    assert!(matrix.iter().all(|row| row.len() == test.field_revs.len()));
+    let field_revs = test.editor.get_view_field_revs(&test.view_id).await.unwrap();
+    let index = field_revs
+        .iter()
+        .position(|field_rev| field_rev.id == text_field.id)
+        .unwrap();
+    let texts = matrix.iter().map(|row| row[index].as_str()).collect::<Vec<_>>();
+    assert_eq!(texts, vec!["DA", "AE", "AE", "A"]);
+}
+
+#[tokio::test]
+async fn sort_scale_single_select_by_option_position_test() {
+    let mut test = GridSortTest::new().await;
+    let single_select = test.get_first_field_rev(FieldType::SingleSelect).clone();
+    // Move the `Planned` option before the `Completed` option, and treat the options as a scale.
+    edit_single_select_type_option(&single_select.id, test.editor.clone(), |type_option| {
+        type_option.options.swap(0, 1);
+        type_option.is_scale = true;
+    })
+    .await
+    .unwrap();
+
+    let scripts = vec![
+        InsertSort {
+            field_rev: single_select.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: single_select.id.clone(),
+            orders: vec!["", "", "Planned", "Planned", "Completed", "Completed"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -88,6 +88,10 @@ impl RevisionMemoryCache {
         Ok(revs)
     }
 
+    pub(crate) fn records(&self) -> Vec<SyncRecord> {
+        self.revs_map.iter().map(|record| record.value().clone()).collect()
+    }
+
     pub(crate) fn number_of_sync_records(&self) -> usize {
         self.revs_map.len()
     }
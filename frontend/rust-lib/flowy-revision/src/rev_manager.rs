@@ -190,6 +190,18 @@ impl<Connection: 'static> RevisionManager<Connection> {
         Ok(())
     }
 
+    /// Squashes the acked revisions into one base revision, except the `keep_recent` most recent
+    /// ones, which are applied on top of the base revision. So the object stays the same and the
+    /// recent edits are still kept as the separate revisions. The revisions that are not acked yet
+    /// are kept as they are, so they can still be synced. The current revision id is kept, so the
+    /// next revisions continue from it.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn compact_revisions(&self, keep_recent: usize) -> FlowyResult<()> {
+        self.rev_persistence
+            .compact_acked_revisions(keep_recent, &self.rev_compress)
+            .await
+    }
+
     #[tracing::instrument(level = "debug", skip(self, revision), err)]
     pub async fn add_remote_revision(&self, revision: &Revision) -> Result<(), FlowyError> {
         if revision.bytes.is_empty() {
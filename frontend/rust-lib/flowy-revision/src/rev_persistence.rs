@@ -7,7 +7,7 @@ use crate::memory::RevisionMemoryCache;
 use crate::RevisionMergeable;
 use flowy_error::{internal_error, FlowyError, FlowyResult};
 use flowy_http_model::revision::{Revision, RevisionRange};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 
 use std::{borrow::Cow, sync::Arc};
 use tokio::sync::RwLock;
@@ -15,6 +15,10 @@ use tokio::task::spawn_blocking;
 
 pub const REVISION_WRITE_INTERVAL_IN_MILLIS: u64 = 600;
 
+/// The number of the most recent revisions that are kept after compacting the revisions, the
+/// older ones are squashed into the base revision.
+pub const RECENT_REVISIONS_AFTER_COMPACTION: usize = 10;
+
 #[derive(Clone)]
 pub struct RevisionPersistenceConfiguration {
     // If the number of revisions that didn't sync to the server greater than the merge_threshold
@@ -317,6 +321,59 @@ where
         Ok(records)
     }
 
+    /// Squashes the acked revisions into one base revision, except the `keep_recent` most recent
+    /// revisions. Only the leading acked revisions are squashed, the revisions that are not acked
+    /// yet keep their state and stay in the sync sequence.
+    #[tracing::instrument(level = "trace", skip(self, rev_compress), err)]
+    pub(crate) async fn compact_acked_revisions<'a>(
+        &'a self,
+        keep_recent: usize,
+        rev_compress: &Arc<dyn RevisionMergeable + 'a>,
+    ) -> FlowyResult<()> {
+        // Holding the lock keeps the revisions from being synced or merged while they're compacted.
+        let _sync_seq = self.sync_seq.write().await;
+        let records = self.load_all_records_with_state()?;
+        let compact_len = records.len().saturating_sub(keep_recent);
+        let acked_records = records
+            .into_iter()
+            .take(compact_len)
+            .take_while(|record| record.state == RevisionState::Ack)
+            .collect::<Vec<SyncRecord>>();
+        if acked_records.len() <= 1 {
+            return Ok(());
+        }
+
+        let rev_ids = acked_records
+            .iter()
+            .map(|record| record.revision.rev_id)
+            .collect::<Vec<i64>>();
+        let revisions = acked_records.into_iter().map(|record| record.revision).collect();
+        let base_revision = rev_compress.merge_revisions(&self.user_id, &self.object_id, revisions)?;
+        rev_ids.iter().for_each(|rev_id| self.memory_cache.remove(rev_id));
+        let record = SyncRecord {
+            revision: base_revision,
+            state: RevisionState::Ack,
+            write_to_disk: true,
+        };
+        let _ = self
+            .disk_cache
+            .delete_and_insert_records(&self.object_id, Some(rev_ids), vec![record])?;
+        Ok(())
+    }
+
+    /// Returns all the records ordered by the rev_id, including the ones that are not written to
+    /// the disk yet. The records in memory have the latest state.
+    fn load_all_records_with_state(&self) -> FlowyResult<Vec<SyncRecord>> {
+        let mut records = BTreeMap::new();
+        for record in self.load_all_records(&self.object_id)? {
+            records.insert(record.revision.rev_id, record);
+        }
+        for record in self.memory_cache.records() {
+            records.insert(record.revision.rev_id, record);
+        }
+        Ok(records.into_values().collect())
+    }
+
     // Read the revision which rev_id >= range.start && rev_id <= range.end
     pub async fn revisions_in_range(&self, range: &RevisionRange) -> FlowyResult<Vec<Revision>> {
         let range = range.clone();
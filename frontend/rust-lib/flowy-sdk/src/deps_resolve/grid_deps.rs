@@ -63,6 +63,14 @@ impl GridUser for GridUserImpl {
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError> {
         self.0.db_pool()
     }
+
+    fn user_name(&self, user_id: &str) -> Option<String> {
+        // Only the name of the current user is known for the moment
+        if self.0.user_id().ok()? != user_id {
+            return None;
+        }
+        self.0.user_name().ok().filter(|name| !name.is_empty())
+    }
 }
 
 struct GridRevisionWebSocket(Arc<FlowyWebSocketConnect>);
@@ -159,25 +159,27 @@ impl GridBlockRevisionPad {
 
     pub fn update_row(&mut self, changeset: RowChangeset) -> CollaborateResult<Option<GridBlockRevisionChangeset>> {
         let row_id = changeset.row_id.clone();
-        self.modify_row(&row_id, |row| {
-            let mut is_changed = None;
-            if let Some(height) = changeset.height {
-                row.height = height;
-                is_changed = Some(());
-            }
-
-            if let Some(visibility) = changeset.visibility {
-                row.visibility = visibility;
-                is_changed = Some(());
-            }
+        self.modify_row(&row_id, |row| Ok(apply_row_changeset(row, changeset)))
+    }
 
-            if !changeset.cell_by_field_id.is_empty() {
-                is_changed = Some(());
-                changeset.cell_by_field_id.into_iter().for_each(|(field_id, cell)| {
-                    row.cells.insert(field_id, cell);
-                })
+    /// Applies the changesets to the rows within one revision. The changesets of the rows that
+    /// can't be found are ignored.
+    pub fn update_rows(
+        &mut self,
+        changesets: Vec<RowChangeset>,
+    ) -> CollaborateResult<Option<GridBlockRevisionChangeset>> {
+        self.modify(|rows| {
+            let mut is_changed = None;
+            for changeset in changesets {
+                match rows.iter_mut().find(|row_rev| row_rev.id == changeset.row_id) {
+                    None => tracing::warn!("[BlockMetaPad]: Can't find any row with id: {}", changeset.row_id),
+                    Some(row_rev) => {
+                        if apply_row_changeset(Arc::make_mut(row_rev), changeset).is_some() {
+                            is_changed = Some(());
+                        }
+                    }
+                }
             }
-
             Ok(is_changed)
         })
     }
@@ -253,6 +255,43 @@ impl GridBlockRevisionPad {
     }
 }
 
+fn apply_row_changeset(row: &mut RowRevision, changeset: RowChangeset) -> Option<()> {
+    let mut is_changed = None;
+    if let Some(height) = changeset.height {
+        row.height = height;
+        is_changed = Some(());
+    }
+
+    if let Some(visibility) = changeset.visibility {
+        row.visibility = visibility;
+        is_changed = Some(());
+    }
+
+    if let Some(archived) = changeset.archived {
+        row.archived = archived;
+        is_changed = Some(());
+    }
+
+    if let Some(last_edited_by) = changeset.last_edited_by {
+        row.last_edited_by = Some(last_edited_by);
+        is_changed = Some(());
+    }
+
+    if !changeset.cell_by_field_id.is_empty() {
+        is_changed = Some(());
+        changeset.cell_by_field_id.into_iter().for_each(|(field_id, cell)| {
+            row.cells.insert(field_id, cell);
+        })
+    }
+
+    for field_id in changeset.deleted_field_ids {
+        if row.cells.remove(&field_id).is_some() {
+            is_changed = Some(());
+        }
+    }
+    is_changed
+}
+
 pub struct GridBlockRevisionChangeset {
     pub operations: GridBlockOperations,
     /// md5: the md5 of the grid after applying the change.
@@ -302,6 +341,9 @@ mod tests {
             cells: Default::default(),
             height: 0,
             visibility: false,
+            archived: false,
+            created_by: None,
+            last_edited_by: None,
         };
 
         let change = pad.add_row_rev(row.clone(), None).unwrap().unwrap();
@@ -349,6 +391,9 @@ mod tests {
             cells: Default::default(),
             height: 0,
             visibility: false,
+            archived: false,
+            created_by: None,
+            last_edited_by: None,
         }
     }
 
@@ -394,6 +439,9 @@ mod tests {
             cells: Default::default(),
             height: 0,
             visibility: false,
+            archived: false,
+            created_by: None,
+            last_edited_by: None,
         };
 
         let _ = pad.add_row_rev(row.clone(), None).unwrap().unwrap();
@@ -415,13 +463,19 @@ mod tests {
             cells: Default::default(),
             height: 0,
             visibility: false,
+            archived: false,
+            created_by: None,
+            last_edited_by: None,
         };
 
         let changeset = RowChangeset {
             row_id: row.id.clone(),
             height: Some(100),
             visibility: Some(true),
+            archived: None,
+            last_edited_by: None,
             cell_by_field_id: Default::default(),
+            deleted_field_ids: vec![],
         };
 
         let _ = pad.add_row_rev(row, None).unwrap().unwrap();
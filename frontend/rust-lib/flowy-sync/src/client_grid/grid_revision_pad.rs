@@ -18,6 +18,9 @@ pub type GridOperationsBuilder = DeltaOperationBuilder<EmptyAttributes>;
 pub struct GridRevisionPad {
     grid_rev: Arc<GridRevision>,
     operations: GridOperations,
+    /// The id of the revision that the next change is saved in. It's recorded on the fields that
+    /// the change modifies.
+    next_rev_id: i64,
 }
 
 pub trait JsonDeserializer {
@@ -61,9 +64,14 @@ impl GridRevisionPad {
         Ok(Self {
             grid_rev: Arc::new(grid),
             operations,
+            next_rev_id: 0,
         })
     }
 
+    pub fn set_next_rev_id(&mut self, rev_id: i64) {
+        self.next_rev_id = rev_id;
+    }
+
     pub fn from_revisions(revisions: Vec<Revision>) -> CollaborateResult<Self> {
         let operations: GridOperations = make_operations_from_revisions(revisions)?;
         Self::from_operations(operations)
@@ -72,9 +80,10 @@ impl GridRevisionPad {
     #[tracing::instrument(level = "debug", skip_all, err)]
     pub fn create_field_rev(
         &mut self,
-        new_field_rev: FieldRevision,
+        mut new_field_rev: FieldRevision,
         start_field_id: Option<String>,
     ) -> CollaborateResult<Option<GridRevisionChangeset>> {
+        new_field_rev.last_modified_rev_id = self.next_rev_id;
         self.modify_grid(|grid_meta| {
             // Check if the field exists or not
             if grid_meta
@@ -120,6 +129,7 @@ impl GridRevisionPad {
         field_id: &str,
         duplicated_field_id: &str,
     ) -> CollaborateResult<Option<GridRevisionChangeset>> {
+        let next_rev_id = self.next_rev_id;
         self.modify_grid(
             |grid_meta| match grid_meta.fields.iter().position(|field| field.id == field_id) {
                 None => Ok(None),
@@ -127,6 +137,7 @@ impl GridRevisionPad {
                     let mut duplicate_field_rev = grid_meta.fields[index].as_ref().clone();
                     duplicate_field_rev.id = duplicated_field_id.to_string();
                     duplicate_field_rev.name = format!("{} (copy)", duplicate_field_rev.name);
+                    duplicate_field_rev.last_modified_rev_id = next_rev_id;
                     grid_meta.fields.insert(index + 1, Arc::new(duplicate_field_rev));
                     Ok(Some(()))
                 }
@@ -157,6 +168,7 @@ impl GridRevisionPad {
         T: Into<FieldTypeRevision>,
     {
         let new_field_type = new_field_type.into();
+        let next_rev_id = self.next_rev_id;
         self.modify_grid(|grid_meta| {
             match grid_meta.fields.iter_mut().find(|field_rev| field_rev.id == field_id) {
                 None => {
@@ -188,6 +200,7 @@ impl GridRevisionPad {
                     }
 
                     mut_field_rev.ty = new_field_type;
+                    mut_field_rev.last_modified_rev_id = next_rev_id;
                     Ok(Some(()))
                 }
             }
@@ -198,12 +211,14 @@ impl GridRevisionPad {
         &mut self,
         field_rev: Arc<FieldRevision>,
     ) -> CollaborateResult<Option<GridRevisionChangeset>> {
+        let mut field_rev = field_rev.as_ref().clone();
+        field_rev.last_modified_rev_id = self.next_rev_id;
         self.modify_grid(
             |grid_meta| match grid_meta.fields.iter().position(|field| field.id == field_rev.id) {
                 None => Ok(None),
                 Some(index) => {
                     grid_meta.fields.remove(index);
-                    grid_meta.fields.insert(index, field_rev);
+                    grid_meta.fields.insert(index, Arc::new(field_rev));
                     Ok(Some(()))
                 }
             },
@@ -216,6 +231,7 @@ impl GridRevisionPad {
         from_index: usize,
         to_index: usize,
     ) -> CollaborateResult<Option<GridRevisionChangeset>> {
+        let next_rev_id = self.next_rev_id;
         self.modify_grid(|grid_meta| {
             match move_vec_element(
                 &mut grid_meta.fields,
@@ -225,12 +241,46 @@ impl GridRevisionPad {
             )
             .map_err(internal_error)?
             {
-                true => Ok(Some(())),
+                true => {
+                    // The fields that are shifted by the move aren't modified
+                    if let Some(field_rev) = grid_meta.fields.iter_mut().find(|field| field.id == field_id) {
+                        Arc::make_mut(field_rev).last_modified_rev_id = next_rev_id;
+                    }
+                    Ok(Some(()))
+                }
                 false => Ok(None),
             }
         })
     }
 
+    /// Exchanges the positions of the two fields. Returns an error if either of them can't be found.
+    pub fn swap_fields(
+        &mut self,
+        field_id_a: &str,
+        field_id_b: &str,
+    ) -> CollaborateResult<Option<GridRevisionChangeset>> {
+        let next_rev_id = self.next_rev_id;
+        self.modify_grid(|grid_meta| {
+            let index_a = grid_meta.fields.iter().position(|field| field.id == field_id_a);
+            let index_b = grid_meta.fields.iter().position(|field| field.id == field_id_b);
+            match (index_a, index_b) {
+                (Some(index_a), Some(index_b)) => {
+                    if index_a == index_b {
+                        return Ok(None);
+                    }
+                    grid_meta.fields.swap(index_a, index_b);
+                    Arc::make_mut(&mut grid_meta.fields[index_a]).last_modified_rev_id = next_rev_id;
+                    Arc::make_mut(&mut grid_meta.fields[index_b]).last_modified_rev_id = next_rev_id;
+                    Ok(Some(()))
+                }
+                _ => {
+                    let msg = format!("Can't swap the field: {} with the field: {}", field_id_a, field_id_b);
+                    Err(CollaborateError::record_not_found().context(msg))
+                }
+            }
+        })
+    }
+
     pub fn contain_field(&self, field_id: &str) -> bool {
         self.grid_rev.fields.iter().any(|field| field.id == field_id)
     }
@@ -379,6 +429,7 @@ impl GridRevisionPad {
     where
         F: FnOnce(&mut FieldRevision) -> CollaborateResult<Option<()>>,
     {
+        let next_rev_id = self.next_rev_id;
         self.modify_grid(
             |grid_rev| match grid_rev.fields.iter().position(|field| field.id == field_id) {
                 None => {
@@ -387,7 +438,13 @@ impl GridRevisionPad {
                 }
                 Some(index) => {
                     let mut_field_rev = Arc::make_mut(&mut grid_rev.fields[index]);
-                    f(mut_field_rev)
+                    let old_field_rev = mut_field_rev.clone();
+                    let changed = f(mut_field_rev)?;
+                    // The field is saved as it is if the change doesn't modify it
+                    if changed.is_some() && *mut_field_rev != old_field_rev {
+                        mut_field_rev.last_modified_rev_id = next_rev_id;
+                    }
+                    Ok(changed)
                 }
             },
         )
@@ -429,6 +486,7 @@ impl std::default::Default for GridRevisionPad {
         GridRevisionPad {
             grid_rev: Arc::new(grid),
             operations,
+            next_rev_id: 0,
         }
     }
 }
@@ -3,8 +3,8 @@ use crate::util::{cal_diff, make_operations_from_revisions};
 use flowy_http_model::revision::Revision;
 use flowy_http_model::util::md5;
 use grid_rev_model::{
-    FieldRevision, FieldTypeRevision, FilterRevision, GridViewRevision, GroupConfigurationRevision, LayoutRevision,
-    SortRevision,
+    FieldRevision, FieldTypeRevision, FilterConjunctionRevision, FilterRevision, GridViewRevision,
+    GroupConfigurationRevision, LayoutRevision, RowHeightRevision, SortRevision,
 };
 use lib_ot::core::{DeltaBuilder, DeltaOperations, EmptyAttributes, OperationTransform};
 use std::sync::Arc;
@@ -262,6 +262,98 @@ impl GridViewRevisionPad {
         })
     }
 
+    /// Removes all the filters, sorts and group configurations of the view. The
+    /// `group_configuration_rev` replaces the removed group configurations if it's not None.
+    pub fn reset_settings(
+        &mut self,
+        group_configuration_rev: Option<GroupConfigurationRevision>,
+    ) -> CollaborateResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            view.filters.clear();
+            view.sorts.clear();
+            view.groups.clear();
+            if let Some(group_configuration_rev) = group_configuration_rev {
+                let field_id = group_configuration_rev.field_id.clone();
+                let field_type = group_configuration_rev.field_type_rev;
+                view.groups.add_object(&field_id, &field_type, group_configuration_rev);
+            }
+            Ok(Some(()))
+        })
+    }
+
+    /// Returns the `field_revs` in the order of this view. The fields that are not in the view's
+    /// field order are appended at the end, keeping the grid's order.
+    pub fn order_field_revs(&self, field_revs: Vec<Arc<FieldRevision>>) -> Vec<Arc<FieldRevision>> {
+        if self.field_order.is_empty() {
+            return field_revs;
+        }
+
+        let mut ordered_field_revs = self
+            .field_order
+            .iter()
+            .flat_map(|field_id| field_revs.iter().find(|field_rev| &field_rev.id == field_id).cloned())
+            .collect::<Vec<Arc<FieldRevision>>>();
+
+        for field_rev in field_revs {
+            if !self.field_order.contains(&field_rev.id) {
+                ordered_field_revs.push(field_rev);
+            }
+        }
+        ordered_field_revs
+    }
+
+    pub fn update_field_order(
+        &mut self,
+        field_ids: Vec<String>,
+    ) -> CollaborateResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.field_order == field_ids {
+                return Ok(None);
+            }
+            view.field_order = field_ids;
+            Ok(Some(()))
+        })
+    }
+
+    pub fn update_show_archived_rows(
+        &mut self,
+        show_archived_rows: bool,
+    ) -> CollaborateResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.show_archived_rows == show_archived_rows {
+                return Ok(None);
+            }
+            view.show_archived_rows = show_archived_rows;
+            Ok(Some(()))
+        })
+    }
+
+    pub fn update_row_height(
+        &mut self,
+        row_height: RowHeightRevision,
+    ) -> CollaborateResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.row_height == row_height {
+                return Ok(None);
+            }
+            view.row_height = row_height;
+            Ok(Some(()))
+        })
+    }
+
+    pub fn update_filter_conjunction(
+        &mut self,
+        filter_conjunction: FilterConjunctionRevision,
+    ) -> CollaborateResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.filter_conjunction == filter_conjunction {
+                return Ok(None);
+            }
+            view.filter_conjunction = filter_conjunction;
+            Ok(Some(()))
+        })
+    }
+
     pub fn json_str(&self) -> CollaborateResult<String> {
         make_grid_view_rev_json_str(&self.view)
     }
@@ -28,6 +28,17 @@ pub struct RowRevision {
     pub cells: IndexMap<FieldId, CellRevision>,
     pub height: i32,
     pub visibility: bool,
+    /// The archived rows are kept in the grid but hidden from the views, unless the view shows
+    /// the archived rows.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
+    /// The id of the user that created the row. It's None for the rows that were created before
+    /// the authors were recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// The id of the user that edited the row most recently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_edited_by: Option<String>,
 }
 
 impl RowRevision {
@@ -38,6 +49,9 @@ impl RowRevision {
             cells: Default::default(),
             height: DEFAULT_ROW_HEIGHT,
             visibility: true,
+            archived: false,
+            created_by: None,
+            last_edited_by: None,
         }
     }
 }
@@ -46,9 +60,13 @@ pub struct RowChangeset {
     pub row_id: String,
     pub height: Option<i32>,
     pub visibility: Option<bool>,
+    pub archived: Option<bool>,
+    pub last_edited_by: Option<String>,
     // Contains the key/value changes represents as the update of the cells. For example,
     // if there is one cell was changed, then the `cell_by_field_id` will only have one key/value.
     pub cell_by_field_id: HashMap<FieldId, CellRevision>,
+    // The cells of these fields are removed from the row.
+    pub deleted_field_ids: Vec<FieldId>,
 }
 
 impl RowChangeset {
@@ -57,12 +75,20 @@ impl RowChangeset {
             row_id,
             height: None,
             visibility: None,
+            archived: None,
+            last_edited_by: None,
             cell_by_field_id: Default::default(),
+            deleted_field_ids: vec![],
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.height.is_none() && self.visibility.is_none() && self.cell_by_field_id.is_empty()
+        self.height.is_none()
+            && self.visibility.is_none()
+            && self.archived.is_none()
+            && self.last_edited_by.is_none()
+            && self.cell_by_field_id.is_empty()
+            && self.deleted_field_ids.is_empty()
     }
 }
 
@@ -116,6 +116,15 @@ pub struct FieldRevision {
 
     #[serde(default = "DEFAULT_IS_PRIMARY")]
     pub is_primary: bool,
+
+    /// The cells of the read-only field can't be edited.
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// The id of the revision of the grid that last changed the field or its position. It's 0 if
+    /// the field hasn't changed since the id is recorded.
+    #[serde(default)]
+    pub last_modified_rev_id: i64,
 }
 
 impl AsRef<FieldRevision> for FieldRevision {
@@ -144,6 +153,8 @@ impl FieldRevision {
             width,
             type_options: Default::default(),
             is_primary,
+            read_only: false,
+            last_modified_rev_id: 0,
         }
     }
 
@@ -3,7 +3,6 @@ use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
 
-#[allow(dead_code)]
 pub fn gen_grid_view_id() -> String {
     nanoid!(6)
 }
@@ -28,6 +27,48 @@ impl std::default::Default for LayoutRevision {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum RowHeightRevision {
+    Compact = 0,
+    Medium = 1,
+    Tall = 2,
+}
+
+impl RowHeightRevision {
+    pub fn is_default(&self) -> bool {
+        *self == RowHeightRevision::default()
+    }
+}
+
+impl std::default::Default for RowHeightRevision {
+    fn default() -> Self {
+        RowHeightRevision::Medium
+    }
+}
+
+/// Decides how the filters of a view are combined when there are more than one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum FilterConjunctionRevision {
+    /// The row is visible if it matches all the filters.
+    And = 0,
+    /// The row is visible if it matches any of the filters.
+    Or = 1,
+}
+
+impl FilterConjunctionRevision {
+    pub fn is_default(&self) -> bool {
+        *self == FilterConjunctionRevision::default()
+    }
+}
+
+impl std::default::Default for FilterConjunctionRevision {
+    fn default() -> Self {
+        FilterConjunctionRevision::And
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GridViewRevision {
     pub view_id: String,
@@ -44,6 +85,22 @@ pub struct GridViewRevision {
 
     #[serde(default)]
     pub sorts: SortConfiguration,
+
+    /// The field ids in the order they are displayed in this view. It's empty if the view
+    /// follows the grid's field order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub field_order: Vec<String>,
+
+    /// Shows the archived rows in this view if it's true.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_archived_rows: bool,
+
+    /// The height of the rows in this view.
+    #[serde(default, skip_serializing_if = "RowHeightRevision::is_default")]
+    pub row_height: RowHeightRevision,
+
+    #[serde(default, skip_serializing_if = "FilterConjunctionRevision::is_default")]
+    pub filter_conjunction: FilterConjunctionRevision,
 }
 
 impl GridViewRevision {
@@ -55,6 +112,10 @@ impl GridViewRevision {
             filters: Default::default(),
             groups: Default::default(),
             sorts: Default::default(),
+            field_order: vec![],
+            show_archived_rows: false,
+            row_height: Default::default(),
+            filter_conjunction: Default::default(),
         }
     }
 
@@ -70,7 +131,7 @@ pub struct RowOrderRevision {
 
 #[cfg(test)]
 mod tests {
-    use crate::GridViewRevision;
+    use crate::{GridViewRevision, LayoutRevision, RowHeightRevision};
 
     #[test]
     fn grid_view_revision_serde_test() {
@@ -81,6 +142,10 @@ mod tests {
             filters: Default::default(),
             groups: Default::default(),
             sorts: Default::default(),
+            field_order: vec![],
+            show_archived_rows: false,
+            row_height: Default::default(),
+            filter_conjunction: Default::default(),
         };
         let s = serde_json::to_string(&grid_view_revision).unwrap();
         assert_eq!(
@@ -88,4 +153,18 @@ mod tests {
             r#"{"view_id":"1","grid_id":"1","layout":0,"filters":[],"groups":[],"sorts":[]}"#
         );
     }
+
+    #[test]
+    fn grid_view_revision_row_height_serde_test() {
+        let mut grid_view_revision = GridViewRevision::new("1".to_string(), "1".to_string(), LayoutRevision::Table);
+        grid_view_revision.row_height = RowHeightRevision::Tall;
+        let s = serde_json::to_string(&grid_view_revision).unwrap();
+        assert_eq!(
+            s,
+            r#"{"view_id":"1","grid_id":"1","layout":0,"filters":[],"groups":[],"sorts":[],"row_height":2}"#
+        );
+
+        let grid_view_revision = GridViewRevision::from_json(s).unwrap();
+        assert_eq!(grid_view_revision.row_height, RowHeightRevision::Tall);
+    }
 }
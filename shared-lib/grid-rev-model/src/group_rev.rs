@@ -6,6 +6,10 @@ use serde_repr::*;
 pub trait GroupConfigurationContentSerde: Sized + Send + Sync {
     fn from_json(s: &str) -> Result<Self, serde_json::Error>;
     fn to_json(&self) -> Result<String, serde_json::Error>;
+
+    /// Whether the group that holds the rows without a value is omitted from the output.
+    fn hide_empty(&self) -> bool;
+    fn set_hide_empty(&mut self, hide_empty: bool);
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -46,6 +50,14 @@ impl GroupConfigurationContentSerde for TextGroupConfigurationRevision {
     fn to_json(&self) -> Result<String, Error> {
         serde_json::to_string(self)
     }
+
+    fn hide_empty(&self) -> bool {
+        self.hide_empty
+    }
+
+    fn set_hide_empty(&mut self, hide_empty: bool) {
+        self.hide_empty = hide_empty;
+    }
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -60,6 +72,14 @@ impl GroupConfigurationContentSerde for NumberGroupConfigurationRevision {
     fn to_json(&self) -> Result<String, Error> {
         serde_json::to_string(self)
     }
+
+    fn hide_empty(&self) -> bool {
+        self.hide_empty
+    }
+
+    fn set_hide_empty(&mut self, hide_empty: bool) {
+        self.hide_empty = hide_empty;
+    }
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -74,6 +94,14 @@ impl GroupConfigurationContentSerde for UrlGroupConfigurationRevision {
     fn to_json(&self) -> Result<String, Error> {
         serde_json::to_string(self)
     }
+
+    fn hide_empty(&self) -> bool {
+        self.hide_empty
+    }
+
+    fn set_hide_empty(&mut self, hide_empty: bool) {
+        self.hide_empty = hide_empty;
+    }
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -89,6 +117,14 @@ impl GroupConfigurationContentSerde for CheckboxGroupConfigurationRevision {
     fn to_json(&self) -> Result<String, Error> {
         serde_json::to_string(self)
     }
+
+    fn hide_empty(&self) -> bool {
+        self.hide_empty
+    }
+
+    fn set_hide_empty(&mut self, hide_empty: bool) {
+        self.hide_empty = hide_empty;
+    }
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -104,6 +140,14 @@ impl GroupConfigurationContentSerde for SelectOptionGroupConfigurationRevision {
     fn to_json(&self) -> Result<String, Error> {
         serde_json::to_string(self)
     }
+
+    fn hide_empty(&self) -> bool {
+        self.hide_empty
+    }
+
+    fn set_hide_empty(&mut self, hide_empty: bool) {
+        self.hide_empty = hide_empty;
+    }
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -136,6 +180,7 @@ impl GroupRevision {
 #[derive(Default, Serialize, Deserialize)]
 pub struct DateGroupConfigurationRevision {
     pub hide_empty: bool,
+    #[serde(default)]
     pub condition: DateCondition,
 }
 
@@ -146,9 +191,17 @@ impl GroupConfigurationContentSerde for DateGroupConfigurationRevision {
     fn to_json(&self) -> Result<String, Error> {
         serde_json::to_string(self)
     }
+
+    fn hide_empty(&self) -> bool {
+        self.hide_empty
+    }
+
+    fn set_hide_empty(&mut self, hide_empty: bool) {
+        self.hide_empty = hide_empty;
+    }
 }
 
-#[derive(Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum DateCondition {
     Relative = 0,